@@ -0,0 +1,123 @@
+#include <cmath>
+#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <ostream>
+#include <string>
+namespace tako {
+struct u8 {
+  uint8_t value;
+  constexpr u8(uint8_t value = 0) : value(value) {}
+  constexpr operator uint8_t() const { return value; }
+};
+inline u8 operator+(u8 l, u8 r) { return uint8_t(l.value + r.value); }
+inline u8 operator-(u8 l, u8 r) { return uint8_t(l.value - r.value); }
+inline u8 operator*(u8 l, u8 r) { return uint8_t(l.value * r.value); }
+inline u8 operator/(u8 l, u8 r) { return uint8_t(l.value / r.value); }
+inline u8 operator%(u8 l, u8 r) { return uint8_t(l.value % r.value); }
+inline u8 operator-(u8 n) { return uint8_t(-n.value); }
+inline u8 pow(u8 l, int exp) {
+  u8 out = 1;
+  for (; exp > 0; exp--) {
+    out = out * l;
+  }
+  return out;
+}
+// Bytes are written as numbers, rather than as characters.
+inline std::ostream& operator<<(std::ostream& out, u8 n) {
+  return out << +n.value;
+}
+struct f64 {
+  double value;
+  constexpr f64(double value = 0) : value(value) {}
+  constexpr operator double() const { return value; }
+};
+inline f64 operator+(f64 l, f64 r) { return l.value + r.value; }
+inline f64 operator-(f64 l, f64 r) { return l.value - r.value; }
+inline f64 operator*(f64 l, f64 r) { return l.value * r.value; }
+inline f64 operator/(f64 l, f64 r) { return l.value / r.value; }
+inline f64 operator%(f64 l, f64 r) { return std::fmod(l.value, r.value); }
+inline f64 operator-(f64 x) { return -x.value; }
+inline f64 pow(f64 l, int exp) { return std::pow(l.value, exp); }
+inline f64 pow(f64 l, f64 exp) { return std::pow(l.value, exp.value); }
+// Floats are written with the fewest digits that read back as the same float, with a `.0` on
+// whole numbers and an exponent on very large or small numbers.
+inline std::ostream& operator<<(std::ostream& out, f64 x) {
+  if (std::isnan(x.value)) {
+    return out << "NaN";
+  }
+  if (std::isinf(x.value)) {
+    return out << (x.value < 0 ? "-inf" : "inf");
+  }
+  char buffer[32];
+  for (int precision = 0; precision <= 17; precision++) {
+    std::snprintf(buffer, sizeof buffer, "%.*e", precision, x.value);
+    if (std::strtod(buffer, nullptr) == x.value) {
+      break;
+    }
+  }
+  const std::string written = buffer;
+  const size_t e = written.find('e');
+  const int exp = std::stoi(written.substr(e + 1));
+  std::string digits;
+  for (const char c : written.substr(0, e)) {
+    if (c >= '0' && c <= '9') {
+      digits += c;
+    }
+  }
+  const double size = std::fabs(x.value);
+  out << (std::signbit(x.value) ? "-" : "");
+  if (size != 0 && (size < 1e-4 || size >= 1e16)) {
+    out << digits[0] << (digits.size() > 1 ? "." + digits.substr(1) : "") << 'e' << exp;
+  } else if (exp < 0) {
+    out << "0." << std::string(-exp - 1, '0') << digits;
+  } else if (digits.size() <= size_t(exp) + 1) {
+    out << digits << std::string(exp + 1 - digits.size(), '0') << ".0";
+  } else {
+    out << digits.substr(0, exp + 1) << '.' << digits.substr(exp + 1);
+  }
+  return out;
+}
+}
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", tako::f64{0.0}), " "), -(tako::f64{0.0})), " "), tako::f64{0.0001}), " "), tako::f64{123456.789}), " "), (tako::f64{100.0}/tako::f64{3.0})), " "), (tako::f64{1.0}/tako::f64{0.0})), " "), (tako::f64{0.0}/tako::f64{0.0})), " "), tako::f64{1e20}), " "), tako::f64{0.000123}), " "), pow(tako::f64{2.0}, tako::f64{0.5})), "\n"));
+  return 0;
+}
@@ -0,0 +1,132 @@
+#include <functional>
+#include <iostream>
+#include <string>
+#include <type_traits>
+#include <utility>
+#include <vector>
+namespace tako {
+// The value of operations that are only run for their effects, e.g. `print`.
+struct unit {};
+std::ostream& operator<<(std::ostream& out, const unit&) {
+  return out << "()";
+}
+// Handlers are numbered in the order that they are installed.
+int& handlers_installed() {
+  static int installed = 0;
+  return installed;
+}
+std::vector<std::pair<int, int>>& handlers_hidden() {
+  static std::vector<std::pair<int, int>> hidden;
+  return hidden;
+}
+bool handler_visible(int handler) {
+  for (const auto& hidden : handlers_hidden()) {
+    if (hidden.first <= handler && handler <= hidden.second) {
+      return false;
+    }
+  }
+  return true;
+}
+struct hide_handlers {
+  explicit hide_handlers(int handler) {
+    handlers_hidden().emplace_back(handler, handlers_installed());
+  }
+  ~hide_handlers() {
+    handlers_hidden().pop_back();
+  }
+};
+template <typename R>
+struct fallback {
+  template <typename F, typename... Args>
+  static R call(const F& op, const Args&... args) {
+    return op(args...);
+  }
+};
+template <>
+struct fallback<unit> {
+  template <typename F, typename... Args>
+  static unit call(const F& op, const Args&... args) {
+    op(args...);
+    return unit{};
+  }
+};
+// The clauses handling the operation numbered `Op`, with their handlers, innermost last.
+template <int Op, typename F>
+std::vector<std::pair<int, std::function<F>>>& clauses() {
+  static std::vector<std::pair<int, std::function<F>>> clauses;
+  return clauses;
+}
+template <int Op, typename F>
+struct operation;
+template <int Op, typename R, typename... Args>
+struct operation<Op, R(Args...)> {
+  template <typename F>
+  static R perform(const F& op, Args... args) {
+    auto& handling = clauses<Op, R(Args...)>();
+    for (auto clause = handling.rbegin(); clause != handling.rend(); ++clause) {
+      if (handler_visible(clause->first)) {
+        const hide_handlers hide(clause->first);
+        return clause->second(args...);
+      }
+    }
+    return fallback<R>::call(op, args...);
+  }
+  struct install {
+    install(int handler, std::function<R(Args...)> clause) {
+      clauses<Op, R(Args...)>().emplace_back(handler, clause);
+    }
+    ~install() {
+      clauses<Op, R(Args...)>().pop_back();
+    }
+  };
+};
+template <typename T>
+struct handler_exit {
+  int handler;
+  T value;
+};
+template <typename T>
+struct handled_value {
+  using type = T;
+};
+template <>
+struct handled_value<const char*> {
+  using type = std::string;
+};
+// An expression with handlers, and the clauses that are waiting for its value, innermost last.
+template <typename T>
+struct handler {
+  using value = typename handled_value<T>::type;
+  const int id = ++handlers_installed();
+  std::vector<std::function<value(value)>> resumptions;
+  template <typename Body>
+  value run(const Body& body) const {
+    try {
+      return body();
+    } catch (const handler_exit<value>& exit) {
+      if (exit.handler != id) {
+        throw;
+      }
+      return exit.value;
+    }
+  }
+  // Leaves the expression with a value, in place of the `R` that a clause would resume with.
+  template <typename R>
+  R exit(const value& val) const {
+    throw handler_exit<value>{id, val};
+  }
+  value resume(value val) const {
+    for (auto waiting = resumptions.rbegin(); waiting != resumptions.rend(); ++waiting) {
+      val = (*waiting)(val);
+    }
+    return val;
+  }
+};
+}
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_b_ask = [&]() {
+    return 0;
+  };
+  return ([&]() {  const auto tako_handled = [&]() {    return (tako::operation<0, bool()>::perform([&]() -> decltype(auto) { return _tmp_h_b_ask(); }) ? 1 : 2);  };  tako::handler<std::decay_t<decltype(tako_handled())>> tako_handler;  const auto tako_value = ([&]() {    const tako::operation<0, bool()>::install tako_clause_0(tako_handler.id, [&]() {  const auto tako_resume = [&](const auto&... value) { return bool(value...); };  return tako_resume(1);});    return tako_handler.run(tako_handled);  })();  return tako_handler.resume(tako_value);})();
+}
@@ -0,0 +1,401 @@
+#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <ostream>
+#include <string>
+namespace tako {
+struct u8 {
+  uint8_t value;
+  constexpr u8(uint8_t value = 0) : value(value) {}
+  constexpr operator uint8_t() const { return value; }
+};
+inline u8 operator+(u8 l, u8 r) { return uint8_t(l.value + r.value); }
+inline u8 operator-(u8 l, u8 r) { return uint8_t(l.value - r.value); }
+inline u8 operator*(u8 l, u8 r) { return uint8_t(l.value * r.value); }
+inline u8 operator/(u8 l, u8 r) { return uint8_t(l.value / r.value); }
+inline u8 operator%(u8 l, u8 r) { return uint8_t(l.value % r.value); }
+inline u8 operator-(u8 n) { return uint8_t(-n.value); }
+inline u8 pow(u8 l, int exp) {
+  u8 out = 1;
+  for (; exp > 0; exp--) {
+    out = out * l;
+  }
+  return out;
+}
+// Bytes are written as numbers, rather than as characters.
+inline std::ostream& operator<<(std::ostream& out, u8 n) {
+  return out << +n.value;
+}
+struct f64 {
+  double value;
+  constexpr f64(double value = 0) : value(value) {}
+  constexpr operator double() const { return value; }
+};
+inline f64 operator+(f64 l, f64 r) { return l.value + r.value; }
+inline f64 operator-(f64 l, f64 r) { return l.value - r.value; }
+inline f64 operator*(f64 l, f64 r) { return l.value * r.value; }
+inline f64 operator/(f64 l, f64 r) { return l.value / r.value; }
+inline f64 operator%(f64 l, f64 r) { return std::fmod(l.value, r.value); }
+inline f64 operator-(f64 x) { return -x.value; }
+inline f64 pow(f64 l, int exp) { return std::pow(l.value, exp); }
+inline f64 pow(f64 l, f64 exp) { return std::pow(l.value, exp.value); }
+// Floats are written with the fewest digits that read back as the same float, with a `.0` on
+// whole numbers and an exponent on very large or small numbers.
+inline std::ostream& operator<<(std::ostream& out, f64 x) {
+  if (std::isnan(x.value)) {
+    return out << "NaN";
+  }
+  if (std::isinf(x.value)) {
+    return out << (x.value < 0 ? "-inf" : "inf");
+  }
+  char buffer[32];
+  for (int precision = 0; precision <= 17; precision++) {
+    std::snprintf(buffer, sizeof buffer, "%.*e", precision, x.value);
+    if (std::strtod(buffer, nullptr) == x.value) {
+      break;
+    }
+  }
+  const std::string written = buffer;
+  const size_t e = written.find('e');
+  const int exp = std::stoi(written.substr(e + 1));
+  std::string digits;
+  for (const char c : written.substr(0, e)) {
+    if (c >= '0' && c <= '9') {
+      digits += c;
+    }
+  }
+  const double size = std::fabs(x.value);
+  out << (std::signbit(x.value) ? "-" : "");
+  if (size != 0 && (size < 1e-4 || size >= 1e16)) {
+    out << digits[0] << (digits.size() > 1 ? "." + digits.substr(1) : "") << 'e' << exp;
+  } else if (exp < 0) {
+    out << "0." << std::string(-exp - 1, '0') << digits;
+  } else if (digits.size() <= size_t(exp) + 1) {
+    out << digits << std::string(exp + 1 - digits.size(), '0') << ".0";
+  } else {
+    out << digits.substr(0, exp + 1) << '.' << digits.substr(exp + 1);
+  }
+  return out;
+}
+}
+#include <codecvt>
+#include <locale>
+#include <map>
+#include <ostream>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+namespace tako {
+template <typename T> struct value { using type = T; };
+template <> struct value<const char*> { using type = std::string; };
+inline std::vector<std::string> chars(const std::string& s) {
+  std::vector<std::string> out;
+  for (size_t i = 0; i < s.size();) {
+    const unsigned char c = s[i];
+    const size_t n = c >= 0xf0 ? 4 : c >= 0xe0 ? 3 : c >= 0xc0 ? 2 : 1;
+    out.push_back(s.substr(i, n));
+    i += n;
+  }
+  return out;
+}
+inline int len(const std::string& s) {
+  return chars(s).size();
+}
+inline int len(const char* s) {
+  return len(std::string(s));
+}
+inline std::string index(const std::string& s, const int i) {
+  return chars(s).at(i);
+}
+inline std::string slice(const std::string& s, const int start, const int end) {
+  const auto cs = chars(s);
+  std::string out;
+  for (int i = start < 0 ? 0 : start; i < end && i < (int)cs.size(); i++) {
+    out += cs[i];
+  }
+  return out;
+}
+inline std::vector<std::string> split(const std::string& s, const std::string& sep) {
+  std::vector<std::string> out;
+  size_t start = 0;
+  for (size_t at; !sep.empty() && (at = s.find(sep, start)) != std::string::npos;) {
+    out.push_back(s.substr(start, at - start));
+    start = at + sep.size();
+  }
+  out.push_back(s.substr(start));
+  return out;
+}
+inline std::string join(const std::vector<std::string>& xs, const std::string& sep) {
+  std::string out;
+  for (size_t i = 0; i < xs.size(); i++) {
+    out += (i ? sep : "") + xs[i];
+  }
+  return out;
+}
+inline int find(const std::string& s, const std::string& x) {
+  const size_t at = s.find(x);
+  return at == std::string::npos ? -1 : len(s.substr(0, at));
+}
+inline std::string replace(const std::string& s, const std::string& from, const std::string& to) {
+  return from.empty() ? s : join(split(s, from), to);
+}
+// Letters change case by the rules of a UTF-8 locale, one code point at a time. Like the
+// interpreter, letters that Unicode changes to more than one letter are kept: the locale leaves
+// most of them (e.g. `ß`, rather than `SS`), but changes `İ` (rather than `i̇`) and the Greek
+// letters with an iota below them (e.g. `ᾳ`, rather than `ΑΙ`).
+inline std::locale utf8_locale() {
+  for (const char* name : {"C.UTF-8", "en_US.UTF-8"}) {
+    try {
+      return std::locale(name);
+    } catch (const std::runtime_error&) {
+    }
+  }
+  return std::locale::classic();
+}
+template <typename F>
+std::string map_letters(const std::string& s, F f) {
+  static const std::locale locale = utf8_locale();
+  std::wstring_convert<std::codecvt_utf8<wchar_t>> utf8;
+  std::wstring letters = utf8.from_bytes(s);
+  for (auto& c : letters) {
+    c = f(c, locale);
+  }
+  return utf8.to_bytes(letters);
+}
+inline std::string upper(const std::string& s) {
+  return map_letters(s, [](wchar_t c, const std::locale& locale) {
+    const bool iota_below = (c >= 0x1f80 && c <= 0x1fa7) || c == 0x1fb3 || c == 0x1fc3 || c == 0x1ff3;
+    return iota_below ? c : std::toupper(c, locale);
+  });
+}
+inline std::string lower(const std::string& s) {
+  return map_letters(s, [](wchar_t c, const std::locale& locale) {
+    return c == 0x130 ? c : std::tolower(c, locale);
+  });
+}
+// Values in containers are written like the interpreter writes them, with strings quoted.
+template <typename T>
+auto show(std::ostream& out, const T& x, int) -> decltype(x.to_string(), void()) {
+  out << x.to_string();
+}
+template <typename T>
+void show(std::ostream& out, const T& x, long) {
+  out << x;
+}
+inline void show(std::ostream& out, const std::string& s, int) {
+  out << '\'' << s << '\'';
+}
+inline void show(std::ostream& out, const char* s, int) {
+  out << '\'' << s << '\'';
+}
+template <typename T, typename... Ts>
+std::vector<typename value<T>::type> list(T first, Ts... rest) {
+  return {first, rest...};
+}
+template <typename C>
+int len(const C& c) {
+  return c.size();
+}
+template <typename T>
+T index(const std::vector<T>& xs, const int i) {
+  return xs.at(i);
+}
+template <typename T, typename F>
+auto map(const std::vector<T>& xs, const F f) {
+  std::vector<decltype(f(xs.front()))> out;
+  for (const auto& x : xs) {
+    out.push_back(f(x));
+  }
+  return out;
+}
+template <typename T, typename F>
+std::vector<T> filter(const std::vector<T>& xs, const F f) {
+  std::vector<T> out;
+  for (const auto& x : xs) {
+    if (f(x)) {
+      out.push_back(x);
+    }
+  }
+  return out;
+}
+template <typename T, typename A, typename F>
+A fold(const std::vector<T>& xs, A acc, const F f) {
+  for (const auto& x : xs) {
+    acc = f(acc, x);
+  }
+  return acc;
+}
+template <typename T>
+std::set<T> to_set(const std::vector<T>& xs) {
+  return std::set<T>(xs.begin(), xs.end());
+}
+template <typename T>
+std::vector<T> to_list(const std::set<T>& xs) {
+  return std::vector<T>(xs.begin(), xs.end());
+}
+template <typename T>
+bool contains(const std::set<T>& xs, const typename std::set<T>::value_type& x) {
+  return xs.count(x) > 0;
+}
+template <typename T>
+std::set<T> insert(std::set<T> xs, const typename std::set<T>::value_type& x) {
+  xs.insert(x);
+  return xs;
+}
+template <typename K, typename V>
+std::map<K, V> to_map(const std::vector<K>& keys, const std::vector<V>& values) {
+  if (keys.size() != values.size()) {
+    throw std::invalid_argument("map has " + std::to_string(keys.size()) + " keys but " +
+                                std::to_string(values.size()) + " values");
+  }
+  std::map<K, V> out;
+  for (size_t i = 0; i < keys.size(); i++) {
+    out.erase(keys[i]);
+    out.emplace(keys[i], values[i]);
+  }
+  return out;
+}
+template <typename K, typename V>
+V get(const std::map<K, V>& m, const typename std::map<K, V>::key_type& k) {
+  return m.at(k);
+}
+template <typename K, typename V>
+std::map<K, V> put(
+    std::map<K, V> m,
+    const typename std::map<K, V>::key_type& k,
+    const typename std::map<K, V>::mapped_type& v) {
+  m.erase(k);
+  m.emplace(k, v);
+  return m;
+}
+template <typename K, typename V>
+std::vector<K> keys(const std::map<K, V>& m) {
+  std::vector<K> out;
+  for (const auto& kv : m) {
+    out.push_back(kv.first);
+  }
+  return out;
+}
+template <typename K, typename V>
+std::vector<V> values(const std::map<K, V>& m) {
+  std::vector<V> out;
+  for (const auto& kv : m) {
+    out.push_back(kv.second);
+  }
+  return out;
+}
+}
+namespace std{
+template <typename T>
+ostream& operator<<(ostream& out, const vector<T>& xs) {
+  out << "[";
+  for (size_t i = 0; i < xs.size(); i++) {
+    out << (i ? ", " : "");
+    tako::show(out, xs[i], 0);
+  }
+  return out << "]";
+}
+template <typename T>
+ostream& operator<<(ostream& out, const set<T>& xs) {
+  out << "{";
+  for (auto it = xs.begin(); it != xs.end(); it++) {
+    out << (it == xs.begin() ? "" : ", ");
+    tako::show(out, *it, 0);
+  }
+  return out << "}";
+}
+template <typename K, typename V>
+ostream& operator<<(ostream& out, const map<K, V>& m) {
+  out << "{";
+  for (auto it = m.begin(); it != m.end(); it++) {
+    out << (it == m.begin() ? "" : ", ");
+    tako::show(out, it->first, 0);
+    out << ": ";
+    tako::show(out, it->second, 0);
+  }
+  return out << "}";
+}
+}
+#include <iostream>
+#include <ostream>
+#include <sstream>
+#include <string>
+#include <tuple>
+#include <utility>
+namespace tako {
+template <typename T, size_t... I>
+void show_tuple(std::ostream& out, const T& t, std::index_sequence<I...>) {
+  (void)std::initializer_list<int>{(out << (I ? ", " : ""), show(out, std::get<I>(t), 0), 0)...};
+}
+inline void show_fields(std::ostream&) {}
+template <typename T, typename... Ts>
+void show_fields(std::ostream& out, const char* name, const T& x, const Ts&... rest) {
+  out << name << '=';
+  show(out, x, 0);
+  out << (sizeof...(rest) ? ", " : "");
+  show_fields(out, rest...);
+}
+template <typename... Ts>
+std::string record(const Ts&... fields) {
+  std::stringstream out;
+  out << '(';
+  show_fields(out, fields...);
+  out << ')';
+  return out.str();
+}
+}
+namespace std {
+template <typename... Ts>
+ostream& operator<<(ostream& out, const tuple<Ts...>& t) {
+  out << '(';
+  tako::show_tuple(out, t, index_sequence_for<Ts...>{});
+  return out << ')';
+}
+}
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_c_xs = tako::list("a", "b");
+  const auto _tmp_h_c_m = tako::to_map(tako::list("a"), tako::list(1));
+  const auto _tmp_h_c_s = tako::to_set(tako::list("x", "y"));
+  const auto _tmp_h_c_f = tako::list(tako::f64{1.5}, tako::f64{2.0});
+  const auto _tmp_h_c_t = std::make_tuple(1, "a");
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", _tmp_h_c_xs), " "), _tmp_h_c_m), " "), _tmp_h_c_s), " "), _tmp_h_c_f), " "), _tmp_h_c_t), " "), tako::list(_tmp_h_c_t)), "\n"));
+  return 0;
+}
@@ -0,0 +1,14 @@
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_later2_origin = [&]() {
+    return _tmp_h_later2_Pt(0, 0);
+  };
+  struct _tmp_h_later2_Point { unsigned int tag : 1; int Pt_x; int Pt_y; };
+  const auto _tmp_h_later2_Pt = [&](
+    const auto _tmp_h_later2_Pt_x,
+    const auto _tmp_h_later2_Pt_y
+  ) {
+    return _tmp_h_later2_Point{0, _tmp_h_later2_Pt_x, _tmp_h_later2_Pt_y};
+  };
+  return [&](const auto tako_match) { return [&](const auto _tmp_h_later2___Pt_x, const auto _tmp_h_later2___Pt_y) { return (_tmp_h_later2___Pt_x+1); }(tako_match.Pt_x, tako_match.Pt_y); }(_tmp_h_later2_origin());
+}
@@ -0,0 +1,9 @@
+
+int main(int argc, char* argv[]) {
+  auto _tmp_h_m1_c = 1;
+  const auto _tmp_h_m1_f = [&]() {
+    return _tmp_h_m1_c;
+  };
+  (_tmp_h_m1_c+=5);
+  return _tmp_h_m1_f();
+}
@@ -0,0 +1,12 @@
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_m2_g = [&]() {
+    auto _tmp_h_m2_g__1_c = 1;
+    const auto _tmp_h_m2_g__1_f = [=]() {
+      return _tmp_h_m2_g__1_c;
+    };
+    (_tmp_h_m2_g__1_c+=5);
+    return _tmp_h_m2_g__1_f();
+  };
+  return _tmp_h_m2_g();
+}
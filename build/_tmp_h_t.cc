@@ -0,0 +1,92 @@
+#include <iostream>
+#include <ostream>
+#include <sstream>
+#include <string>
+#include <tuple>
+#include <utility>
+namespace tako {
+template <typename T>
+auto show(std::ostream& out, const T& x, int) -> decltype(x.to_string(), void()) {
+  out << x.to_string();
+}
+template <typename T>
+void show(std::ostream& out, const T& x, long) {
+  out << x;
+}
+inline void show(std::ostream& out, const std::string& s, int) {
+  out << '\'' << s << '\'';
+}
+inline void show(std::ostream& out, const char* s, int) {
+  out << '\'' << s << '\'';
+}
+template <typename T, size_t... I>
+void show_tuple(std::ostream& out, const T& t, std::index_sequence<I...>) {
+  (void)std::initializer_list<int>{(out << (I ? ", " : ""), show(out, std::get<I>(t), 0), 0)...};
+}
+inline void show_fields(std::ostream&) {}
+template <typename T, typename... Ts>
+void show_fields(std::ostream& out, const char* name, const T& x, const Ts&... rest) {
+  out << name << '=';
+  show(out, x, 0);
+  out << (sizeof...(rest) ? ", " : "");
+  show_fields(out, rest...);
+}
+template <typename... Ts>
+std::string record(const Ts&... fields) {
+  std::stringstream out;
+  out << '(';
+  show_fields(out, fields...);
+  out << ')';
+  return out.str();
+}
+}
+namespace std {
+template <typename... Ts>
+ostream& operator<<(ostream& out, const tuple<Ts...>& t) {
+  out << '(';
+  tako::show_tuple(out, t, index_sequence_for<Ts...>{});
+  return out << ')';
+}
+}
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_t_p = std::make_tuple(1, "a");
+  const auto _tmp_h_t_r = [&](auto x_, auto y_) { struct { decltype(x_) x; decltype(y_) y; std::string to_string() const { return tako::record("x", x, "y", y); } } fields{x_, y_}; return fields; }(1, 2);
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", _tmp_h_t_p), " "), _tmp_h_t_r), "\n"));
+  return 0;
+}
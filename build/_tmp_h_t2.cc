@@ -0,0 +1,8 @@
+#include <iostream>
+#include <tuple>
+
+int main(int argc, char* argv[]) {
+  const auto _tmp_h_t2_p = std::make_tuple(1, "a");
+  std::cout << (_tmp_h_t2_p);
+  return 0;
+}
@@ -0,0 +1,11 @@
+
+int main(int argc, char* argv[]) {
+  struct _tmp_h_u_S { unsigned int tag : 1; int B_x; };
+  const auto _tmp_h_u_B = [&](
+    const auto _tmp_h_u_B_x
+  ) {
+    return _tmp_h_u_S{1, _tmp_h_u_B_x};
+  };
+  const auto _tmp_h_u_b = _tmp_h_u_B(3);
+  return [&](const auto tako_match) { return (tako_match.tag == 0 ? 0 : [&](const auto _tmp_h_u___B_x) { return _tmp_h_u___B_x; }(tako_match.B_x)); }(_tmp_h_u_b);
+}
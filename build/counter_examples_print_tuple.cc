@@ -0,0 +1,55 @@
+#include <iostream>
+#include <ostream>
+#include <sstream>
+#include <string>
+#include <tuple>
+#include <utility>
+namespace tako {
+template <typename T>
+auto show(std::ostream& out, const T& x, int) -> decltype(x.to_string(), void()) {
+  out << x.to_string();
+}
+template <typename T>
+void show(std::ostream& out, const T& x, long) {
+  out << x;
+}
+inline void show(std::ostream& out, const std::string& s, int) {
+  out << '\'' << s << '\'';
+}
+inline void show(std::ostream& out, const char* s, int) {
+  out << '\'' << s << '\'';
+}
+template <typename T, size_t... I>
+void show_tuple(std::ostream& out, const T& t, std::index_sequence<I...>) {
+  (void)std::initializer_list<int>{(out << (I ? ", " : ""), show(out, std::get<I>(t), 0), 0)...};
+}
+inline void show_fields(std::ostream&) {}
+template <typename T, typename... Ts>
+void show_fields(std::ostream& out, const char* name, const T& x, const Ts&... rest) {
+  out << name << '=';
+  show(out, x, 0);
+  out << (sizeof...(rest) ? ", " : "");
+  show_fields(out, rest...);
+}
+template <typename... Ts>
+std::string record(const Ts&... fields) {
+  std::stringstream out;
+  out << '(';
+  show_fields(out, fields...);
+  out << ')';
+  return out.str();
+}
+}
+namespace std {
+template <typename... Ts>
+ostream& operator<<(ostream& out, const tuple<Ts...>& t) {
+  out << '(';
+  tako::show_tuple(out, t, index_sequence_for<Ts...>{});
+  return out << ')';
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto counter_examples_print_tuple_pair = std::make_tuple(1, "a");
+  return std::cout << (counter_examples_print_tuple_pair);
+}
@@ -0,0 +1,28 @@
+
+int main(int argc, char* argv[]) {
+  auto examples_accumulator_total = 0;
+  const auto examples_accumulator_add = [&](
+    const auto examples_accumulator_add_n
+  ) {
+    return (examples_accumulator_total+=examples_accumulator_add_n);
+  };
+  const auto examples_accumulator_double = [&](
+    const auto examples_accumulator_double_n
+  ) {
+    auto examples_accumulator_double_acc = examples_accumulator_double_n;
+    (examples_accumulator_double_acc*=2);
+    return examples_accumulator_double_acc;
+  };
+  const auto examples_accumulator_halve_parity = [&](
+    const auto examples_accumulator_halve_parity_n
+  ) {
+    auto examples_accumulator_halve_parity_acc = examples_accumulator_halve_parity_n;
+    (examples_accumulator_halve_parity_acc/=2);
+    (examples_accumulator_halve_parity_acc%=2);
+    return examples_accumulator_halve_parity_acc;
+  };
+  examples_accumulator_add(1);
+  examples_accumulator_add(2);
+  examples_accumulator_add(3);
+  return (examples_accumulator_double(examples_accumulator_total)+examples_accumulator_halve_parity(examples_accumulator_total));
+}
@@ -0,0 +1,73 @@
+#include <cmath>
+#include <gmpxx.h>
+#include <ostream>
+namespace tako {
+struct bigint {
+  mpz_class value;
+  explicit bigint(const char* digits) : value(digits) {}
+  bigint(const mpz_class& value) : value(value) {}
+};
+inline bigint operator+(const bigint& l, const bigint& r) { return mpz_class(l.value + r.value); }
+inline bigint operator-(const bigint& l, const bigint& r) { return mpz_class(l.value - r.value); }
+inline bigint operator*(const bigint& l, const bigint& r) { return mpz_class(l.value * r.value); }
+inline bigint operator/(const bigint& l, const bigint& r) { return mpz_class(l.value / r.value); }
+inline bigint operator%(const bigint& l, const bigint& r) { return mpz_class(l.value % r.value); }
+inline bigint operator-(const bigint& n) { return mpz_class(-n.value); }
+inline bool operator==(const bigint& l, const bigint& r) { return l.value == r.value; }
+inline bool operator!=(const bigint& l, const bigint& r) { return l.value != r.value; }
+inline bool operator<(const bigint& l, const bigint& r) { return l.value < r.value; }
+inline bool operator<=(const bigint& l, const bigint& r) { return l.value <= r.value; }
+inline bool operator>(const bigint& l, const bigint& r) { return l.value > r.value; }
+inline bool operator>=(const bigint& l, const bigint& r) { return l.value >= r.value; }
+inline bigint pow(const bigint& l, const int exp) {
+  mpz_class out;
+  mpz_pow_ui(out.get_mpz_t(), l.value.get_mpz_t(), exp);
+  return out;
+}
+inline std::ostream& operator<<(std::ostream& out, const bigint& n) {
+  return out << n.value;
+}
+}
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_big_numbers_big = (tako::bigint("99999999999999999999")*tako::bigint("3"));
+  const auto examples_big_numbers_half = ((examples_big_numbers_big-tako::bigint("7"))/tako::bigint("2"));
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", examples_big_numbers_big), " "), examples_big_numbers_half), " "), pow(tako::bigint("2"), 100)), " "), (examples_big_numbers_big>examples_big_numbers_half)), "\n"));
+  return 0;
+}
@@ -0,0 +1,51 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_blocks_x = 2;
+  const auto examples_blocks_y = ([&]() {  const auto examples_blocks_y__1_x = 10;  const auto examples_blocks_y__1_z = (examples_blocks_y__1_x*3);  return (examples_blocks_y__1_z+1);})();
+  const auto examples_blocks_area = [&](
+    const auto examples_blocks_area_w,
+    const auto examples_blocks_area_h
+  ) {
+    const auto examples_blocks_area__2_size = (examples_blocks_area_w*examples_blocks_area_h);
+    return (examples_blocks_area__2_size*2);
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", examples_blocks_x), " "), examples_blocks_y), " "), examples_blocks_area(3, 4)), "\n"));
+  ([&]() {  const auto examples_blocks__3_x = 5;  std::cout << (tako::concat(tako::concat("", examples_blocks__3_x), "\n"));  return examples_blocks__3_x;})();
+  return examples_blocks_x;
+}
@@ -0,0 +1,64 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_closures_adder = [&](
+    const auto examples_closures_adder_n
+  ) {
+    return [=](  const auto examples_closures_adder_x) {  return (examples_closures_adder_x+examples_closures_adder_n);};
+  };
+  const auto examples_closures_compose = [&](
+    const auto examples_closures_compose_f,
+    const auto examples_closures_compose_g
+  ) {
+    return [=](  const auto examples_closures_compose_x) {  return examples_closures_compose_f(examples_closures_compose_g(examples_closures_compose_x));};
+  };
+  const auto examples_closures_counter = [&](
+    const auto examples_closures_counter_start
+  ) {
+    const auto examples_closures_counter_next = [=](
+      const auto examples_closures_counter_next_by
+    ) {
+      return (examples_closures_counter_start+examples_closures_counter_next_by);
+    };
+    return examples_closures_counter_next;
+  };
+  const auto examples_closures_add3 = examples_closures_adder(3);
+  const auto examples_closures_add6 = examples_closures_compose(examples_closures_add3, examples_closures_add3);
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", examples_closures_add3(4)), " "), examples_closures_add6(1)), " "), examples_closures_counter(10)(5)), "\n"));
+  return 0;
+}
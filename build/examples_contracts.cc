@@ -0,0 +1,15 @@
+#include <cstdlib>
+#include <iostream>
+[[noreturn]] void tako_contract_failure(const char* kind, const char* at, const char* expr) {
+  std::cerr << "runtime " << kind << " failed at " << at << ": " << expr << std::endl;
+  std::exit(101);
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_contracts_half = [&](
+    const auto examples_contracts_half_n
+  ) {
+    return [&](const auto examples_contracts_half_it) { return (((examples_contracts_half_it*2)==examples_contracts_half_n) ? examples_contracts_half_it : (tako_contract_failure("postcondition", "examples/contracts.tk at line 2, column 20", "((it*2)==n)"), examples_contracts_half_it)); }((((examples_contracts_half_n%2)==0) ? (examples_contracts_half_n/2) : (tako_contract_failure("requirement", "examples/contracts.tk at line 2, column 15", "((n%2)==0)"), (examples_contracts_half_n/2))));
+  };
+  return (examples_contracts_half(4)+examples_contracts_half(3));
+}
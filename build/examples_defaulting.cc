@@ -0,0 +1,9 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_defaulting_x = [&](
+    const auto examples_defaulting_x_y
+  ) {
+    return (examples_defaulting_x_y ? (examples_defaulting_x_y*2) : 1);
+  };
+  return (((6/examples_defaulting_x(3))+" ")+!(examples_defaulting_x()));
+}
@@ -0,0 +1,7 @@
+#include <iostream>
+
+int main(int argc, char* argv[]) {
+  std::cerr << ("This is an error!\n");
+  std::cout << ("This is a message!\n");
+  return 0;
+}
@@ -0,0 +1,112 @@
+#include <functional>
+#include <iostream>
+#include <string>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+#include <type_traits>
+
+int main(int argc, char* argv[]) {
+  const auto examples_interfaces_size = [&](
+    const auto tako_it
+  ) {
+    return std::decay_t<decltype(tako_it)>::tako_size()(tako_it);
+  };
+  struct examples_interfaces_Point { unsigned int tag : 1; int Pt_x; int Pt_y; static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)>& tako_add() { static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)> impl; return impl; }; static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)>& tako_sub() { static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)> impl; return impl; }; static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)>& tako_mul() { static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)> impl; return impl; }; static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)>& tako_div() { static std::function<examples_interfaces_Point(examples_interfaces_Point, examples_interfaces_Point)> impl; return impl; }; examples_interfaces_Point operator+(const examples_interfaces_Point& right) const { return tako_add()(*this, right); }; examples_interfaces_Point operator-(const examples_interfaces_Point& right) const { return tako_sub()(*this, right); }; examples_interfaces_Point operator*(const examples_interfaces_Point& right) const { return tako_mul()(*this, right); }; examples_interfaces_Point operator/(const examples_interfaces_Point& right) const { return tako_div()(*this, right); }; static std::function<std::string(examples_interfaces_Point)>& tako_to_string() { static std::function<std::string(examples_interfaces_Point)> impl; return impl; }; std::string to_string() const { return tako_to_string()(*this); }; static std::function<int(examples_interfaces_Point)>& tako_size() { static std::function<int(examples_interfaces_Point)> impl; return impl; }; };
+  const auto examples_interfaces_Pt = [&](
+    const auto examples_interfaces_Pt_x,
+    const auto examples_interfaces_Pt_y
+  ) {
+    return examples_interfaces_Point{0, examples_interfaces_Pt_x, examples_interfaces_Pt_y};
+  };
+  const auto examples_interfaces_x_of = [&](
+    const auto examples_interfaces_x_of_p
+  ) {
+    return [&](const auto tako_match) { return [&](const auto examples_interfaces_x_of___Pt_x, const auto examples_interfaces_x_of___Pt_y) { return examples_interfaces_x_of___Pt_x; }(tako_match.Pt_x, tako_match.Pt_y); }(examples_interfaces_x_of_p);
+  };
+  const auto examples_interfaces_y_of = [&](
+    const auto examples_interfaces_y_of_p
+  ) {
+    return [&](const auto tako_match) { return [&](const auto examples_interfaces_y_of___Pt_x, const auto examples_interfaces_y_of___Pt_y) { return examples_interfaces_y_of___Pt_y; }(tako_match.Pt_x, tako_match.Pt_y); }(examples_interfaces_y_of_p);
+  };
+  const auto examples_interfaces_Point_Number_add = [&](
+    const auto examples_interfaces_Point_Number_add_left,
+    const auto examples_interfaces_Point_Number_add_right
+  ) {
+    return examples_interfaces_Pt((examples_interfaces_x_of(examples_interfaces_Point_Number_add_left)+examples_interfaces_x_of(examples_interfaces_Point_Number_add_right)), (examples_interfaces_y_of(examples_interfaces_Point_Number_add_left)+examples_interfaces_y_of(examples_interfaces_Point_Number_add_right)));
+  };
+  examples_interfaces_Point::tako_add() = examples_interfaces_Point_Number_add;
+  const auto examples_interfaces_Point_Number_sub = [&](
+    const auto examples_interfaces_Point_Number_sub_left,
+    const auto examples_interfaces_Point_Number_sub_right
+  ) {
+    return examples_interfaces_Pt((examples_interfaces_x_of(examples_interfaces_Point_Number_sub_left)-examples_interfaces_x_of(examples_interfaces_Point_Number_sub_right)), (examples_interfaces_y_of(examples_interfaces_Point_Number_sub_left)-examples_interfaces_y_of(examples_interfaces_Point_Number_sub_right)));
+  };
+  examples_interfaces_Point::tako_sub() = examples_interfaces_Point_Number_sub;
+  const auto examples_interfaces_Point_Number_mul = [&](
+    const auto examples_interfaces_Point_Number_mul_left,
+    const auto examples_interfaces_Point_Number_mul_right
+  ) {
+    return examples_interfaces_Pt((examples_interfaces_x_of(examples_interfaces_Point_Number_mul_left)*examples_interfaces_x_of(examples_interfaces_Point_Number_mul_right)), (examples_interfaces_y_of(examples_interfaces_Point_Number_mul_left)*examples_interfaces_y_of(examples_interfaces_Point_Number_mul_right)));
+  };
+  examples_interfaces_Point::tako_mul() = examples_interfaces_Point_Number_mul;
+  const auto examples_interfaces_Point_Number_div = [&](
+    const auto examples_interfaces_Point_Number_div_left,
+    const auto examples_interfaces_Point_Number_div_right
+  ) {
+    return examples_interfaces_Pt((examples_interfaces_x_of(examples_interfaces_Point_Number_div_left)/examples_interfaces_x_of(examples_interfaces_Point_Number_div_right)), (examples_interfaces_y_of(examples_interfaces_Point_Number_div_left)/examples_interfaces_y_of(examples_interfaces_Point_Number_div_right)));
+  };
+  examples_interfaces_Point::tako_div() = examples_interfaces_Point_Number_div;
+  const auto examples_interfaces_Point_Display_to_string = [&](
+    const auto examples_interfaces_Point_Display_to_string_it
+  ) {
+    return tako::concat(tako::concat(tako::concat(tako::concat("(", examples_interfaces_x_of(examples_interfaces_Point_Display_to_string_it)), ", "), examples_interfaces_y_of(examples_interfaces_Point_Display_to_string_it)), ")");
+  };
+  examples_interfaces_Point::tako_to_string() = examples_interfaces_Point_Display_to_string;
+  const auto examples_interfaces_Point_Sized_size = [&](
+    const auto examples_interfaces_Point_Sized_size_it
+  ) {
+    return (examples_interfaces_x_of(examples_interfaces_Point_Sized_size_it)*examples_interfaces_y_of(examples_interfaces_Point_Sized_size_it));
+  };
+  examples_interfaces_Point::tako_size() = examples_interfaces_Point_Sized_size;
+  const auto examples_interfaces_describe = [&](
+    const auto examples_interfaces_describe_it
+  ) {
+    return tako::concat(tako::concat(tako::concat("", examples_interfaces_describe_it), " has size "), examples_interfaces_size(examples_interfaces_describe_it));
+  };
+  const auto examples_interfaces_total = (examples_interfaces_Pt(1, 2)+(examples_interfaces_Pt(3, 4)*examples_interfaces_Pt(2, 2)));
+  std::cout << (tako::concat(tako::concat("", examples_interfaces_describe(examples_interfaces_total)), "\n"));
+  return 0;
+}
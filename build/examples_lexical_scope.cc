@@ -0,0 +1,8 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_lexical_scope_x = 1;
+  const auto examples_lexical_scope_f = [&]() {
+    return examples_lexical_scope_x;
+  };
+  return ([&]() {  const auto examples_lexical_scope__1_x = 2;  return ((examples_lexical_scope_f()*10)+examples_lexical_scope__1_x);})();
+}
@@ -0,0 +1,18 @@
+#include <memory>
+
+int main(int argc, char* argv[]) {
+  struct examples_linked_lists_Ints { unsigned int tag : 1; int Link_head; std::shared_ptr<const examples_linked_lists_Ints> Link_tail; };
+  const auto examples_linked_lists_End = examples_linked_lists_Ints{0, {}, {}};
+  const auto examples_linked_lists_Link = [&](
+    const auto examples_linked_lists_Link_head,
+    const auto examples_linked_lists_Link_tail
+  ) {
+    return examples_linked_lists_Ints{1, examples_linked_lists_Link_head, std::make_shared<const examples_linked_lists_Ints>(examples_linked_lists_Link_tail)};
+  };
+  const auto examples_linked_lists_second = [&](
+    const auto examples_linked_lists_second_it
+  ) {
+    return [&](const auto tako_match) { return (tako_match.tag == 1 ? [&](const auto examples_linked_lists_second___Link_tail) { return [&](const auto tako_match) { return (tako_match.tag == 1 ? [&](const auto examples_linked_lists_second___Link___Link_head) { return examples_linked_lists_second___Link___Link_head; }(tako_match.Link_head) : 0); }(examples_linked_lists_second___Link_tail); }(*tako_match.Link_tail) : 0); }(examples_linked_lists_second_it);
+  };
+  return examples_linked_lists_second(examples_linked_lists_Link(1, examples_linked_lists_Link(2, examples_linked_lists_End)));
+}
@@ -0,0 +1,15 @@
+
+int main(int argc, char* argv[]) {
+  struct examples_matched_constructors_Light { unsigned int tag : 1; int On_level; };
+  const auto examples_matched_constructors_On = [&](
+    const auto examples_matched_constructors_On_level
+  ) {
+    return examples_matched_constructors_Light{1, examples_matched_constructors_On_level};
+  };
+  const auto examples_matched_constructors_brightness = [&](
+    const auto examples_matched_constructors_brightness_it
+  ) {
+    return [&](const auto tako_match) { return (tako_match.tag == 0 ? 0 : [&](const auto examples_matched_constructors_brightness___On_level) { return examples_matched_constructors_brightness___On_level; }(tako_match.On_level)); }(examples_matched_constructors_brightness_it);
+  };
+  return examples_matched_constructors_brightness(examples_matched_constructors_On(3));
+}
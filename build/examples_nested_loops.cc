@@ -0,0 +1,60 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  auto examples_nested_loops_steps = 0;
+  auto examples_nested_loops_i = 0;
+  while((examples_nested_loops_i<2)) {
+    auto examples_nested_loops__1_tako_while__2_j = 0;
+    while((examples_nested_loops__1_tako_while__2_j<2)) {
+      (examples_nested_loops_steps+=1);
+      (examples_nested_loops__1_tako_while__2_j+=1);
+    }
+    (examples_nested_loops_i+=1);
+  }
+  const auto examples_nested_loops_count = [&](
+    const auto examples_nested_loops_count_n
+  ) {
+    auto examples_nested_loops_count__5_k = 0;
+    while((examples_nested_loops_count__5_k<examples_nested_loops_count_n)) {
+      (examples_nested_loops_count__5_k+=1);
+    }
+    return examples_nested_loops_count__5_k;
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", examples_nested_loops_steps), " "), examples_nested_loops_count(100000)), "\n"));
+  return examples_nested_loops_steps;
+}
@@ -0,0 +1,5 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_optional_semis_x = 3;
+  return examples_optional_semis_x;
+}
@@ -0,0 +1,5 @@
+#include <cmath>
+
+int main(int argc, char* argv[]) {
+  return (3*pow(2, 2));
+}
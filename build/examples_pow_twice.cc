@@ -0,0 +1,5 @@
+#include <cmath>
+
+int main(int argc, char* argv[]) {
+  return pow(2, pow(3, 2));
+}
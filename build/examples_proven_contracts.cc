@@ -0,0 +1,20 @@
+#include <cstdlib>
+#include <iostream>
+[[noreturn]] void tako_contract_failure(const char* kind, const char* at, const char* expr) {
+  std::cerr << "runtime " << kind << " failed at " << at << ": " << expr << std::endl;
+  std::exit(101);
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_proven_contracts_positive = [&](
+    const auto examples_proven_contracts_positive_n
+  ) {
+    return [&](const auto examples_proven_contracts_positive_it) { return ((examples_proven_contracts_positive_it>0) ? examples_proven_contracts_positive_it : (tako_contract_failure("postcondition", "examples/proven_contracts.tk at line 2, column 21", "(it>0)"), examples_proven_contracts_positive_it)); }(examples_proven_contracts_positive_n);
+  };
+  const auto examples_proven_contracts_halve = [&](
+    const auto examples_proven_contracts_halve_n
+  ) {
+    return ((examples_proven_contracts_halve_n>2) ? (examples_proven_contracts_halve_n/2) : (tako_contract_failure("requirement", "examples/proven_contracts.tk at line 4, column 13", "(n>2)"), (examples_proven_contracts_halve_n/2)));
+  };
+  return (examples_proven_contracts_positive(3)+examples_proven_contracts_halve(8));
+}
@@ -0,0 +1,4 @@
+
+int main(int argc, char* argv[]) {
+  return (((2*3)*(9-3))/3);
+}
@@ -0,0 +1,5 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_x_plus_1_x = 3;
+  return (examples_x_plus_1_x+1);
+}
@@ -1,11 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
 use crate::database::DBStorage;
 use crate::errors::TError;
 use crate::location::*;
-use crate::primitives::{unit_type, Val};
+use crate::primitives::{boolean, int32, unit_type, Prim, Val};
 use crate::symbol_table::Table;
 
 impl HasInfo for TError {
@@ -14,6 +14,10 @@ impl HasInfo for TError {
         match self {
             CppCompilerError(_, _, info) => info.clone(),
             UnknownSymbol(_, info, _) => info.clone(),
+            UnknownModule(_, info) => info.clone(),
+            UnknownMember(_, info) => info.clone(),
+            UnknownField(_, _, info) => info.clone(),
+            UnknownMethod(_, info) => info.clone(),
             OutOfScopeTypeVariable(_, info) => info.clone(),
             UnknownInfixOperator(_, info) => info.clone(),
             UnknownPrefixOperator(_, info) => info.clone(),
@@ -23,7 +27,30 @@ impl HasInfo for TError {
             StaticPointerCardinality(info) => info.clone(),
             TypeMismatch(_, _, info) => info.clone(),
             TypeMismatch2(_, _, _, info) => info.clone(),
+            UnexpectedType(_, _, _, info) => info.clone(),
+            UnexpectedEffects(_, info) => info.clone(),
             RequirementFailure(info) => info.clone(),
+            PostconditionFailure(info) => info.clone(),
+            NonExhaustiveMatch(_, info) => info.clone(),
+            UnreachableClause(_, info) => info.clone(),
+            IncompleteClauses(_, info) => info.clone(),
+            ClauseArity(_, info) => info.clone(),
+            IncompleteImplementation(_, info) => info.clone(),
+            MissingImplementation(_, _, info) => info.clone(),
+            DuplicateImplementation(_, _, info) => info.clone(),
+            DuplicateConstructor(_, _, info) => info.clone(),
+            IndexOutOfBounds(_, _, _, info) => info.clone(),
+            DivisionByZero(info) => info.clone(),
+            MissingKey(_, info) => info.clone(),
+            MismatchedKeys(_, _, info) => info.clone(),
+            ImmutableAssignment(_, info) => info.clone(),
+            CapturedAssignment(_, info) => info.clone(),
+            CapturedVariable(_, info) => info.clone(),
+            HandlerExit(_, _, info) => info.clone(),
+            RecursionLimit(_, info) => info.clone(),
+            MisplacedResume(info) => info.clone(),
+            ResumeOutsideHandler(info) => info.clone(),
+            UnsupportedInCpp(_, info) => info.clone(),
             ParseError(_, info) => info.clone(),
             InternalError(_, info) => info.clone(),
             ExpectedLetNode(node) => node.get_info(),
@@ -35,6 +62,10 @@ impl HasInfo for TError {
         match self {
             CppCompilerError(_, _, ref mut info) => info,
             UnknownSymbol(_, ref mut info, _) => info,
+            UnknownModule(_, ref mut info) => info,
+            UnknownMember(_, ref mut info) => info,
+            UnknownField(_, _, ref mut info) => info,
+            UnknownMethod(_, ref mut info) => info,
             OutOfScopeTypeVariable(_, ref mut info) => info,
             UnknownInfixOperator(_, ref mut info) => info,
             UnknownPrefixOperator(_, ref mut info) => info,
@@ -44,7 +75,30 @@ impl HasInfo for TError {
             StaticPointerCardinality(ref mut info) => info,
             TypeMismatch(_, _, ref mut info) => info,
             TypeMismatch2(_, _, _, ref mut info) => info,
+            UnexpectedType(_, _, _, ref mut info) => info,
+            UnexpectedEffects(_, ref mut info) => info,
             RequirementFailure(ref mut info) => info,
+            PostconditionFailure(ref mut info) => info,
+            NonExhaustiveMatch(_, ref mut info) => info,
+            UnreachableClause(_, ref mut info) => info,
+            IncompleteClauses(_, ref mut info) => info,
+            ClauseArity(_, ref mut info) => info,
+            IncompleteImplementation(_, ref mut info) => info,
+            MissingImplementation(_, _, ref mut info) => info,
+            DuplicateImplementation(_, _, ref mut info) => info,
+            DuplicateConstructor(_, _, ref mut info) => info,
+            IndexOutOfBounds(_, _, _, ref mut info) => info,
+            DivisionByZero(ref mut info) => info,
+            MissingKey(_, ref mut info) => info,
+            MismatchedKeys(_, _, ref mut info) => info,
+            ImmutableAssignment(_, ref mut info) => info,
+            CapturedAssignment(_, ref mut info) => info,
+            CapturedVariable(_, ref mut info) => info,
+            HandlerExit(_, _, ref mut info) => info,
+            RecursionLimit(_, ref mut info) => info,
+            MisplacedResume(ref mut info) => info,
+            ResumeOutsideHandler(ref mut info) => info,
+            UnsupportedInCpp(_, ref mut info) => info,
             ParseError(_, ref mut info) => info,
             InternalError(_, ref mut info) => info,
             ExpectedLetNode(ref mut node) => node.get_mut_info(),
@@ -148,7 +202,6 @@ pub struct Let {
     pub value: Box<Node>,
     pub args: Option<Vec<Let>>,
     pub info: Info,
-    // TODO; support captures
 }
 
 impl Let {
@@ -168,6 +221,835 @@ impl Let {
     }
 }
 
+// A constructor of a data type, e.g. `Circle(r: I32)` in `type Shape = Circle(r: I32) | Empty`.
+#[derive(Debug, Clone)]
+pub struct Constructor {
+    pub name: String,
+    pub fields: Vec<Let>,
+    pub info: Info,
+}
+
+// Finds the constructors in the body of a `type` declaration.
+pub fn constructors(expr: &Node) -> Result<Vec<Constructor>, TError> {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == "|" => {
+            let mut ctors = constructors(left)?;
+            ctors.extend(constructors(right)?);
+            Ok(ctors)
+        }
+        Node::SymNode(sym) => Ok(vec![Constructor {
+            name: sym.name.clone(),
+            fields: vec![],
+            info: sym.get_info(),
+        }]),
+        Node::ApplyNode(Apply { inner, args, info }) => match &**inner {
+            Node::SymNode(sym) => Ok(vec![Constructor {
+                name: sym.name.clone(),
+                fields: args.clone(),
+                info: info.clone(),
+            }]),
+            _ => Err(TError::ParseError(
+                format!("Expected a constructor, found {}", expr),
+                info.clone(),
+            )),
+        },
+        _ => Err(TError::ParseError(
+            format!("Expected a constructor, found {}", expr),
+            expr.get_info(),
+        )),
+    }
+}
+
+// An operation declared by an interface, e.g. `area(it: Self): I32` in `interface Sized = ...`.
+// The annotation on the operation (in `info.ty`) is the type of its result.
+#[derive(Debug, Clone)]
+pub struct Signature {
+    pub name: String,
+    pub args: Vec<Let>,
+    pub info: Info,
+}
+
+// Finds the operations in the body of an `interface` declaration.
+pub fn signatures(expr: &Node) -> Result<Vec<Signature>, TError> {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == "," => {
+            let mut sigs = signatures(left)?;
+            sigs.extend(signatures(right)?);
+            Ok(sigs)
+        }
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "()" => signatures(inner),
+        Node::ApplyNode(Apply { inner, args, info }) => match &**inner {
+            Node::SymNode(sym) => Ok(vec![Signature {
+                name: sym.name.clone(),
+                args: args.clone(),
+                info: info.clone(),
+            }]),
+            _ => Err(TError::ParseError(
+                format!("Expected an operation, found {}", expr),
+                info.clone(),
+            )),
+        },
+        _ => Err(TError::ParseError(
+            format!("Expected an operation, found {}", expr),
+            expr.get_info(),
+        )),
+    }
+}
+
+// An implementation of an interface for a type, e.g. `impl Display(Point) = (to_string(it) = ...)`.
+#[derive(Debug, Clone)]
+pub struct Implementation<'a> {
+    pub interface: &'a str,
+    pub ty: &'a str,
+    pub methods: Vec<&'a Let>,
+}
+
+impl<'a> Implementation<'a> {
+    // Methods are defined in a scope of their own, so that types can implement the same interface.
+    pub fn scope(&self) -> Symbol {
+        Symbol::new(&format!("{}_{}", self.ty, self.interface))
+    }
+}
+
+// Reads the interface, type and methods of an `impl` declaration.
+pub fn implementation(decl: &Let) -> Result<Implementation<'_>, TError> {
+    let ty = match decl.args.as_deref() {
+        Some([ty]) => &ty.name,
+        _ => {
+            return Err(TError::ParseError(
+                format!("Expected a type to implement `{}` for", decl.name),
+                decl.get_info(),
+            ))
+        }
+    };
+    let mut methods = vec![];
+    definitions_in(&decl.value, &mut methods);
+    Ok(Implementation {
+        interface: &decl.name,
+        ty,
+        methods,
+    })
+}
+
+// Collects the definitions in a sequence, e.g. the clauses of a handler.
+pub fn definitions_in<'a>(expr: &'a Node, defs: &mut Vec<&'a Let>) {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == "," || name == ";" => {
+            definitions_in(left, defs);
+            definitions_in(right, defs);
+        }
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "()" => definitions_in(inner, defs),
+        Node::LetNode(def) => defs.push(def),
+        _ => {}
+    }
+}
+
+// Finds the names of the symbols used in an expression.
+pub fn symbols_in(expr: &Node, names: &mut BTreeSet<String>) {
+    match expr {
+        Node::SymNode(sym) => {
+            names.insert(sym.name.clone());
+        }
+        Node::ValNode(..) => {}
+        Node::ApplyNode(apply) => {
+            symbols_in(&apply.inner, names);
+            for arg in apply.args.iter() {
+                symbols_in(&arg.value, names);
+            }
+        }
+        Node::AbsNode(abs) => symbols_in(&abs.value, names),
+        Node::LetNode(def) => {
+            for arg in def.args.iter().flatten() {
+                symbols_in(&arg.value, names);
+            }
+            symbols_in(&def.value, names);
+        }
+        Node::UnOpNode(op) => symbols_in(&op.inner, names),
+        Node::BinOpNode(op) => {
+            symbols_in(&op.left, names);
+            symbols_in(&op.right, names);
+        }
+    }
+}
+
+// Splits a handler clause into its statements.
+pub fn statements(expr: &Node) -> Vec<&Node> {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == ";" => {
+            let mut stmts = statements(left);
+            stmts.extend(statements(right));
+            stmts
+        }
+        expr => vec![expr],
+    }
+}
+
+pub fn is_resume(expr: &Node) -> bool {
+    matches!(expr, Node::ApplyNode(Apply { inner, .. }) if matches!(&**inner, Node::SymNode(Sym { name, .. }) if name == "resume"))
+}
+
+// Finds a statement that resumes, e.g. `resume(it=x)` or `r = resume(it=x)`, with the name it binds.
+pub fn resume_statement(stmt: &Node) -> Option<(Option<String>, &Node)> {
+    match stmt {
+        Node::LetNode(Let {
+            name,
+            args: None,
+            value,
+            ..
+        }) if is_resume(value) => Some((Some(name.clone()), value)),
+        stmt if is_resume(stmt) => Some((None, stmt)),
+        _ => None,
+    }
+}
+
+pub fn uses_resume(expr: &Node) -> bool {
+    let mut names = BTreeSet::new();
+    symbols_in(expr, &mut names);
+    names.contains("resume")
+}
+
+// Checks that a `resume` is the last step of an expression, so nothing is waiting for its value.
+pub fn resumes_last(expr: &Node) -> bool {
+    match expr {
+        Node::ApplyNode(Apply { args, .. }) if is_resume(expr) => {
+            !args.iter().any(|arg| uses_resume(&arg.value))
+        }
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if matches!(name.as_str(), ";" | "-|") => !uses_resume(left) && resumes_last(right),
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == "?" => resumes_last(left) && resumes_last(right),
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" => resumes_last(inner),
+        expr => !uses_resume(expr),
+    }
+}
+
+// Handler clauses resume at most once, either as a statement of their own or as their last step.
+pub fn check_resumes(clause: &Node) -> Result<(), TError> {
+    let stmts = statements(clause);
+    let at = match stmts.iter().position(|stmt| uses_resume(stmt)) {
+        Some(at) => at,
+        None => return Ok(()),
+    };
+    let rest = &stmts[at + 1..];
+    if rest.iter().any(|stmt| uses_resume(stmt)) {
+        return Err(TError::MisplacedResume(rest[0].get_info()));
+    }
+    if resume_statement(stmts[at]).is_some() || (rest.is_empty() && resumes_last(stmts[at])) {
+        return Ok(());
+    }
+    Err(TError::MisplacedResume(stmts[at].get_info()))
+}
+
+// Finds the elements of a list literal, e.g. `1, 2, 3` in `[1, 2, 3]`.
+pub fn elements(expr: &Node) -> Vec<&Node> {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == "," => {
+            let mut elems = elements(left);
+            elems.extend(elements(right));
+            elems
+        }
+        Node::ValNode(Val::Product(tys), _) if tys.is_empty() => vec![],
+        expr => vec![expr],
+    }
+}
+
+// A tuple, e.g. `(a, b=2)`, see `tuple_fields`.
+pub fn tuple(inner: Node, info: &Info) -> Node {
+    UnOp {
+        name: "()".to_string(),
+        inner: Box::new(inner),
+        info: info.clone(),
+    }
+    .into_node()
+}
+
+// Finds the fields of a tuple, naming positional fields by their position, e.g. `(3, 4, y=5)` has
+// the fields `0`, `1` and `y`. Positional fields come before named ones, and names are unique.
+pub fn tuple_fields(expr: &Node) -> Result<Vec<(String, &Node)>, TError> {
+    fn flatten<'a>(expr: &'a Node, elems: &mut Vec<&'a Node>) {
+        match expr {
+            Node::BinOpNode(BinOp {
+                name, left, right, ..
+            }) if name == "," => {
+                flatten(left, elems);
+                flatten(right, elems);
+            }
+            expr => elems.push(expr),
+        }
+    }
+    let mut elems = vec![];
+    flatten(expr, &mut elems);
+    let mut fields: Vec<(String, &Node)> = vec![];
+    for elem in elems {
+        let name = match elem {
+            Node::LetNode(def) => def.name.clone(),
+            _ if fields
+                .iter()
+                .any(|(_, field)| matches!(field, Node::LetNode(_))) =>
+            {
+                return Err(TError::ParseError(
+                    format!("Expected a name for {}, as it follows a named field", elem),
+                    elem.get_info(),
+                ))
+            }
+            _ => fields.len().to_string(),
+        };
+        if fields.iter().any(|(field, _)| *field == name) {
+            return Err(TError::ParseError(
+                format!("Duplicate field `{}`", name),
+                elem.get_info(),
+            ));
+        }
+        fields.push((name, elem));
+    }
+    Ok(fields)
+}
+
+// The value of a field in a tuple, e.g. `px` in `x=px`.
+pub fn field_value(elem: &Node) -> &Node {
+    match elem {
+        Node::LetNode(def) if def.args.is_none() => &def.value,
+        elem => elem,
+    }
+}
+
+// Shows a pattern as it was written, e.g. `(a, (b, c))` or `(x=px, y=py)`.
+pub fn show_pattern(pattern: &Node) -> String {
+    match pattern {
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "()" => {
+            let fields = tuple_fields(inner).unwrap_or_default();
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(field, elem)| match elem {
+                    Node::LetNode(_) => format!("{}={}", field, show_pattern(field_value(elem))),
+                    elem => show_pattern(elem),
+                })
+                .collect();
+            format!("({})", fields.join(", "))
+        }
+        pattern => format!("{}", pattern),
+    }
+}
+
+// `let (a, (b, c)) = value` defines each name in the pattern as a field of the value, i.e.
+// `let_a_b_c = value; a = let_a_b_c.0; b = let_a_b_c.1.0; c = let_a_b_c.1.1`.
+// Named fields are bound with `field=name`, e.g. `let (x=px, y=py) = point`.
+pub fn destructure(pattern: &Node, value: Node, info: &Info) -> Result<Node, TError> {
+    fn bindings(
+        pattern: &Node,
+        fields: &mut Vec<String>,
+        out: &mut Vec<(String, Vec<String>)>,
+    ) -> Result<(), TError> {
+        match pattern {
+            Node::SymNode(sym) => out.push((sym.name.clone(), fields.clone())),
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "()" => {
+                for (field, elem) in tuple_fields(inner)? {
+                    fields.push(field);
+                    bindings(field_value(elem), fields, out)?;
+                    fields.pop();
+                }
+            }
+            _ => {
+                return Err(TError::ParseError(
+                    format!("Expected a name or a tuple of names, found {}", pattern),
+                    pattern.get_info(),
+                ))
+            }
+        }
+        Ok(())
+    }
+    let mut out = vec![];
+    bindings(pattern, &mut vec![], &mut out)?;
+    let names: Vec<&str> = out.iter().map(|(name, _)| name.as_str()).collect();
+    let whole = format!("let_{}", names.join("_"));
+    // The pattern is kept, so that the type checker can check it against the value's type.
+    let mut node = Let {
+        name: whole.clone(),
+        args: None,
+        value: Box::new(value),
+        info: Info {
+            pattern: Some(Box::new(pattern.clone())),
+            ..info.clone()
+        },
+    }
+    .into_node();
+    for (name, fields) in out {
+        let access = fields.iter().fold(sym(&whole, info), |access, field| {
+            let field = match field.parse() {
+                Ok(index) => int32(index).into_node(),
+                Err(_) => sym(field, info),
+            };
+            bin_op(".", access, field, info)
+        });
+        let def = Let {
+            name,
+            args: None,
+            value: Box::new(access),
+            info: info.clone(),
+        };
+        node = bin_op(";", node, def.into_node(), info);
+    }
+    Ok(node)
+}
+
+// Indexing into a function calls it, e.g. `argv[1]` is `argv(it=1)`.
+pub fn index_call(expr: &BinOp) -> Node {
+    Apply {
+        inner: expr.left.clone(),
+        args: vec![Let {
+            name: "it".to_string(),
+            args: None,
+            info: expr.right.get_info(),
+            value: expr.right.clone(),
+        }],
+        info: expr.get_info(),
+    }
+    .into_node()
+}
+
+// `x.f(y)` calls `f` with `x` as its first argument, i.e. `f(it=x, y)`.
+pub fn method_call(expr: &BinOp) -> Option<Apply> {
+    match (expr.name.as_str(), &*expr.right) {
+        (".", Node::ApplyNode(call)) => {
+            let mut args = vec![Let {
+                name: "it".to_string(),
+                args: None,
+                info: expr.left.get_info(),
+                value: expr.left.clone(),
+            }];
+            args.extend(call.args.iter().cloned());
+            Some(Apply {
+                inner: call.inner.clone(),
+                args,
+                info: expr.get_info(),
+            })
+        }
+        _ => None,
+    }
+}
+
+// Reports a method call to an unknown function as an unknown method.
+pub fn method_error(call: &Apply, err: TError) -> TError {
+    match (err, &*call.inner) {
+        (TError::UnknownSymbol(name, info, _), Node::SymNode(method)) if name == method.name => {
+            TError::UnknownMethod(name, info)
+        }
+        (err, _) => err,
+    }
+}
+
+// The field that `value.field` (or `value?.field`) accesses, e.g. `x` in `point.x` or `0` in `pair.0`.
+pub fn field_name(expr: &BinOp) -> Option<String> {
+    match (expr.name.as_str(), &*expr.right) {
+        ("." | "?.", Node::SymNode(field)) => Some(field.name.clone()),
+        ("." | "?.", Node::ValNode(Val::PrimVal(Prim::I32(index)), _)) => Some(index.to_string()),
+        _ => None,
+    }
+}
+
+// `value?.rest` is `value.rest`, once `value` is known not to be `()`.
+pub fn optional_access(expr: &BinOp, value: Node) -> BinOp {
+    BinOp {
+        name: ".".to_string(),
+        left: Box::new(value),
+        right: expr.right.clone(),
+        info: expr.get_info(),
+    }
+}
+
+// Whether an expression is `()`, i.e. a missing optional value, possibly in a block.
+pub fn is_none(expr: &Node) -> bool {
+    match expr {
+        Node::ValNode(val, _) => *val == unit_type(),
+        Node::UnOpNode(op) if op.name == "{}" => is_none(&op.inner),
+        _ => false,
+    }
+}
+
+// The variable that a condition like `x != ()` checks for a value, and whether the variable has a
+// value when the condition holds (i.e. `true` for `x != ()` and `false` for `x == ()`).
+pub fn null_checked(cond: &Node) -> Option<(&Sym, bool)> {
+    let (operands, has_value) = match operands(cond, "!=") {
+        Some(operands) => (operands, true),
+        None => (operands(cond, "==")?, false),
+    };
+    match operands {
+        (Node::SymNode(sym), other) | (other, Node::SymNode(sym)) if is_none(other) => {
+            Some((sym, has_value))
+        }
+        _ => None,
+    }
+}
+
+fn sym(name: &str, info: &Info) -> Node {
+    Sym {
+        name: name.to_string(),
+        info: info.clone(),
+    }
+    .into_node()
+}
+
+fn bin_op(name: &str, left: Node, right: Node, info: &Info) -> Node {
+    BinOp {
+        name: name.to_string(),
+        left: Box::new(left),
+        right: Box::new(right),
+        info: info.clone(),
+    }
+    .into_node()
+}
+
+fn block(inner: Node, info: &Info) -> Node {
+    UnOp {
+        name: "{}".to_string(),
+        inner: Box::new(inner),
+        info: info.clone(),
+    }
+    .into_node()
+}
+
+// Finds the operands of a binary operator, e.g. `a` and `b` in `a;b`.
+fn operands<'a>(expr: &'a Node, op: &str) -> Option<(&'a Node, &'a Node)> {
+    match expr {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == op => Some((left, right)),
+        _ => None,
+    }
+}
+
+// `if cond {then} else {then_else}` is `cond -| {then} ? {then_else}`.
+pub fn if_else(cond: Node, then: Node, then_else: Node, info: &Info) -> Node {
+    bin_op("?", bin_op("-|", cond, then, info), then_else, info)
+}
+
+// `if cond {then}` only runs `then` for its effects, i.e. `cond -| ({then}; ()) ? ()`.
+pub fn if_then(cond: Node, then: Node, info: &Info) -> Node {
+    let then = bin_op(";", then, unit_type().into_node(), info);
+    if_else(cond, then, unit_type().into_node(), info)
+}
+
+// Finds the condition and body of an `if` without an `else`, see `if_then`.
+pub fn if_then_parts(expr: &BinOp) -> Option<(&Node, &Node)> {
+    let is_unit =
+        |expr: &Node| matches!(expr, Node::ValNode(Val::Product(tys), _) if tys.is_empty());
+    let (cond, then) = operands(&expr.left, "-|")?;
+    match operands(then, ";")? {
+        (then, done) if expr.name == "?" && is_unit(done) && is_unit(&expr.right) => {
+            Some((cond, then))
+        }
+        _ => None,
+    }
+}
+
+// Names of the definitions that loops are lowered to. Names can't contain `#`, so these are
+// hidden from the loop's body.
+pub const LOOP_STEP: &str = "#while";
+pub const LOOP_ITEMS: &str = "#in";
+pub const LOOP_INDEX: &str = "#for";
+
+// Finds the condition, body and alternative of an `if` with an `else`, see `if_else`.
+pub fn if_else_parts(expr: &BinOp) -> Option<(&Node, &Node, &Node)> {
+    let is_block = |expr: &Node| matches!(expr, Node::UnOpNode(UnOp { name, .. }) if name == "{}");
+    let (cond, then) = operands(&expr.left, "-|")?;
+    let is_else = match &*expr.right {
+        Node::BinOpNode(other) => if_else_parts(other).is_some() || if_then_parts(other).is_some(),
+        other => is_block(other),
+    };
+    if expr.name == "?" && is_block(then) && is_else {
+        return Some((cond, then, &expr.right));
+    }
+    None
+}
+
+// `while cond {body}` is a function that runs the body and then itself again, for as long as
+// the condition holds, i.e. `{#while() = cond -| ({body}; #while()) ? (); #while()}`.
+pub fn while_loop(cond: Node, body: Node, info: &Info) -> Node {
+    let again = Apply {
+        inner: Box::new(sym(LOOP_STEP, info)),
+        args: vec![],
+        info: info.clone(),
+    }
+    .into_node();
+    let step = Let {
+        name: LOOP_STEP.to_string(),
+        args: Some(vec![]),
+        value: Box::new(if_else(
+            cond,
+            bin_op(";", body, again.clone(), info),
+            unit_type().into_node(),
+            info,
+        )),
+        info: info.clone(),
+    };
+    block(bin_op(";", step.into_node(), again, info), info)
+}
+
+// Whether a definition is the step of a `while` loop, see `while_loop`.
+pub fn is_loop_step(def: &Let) -> bool {
+    def.name == LOOP_STEP && def.args.as_ref().is_some_and(|args| args.is_empty())
+}
+
+// Finds the condition and body in the inside of a `while` loop's block, see `while_loop`.
+pub fn loop_parts(expr: &Node) -> Option<(&Node, &Node)> {
+    let step = match operands(expr, ";")?.0 {
+        Node::LetNode(def) if is_loop_step(def) => &def.value,
+        _ => return None,
+    };
+    let (cond, next) = operands(operands(step, "?")?.0, "-|")?;
+    Some((cond, operands(next, ";")?.0))
+}
+
+// `for x in xs {body}` steps through the indices of `xs`, i.e.
+// `{#in = xs; #for := 0; while #for < len(it=#in) {x = #in[#for]; {body}; #for += 1}}`.
+pub fn for_loop(name: &str, items: Node, body: Node, info: &Info) -> Node {
+    let items = Let {
+        name: LOOP_ITEMS.to_string(),
+        args: None,
+        value: Box::new(items),
+        info: info.clone(),
+    };
+    let index = Let {
+        name: LOOP_INDEX.to_string(),
+        args: None,
+        value: Box::new(int32(0).into_node()),
+        info: Info {
+            mutable: true,
+            ..info.clone()
+        },
+    };
+    let len = Apply {
+        inner: Box::new(sym("len", info)),
+        args: vec![Let {
+            name: "it".to_string(),
+            args: None,
+            value: Box::new(sym(LOOP_ITEMS, info)),
+            info: info.clone(),
+        }],
+        info: info.clone(),
+    };
+    let item = Let {
+        name: name.to_string(),
+        args: None,
+        value: Box::new(bin_op(
+            "[]",
+            sym(LOOP_ITEMS, info),
+            sym(LOOP_INDEX, info),
+            info,
+        )),
+        info: info.clone(),
+    };
+    let next = bin_op("+=", sym(LOOP_INDEX, info), int32(1).into_node(), info);
+    let body = bin_op(";", item.into_node(), bin_op(";", body, next, info), info);
+    let cond = bin_op("<", sym(LOOP_INDEX, info), len.into_node(), info);
+    let inner = bin_op(
+        ";",
+        items.into_node(),
+        bin_op(
+            ";",
+            index.into_node(),
+            while_loop(cond, block(body, info), info),
+            info,
+        ),
+        info,
+    );
+    block(inner, info)
+}
+
+// Finds the name, items and body in the inside of a `for` loop's block, see `for_loop`.
+pub fn for_parts(expr: &Node) -> Option<(&str, &Node, &Node)> {
+    let (items, rest) = operands(expr, ";")?;
+    let items = match items {
+        Node::LetNode(def) if def.name == LOOP_ITEMS => &def.value,
+        _ => return None,
+    };
+    let steps = match operands(rest, ";")?.1 {
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" => inner,
+        _ => return None,
+    };
+    let body = match loop_parts(steps)?.1 {
+        Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" => inner,
+        _ => return None,
+    };
+    match operands(body, ";")? {
+        (Node::LetNode(item), rest) => Some((&item.name, items, operands(rest, ";")?.0)),
+        _ => None,
+    }
+}
+
+// Functions can be defined by clauses that match their arguments against literals, e.g.
+// `fac(0) = 1; fac(n) = n*fac(n=n-1)`. The clauses in a sequence are merged into one function
+// that tries each clause in order, i.e. `fac(n) = n==0 -| 1 ? n*fac(n=n-1)`.
+pub fn merge_clauses(expr: &BinOp) -> Result<Option<Node>, TError> {
+    let mut items = vec![];
+    sequence(&expr.clone().into_node(), &expr.name, &mut items);
+    let is_clause = |item: &Node| match item {
+        Node::LetNode(def) if def.args.is_some() => Some(def.name.clone()),
+        _ => None,
+    };
+    let names: Vec<Option<String>> = items.iter().map(is_clause).collect();
+    let repeated = |name: &Option<String>| {
+        name.is_some() && names.iter().filter(|other| *other == name).count() > 1
+    };
+    if !names.iter().any(repeated) {
+        return Ok(None);
+    }
+    let mut merged = vec![];
+    for (index, item) in items.iter().enumerate() {
+        match &names[index] {
+            // Later clauses are merged into the first.
+            Some(_) if names[..index].contains(&names[index]) => {}
+            name if repeated(name) => {
+                let clauses: Vec<&Let> = items
+                    .iter()
+                    .zip(names.iter())
+                    .filter(|(_, other)| *other == name)
+                    .filter_map(|(item, _)| match item {
+                        Node::LetNode(def) => Some(def),
+                        _ => None,
+                    })
+                    .collect();
+                merged.push(merge(&clauses)?.into_node());
+            }
+            _ => merged.push(item.clone()),
+        }
+    }
+    let mut merged = merged.into_iter();
+    let first = merged.next().expect("Missing definition");
+    Ok(Some(merged.fold(first, |left, right| {
+        bin_op(&expr.name, left, right, &expr.info)
+    })))
+}
+
+// Flattens a sequence, e.g. `a; b; c`, into its items.
+fn sequence(expr: &Node, op: &str, items: &mut Vec<Node>) {
+    match operands(expr, op) {
+        Some((left, right)) => {
+            sequence(left, op, items);
+            sequence(right, op, items);
+        }
+        None => items.push(expr.clone()),
+    }
+}
+
+// The literal that an argument of a clause matches, e.g. `0` in `fac(0)`.
+fn pattern(arg: &Let) -> Option<&Val> {
+    match &*arg.value {
+        Node::ValNode(val, _) if *val != unit_type() => Some(val),
+        _ => None,
+    }
+}
+
+fn merge(clauses: &[&Let]) -> Result<Let, TError> {
+    let first = clauses[0];
+    let args_of = |clause: &Let| clause.args.clone().unwrap_or_default();
+    let arity = args_of(first).len();
+    if let Some(clause) = clauses.iter().find(|clause| args_of(clause).len() != arity) {
+        return Err(TError::ClauseArity(first.name.clone(), clause.get_info()));
+    }
+    // Arguments are matched by position, and are named by the first clause that names them.
+    let params: Vec<Let> = (0..arity)
+        .map(|index| {
+            let args = clauses.iter().map(|clause| args_of(clause)[index].clone());
+            let mut named = args.clone().filter(|arg| arg.name != "it");
+            let param = args
+                .clone()
+                .find(|arg| pattern(arg).is_none())
+                .or_else(|| named.next())
+                .unwrap_or_else(|| args_of(first)[index].clone());
+            Let {
+                value: Box::new(unit_type().into_node()),
+                ..param
+            }
+        })
+        .collect();
+    let patterns: Vec<Vec<Option<&Val>>> = clauses
+        .iter()
+        .map(|clause| {
+            let args = clause.args.as_ref().expect("Missing arguments");
+            args.iter().map(pattern).collect()
+        })
+        .collect();
+    for (index, clause) in clauses.iter().enumerate() {
+        let covers = |earlier: &Vec<Option<&Val>>| {
+            earlier
+                .iter()
+                .zip(patterns[index].iter())
+                .all(|(earlier, pat)| earlier.is_none() || earlier == pat)
+        };
+        if patterns[..index].iter().any(covers) {
+            return Err(TError::UnreachableClause(
+                clause.name.clone(),
+                clause.get_info(),
+            ));
+        }
+    }
+    let catch_all = patterns.iter().any(|pats| pats.iter().all(Option::is_none));
+    let bools = [boolean(true), boolean(false)];
+    let all_bools = arity == 1
+        && bools
+            .iter()
+            .all(|b| patterns.iter().any(|pats| pats[0] == Some(b)));
+    if !catch_all && !all_bools {
+        return Err(TError::IncompleteClauses(
+            first.name.clone(),
+            first.get_info(),
+        ));
+    }
+    // The last clause is only reached if no other clause matched, so it needs no check.
+    let mut value: Option<Node> = None;
+    for (clause, pats) in clauses.iter().zip(patterns.iter()).rev() {
+        let mut body = (*clause.value).clone();
+        let mut conds = vec![];
+        for ((arg, param), pat) in args_of(clause).iter().zip(params.iter()).zip(pats.iter()) {
+            let info = arg.get_info();
+            match pat {
+                Some(pat) => conds.push(bin_op(
+                    "==",
+                    sym(&param.name, &info),
+                    (*pat).clone().into_node(),
+                    &info,
+                )),
+                // Arguments that are named differently by this clause are renamed.
+                None if arg.name != param.name => {
+                    let rename = Let {
+                        value: Box::new(sym(&param.name, &info)),
+                        ..arg.clone()
+                    };
+                    body = bin_op(";", rename.into_node(), body, &info);
+                }
+                None => {}
+            }
+        }
+        let mut conds = conds.into_iter();
+        value = Some(match (value, conds.next()) {
+            (Some(rest), Some(cond)) => {
+                let info = clause.get_info();
+                let cond = conds.fold(cond, |left, right| bin_op("&&", left, right, &info));
+                if_else(cond, body, rest, &info)
+            }
+            _ => body,
+        });
+    }
+    Ok(Let {
+        args: Some(params),
+        value: Box::new(value.expect("Missing clause")),
+        ..first.clone()
+    })
+}
+
 impl ToNode for Let {
     fn into_node(self) -> Node {
         Node::LetNode(self)
@@ -231,6 +1113,10 @@ pub struct Info {
     pub ty: Option<Box<Node>>,
     pub defined_at: Option<Path>,
     pub callable: bool,
+    // Whether a definition can be assigned to, i.e. it was declared with `:=`.
+    pub mutable: bool,
+    // The tuple pattern that a definition destructures, e.g. `(a, b)` in `let (a, b) = pair`.
+    pub pattern: Option<Box<Node>>,
 }
 
 impl Default for Info {
@@ -240,6 +1126,8 @@ impl Default for Info {
             ty: None,
             defined_at: None,
             callable: false,
+            mutable: false,
+            pattern: None,
         }
     }
 }
@@ -367,14 +1255,16 @@ pub trait HasInfo {
 
 #[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
 pub enum Symbol {
-    Anon(),
+    // An anonymous scope, blocks are numbered from 1 so that they don't share definitions.
+    Anon(usize),
     Named(String, Option<String>), // name, (and for files) an optional extension
 }
 
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Symbol::Anon() => write!(f, "_")?,
+            Symbol::Anon(0) => write!(f, "_")?,
+            Symbol::Anon(n) => write!(f, "_{}", n)?,
             // TODO: Edge case exists here if two files with different extensions are used together
             Symbol::Named(name, _) => write!(f, "{}", name)?,
         }
@@ -391,7 +1281,7 @@ impl Symbol {
     }
     pub fn to_filename(self: &Symbol) -> String {
         match self {
-            Symbol::Anon() => "".to_owned(),
+            Symbol::Anon(_) => "".to_owned(),
             // TODO: Edge case exists here if two files with different extensions are used together
             Symbol::Named(name, ext) => format!(
                 "{}{}",
@@ -418,6 +1308,7 @@ pub fn path_to_string(path: PathRef) -> String {
 pub struct Entry {
     pub uses: HashSet<Path>,
     pub defined_at: Path,
+    pub mutable: bool,
     // pub requires: Vec<Sym>,
     // pub defines: HashMap<Sym, Path>,
 }
@@ -427,8 +1318,9 @@ impl Default for Entry {
         Entry {
             uses: HashSet::new(),
             defined_at: vec![], //TODO: Remove the default instance.
-                                // requires: vec![],
-                                // defines: HashMap::new(),
+            mutable: false,
+            // requires: vec![],
+            // defines: HashMap::new(),
         }
     }
 }
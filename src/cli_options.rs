@@ -1,8 +1,22 @@
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Command {
     Build,
+    Check,
     Interpret,
     Repl,
+    Test,
+}
+
+// How requirements (`-|`) and postconditions (`|=`) are lowered to C++.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContractMode {
+    // Requirements become plain conditionals, postconditions are not checked.
+    Unchecked,
+    // Every contract is checked and reported with its location on failure.
+    Checked,
+    // As with Checked, but contracts that are proven to hold are removed, i.e. those over constants
+    // (e.g. `1 < 2`) and those implied by requirements on arguments (e.g. `n > 0` after `n > 2`).
+    Release,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -14,6 +28,8 @@ pub struct Options {
     pub show_full_ast: bool,
     pub debug_level: i32,
     pub interpreter_args: Vec<String>,
+    pub contracts: ContractMode,
+    pub generate_tests: bool,
 }
 
 impl Default for Options {
@@ -26,6 +42,8 @@ impl Default for Options {
             show_full_ast: false,
             debug_level: 0,
             interpreter_args: vec![],
+            contracts: ContractMode::Unchecked,
+            generate_tests: false,
         }
     }
 }
@@ -65,6 +83,9 @@ impl Options {
                     "--ast" => opts.show_ast = true,
                     "--table" => opts.show_table = true,
                     "--full-ast" => opts.show_full_ast = true,
+                    "--checked" => opts.contracts = ContractMode::Checked,
+                    "--release" => opts.contracts = ContractMode::Release,
+                    "--generate" => opts.generate_tests = true,
                     "--version" => {
                         println!("{}{}", TITLE, VERSION);
                         return opts;
@@ -78,6 +99,10 @@ impl Options {
                         return opts;
                     }
                 }
+            } else if f == "test" && opts.files.is_empty() {
+                opts.cmd = Command::Test;
+            } else if f == "check" && opts.files.is_empty() {
+                opts.cmd = Command::Check;
             } else {
                 if opts.files.is_empty() {
                     // This is the first argument, so it should be the 'main'.
@@ -106,7 +131,9 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const USAGE: &str = "An experimental programming language for ergonomic software verification.
 
 Usage:
-  tako [-i|-r] [-d <level>] [--ast] [--full-ast] [--table] <files>...
+  tako [-i|-r] [-d <level>] [--ast] [--full-ast] [--table] [--checked|--release] <files>...
+  tako check <files>...
+  tako test [--generate] <files>...
   tako (-h | --help)
   tako --version
 
@@ -117,6 +144,12 @@ Options:
   --ast               Pretty print an abstract syntax tree of the code.
   --full-ast          Debug print an abstract syntax tree of the code.
   --table             Pretty print the symbol table of the code.
+  --checked           Check requirements and postconditions in compiled code.
+  --release           As --checked, omitting checks of conditions that are proven to hold.
+  --generate          Test each function with generated arguments.
   -h --help           Show this screen.
   --version           Show compiler version.
+
+Environment:
+  TAKO_PATH           Directories to search for imported modules, after those of <files>.
 ";
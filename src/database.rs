@@ -1,6 +1,7 @@
 use specs::prelude::*;
 use specs::World;
-use std::collections::{HashMap, HashSet};
+use std::cell::OnceCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
@@ -8,14 +9,20 @@ use std::sync::Arc;
 
 use directories::ProjectDirs;
 
-use crate::ast::{path_to_string, Node, Path, PathRef, Root, Symbol, Visitor};
+use crate::ast::{
+    path_to_string, Abs, Apply, BinOp, HasInfo, Let, Node, Path, PathRef, Root, Sym, Symbol,
+    ToNode, UnOp, Visitor,
+};
 use crate::cli_options::Options;
 use crate::components::*;
 use crate::errors::TError;
 use crate::externs::get_externs;
 use crate::externs::{Extern, Semantic};
-use crate::primitives::Val;
+use crate::passes::interpreter::Interpreter;
+use crate::passes::type_graph_builder::TypeGraphBuilder;
+use crate::primitives::{unit_type, Prim, Val};
 use crate::symbol_table::Table;
+use crate::type_graph::TypeGraph;
 
 fn to_file_path(context: PathRef) -> Path {
     let mut module = context.to_vec();
@@ -25,7 +32,7 @@ fn to_file_path(context: PathRef) -> Path {
                 "Couldn't find a file associated with symbol at {}",
                 path_to_string(context)
             ),
-            Some(Symbol::Anon()) => {}                   // Skip anons
+            Some(Symbol::Anon(_)) => {}                  // Skip anons
             Some(Symbol::Named(_, None)) => {}           // Skip regular symbols
             Some(Symbol::Named(_, Some(_ext))) => break, // Found the file
         }
@@ -34,6 +41,147 @@ fn to_file_path(context: PathRef) -> Path {
     module
 }
 
+// The definitions at the top level of a module.
+fn top_level_definitions(ast: &Node) -> Vec<&Let> {
+    match ast {
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == ";" || name == "," => {
+            let mut defs = top_level_definitions(left);
+            defs.extend(top_level_definitions(right));
+            defs
+        }
+        Node::LetNode(def) => vec![def],
+        _ => vec![],
+    }
+}
+
+// Names the top level definitions of an imported module, and the module's uses of them, with the
+// module's name (e.g. `maths.square`), so that modules can define the same names.
+fn qualify(ast: Node, module: &str) -> Node {
+    let renames: HashMap<String, String> = top_level_definitions(&ast)
+        .iter()
+        .map(|def| (def.name.clone(), format!("{}.{}", module, def.name)))
+        .collect();
+    rename_uses(rename_definitions(ast, &renames), &renames)
+}
+
+fn rename_definitions(ast: Node, renames: &HashMap<String, String>) -> Node {
+    match ast {
+        Node::BinOpNode(BinOp {
+            name,
+            left,
+            right,
+            info,
+        }) if name == ";" || name == "," => BinOp {
+            name,
+            left: Box::new(rename_definitions(*left, renames)),
+            right: Box::new(rename_definitions(*right, renames)),
+            info,
+        }
+        .into_node(),
+        Node::LetNode(def) => Let {
+            name: renames.get(&def.name).cloned().unwrap_or(def.name),
+            ..def
+        }
+        .into_node(),
+        ast => ast,
+    }
+}
+
+fn shadow<'a>(
+    renames: &HashMap<String, String>,
+    names: impl IntoIterator<Item = &'a String>,
+) -> HashMap<String, String> {
+    let mut renames = renames.clone();
+    for name in names {
+        renames.remove(name);
+    }
+    renames
+}
+
+// Replaces the names used in an expression with those in `renames`, except where they are
+// shadowed by arguments or local definitions.
+fn rename_uses(ast: Node, renames: &HashMap<String, String>) -> Node {
+    if renames.is_empty() {
+        return ast;
+    }
+    let rename_args = |args: Vec<Let>, renames: &HashMap<String, String>| -> Vec<Let> {
+        args.into_iter()
+            .map(|arg| Let {
+                value: Box::new(rename_uses(*arg.value, renames)),
+                ..arg
+            })
+            .collect()
+    };
+    match ast {
+        Node::SymNode(Sym { name, info }) => Sym {
+            name: renames.get(&name).cloned().unwrap_or(name),
+            info,
+        }
+        .into_node(),
+        Node::ApplyNode(Apply { inner, args, info }) => Apply {
+            inner: Box::new(rename_uses(*inner, renames)),
+            args: rename_args(args, renames),
+            info,
+        }
+        .into_node(),
+        Node::AbsNode(Abs { name, value, info }) => Abs {
+            value: Box::new(rename_uses(*value, &shadow(renames, [&name]))),
+            name,
+            info,
+        }
+        .into_node(),
+        Node::LetNode(Let {
+            name,
+            args,
+            value,
+            info,
+        }) => {
+            let params = args.iter().flatten().map(|arg| &arg.name);
+            let inner = shadow(renames, params.chain([&name]));
+            Let {
+                value: Box::new(rename_uses(*value, &inner)),
+                args: args.map(|args| rename_args(args, renames)),
+                name,
+                info,
+            }
+            .into_node()
+        }
+        Node::UnOpNode(UnOp { name, inner, info }) => UnOp {
+            inner: Box::new(rename_uses(*inner, renames)),
+            name,
+            info,
+        }
+        .into_node(),
+        Node::BinOpNode(BinOp {
+            name,
+            left,
+            right,
+            info,
+        }) => {
+            let right = match (name.as_str(), *right) {
+                // Fields belong to the value, e.g. the `x` in `point.x`.
+                ("." | "?.", right @ Node::SymNode(_)) => right,
+                // Definitions shadow the names for the rest of their sequence.
+                (";" | ",", right) => match &*left {
+                    Node::LetNode(def) => rename_uses(right, &shadow(renames, [&def.name])),
+                    _ => rename_uses(right, renames),
+                },
+                (_, right) => rename_uses(right, renames),
+            };
+            BinOp {
+                left: Box::new(rename_uses(*left, renames)),
+                right: Box::new(right),
+                name,
+                info,
+            }
+            .into_node()
+        }
+        ast => ast,
+    }
+}
+
 pub struct DBStorage {
     world: World,
     project_dirs: Option<ProjectDirs>,
@@ -43,6 +191,11 @@ pub struct DBStorage {
     // refers_to: HashMap<Loc, Entity>,
     instance_at: HashMap<Entity, HashSet<Loc>>,
     file_contents: HashMap<String, Arc<String>>,
+    // The built in definitions, which are the same for every module, so they are only made once.
+    externs: OnceCell<HashMap<String, Extern>>,
+    // Modules that have already been parsed, and their definitions, until a file is changed.
+    modules: HashMap<Path, Node>,
+    definitions: HashMap<Path, Root>,
 }
 
 macro_rules! define_components {
@@ -95,6 +248,9 @@ impl Default for DBStorage {
             project_dirs,
             options: Options::default(),
             file_contents: HashMap::default(),
+            externs: OnceCell::new(),
+            modules: HashMap::default(),
+            definitions: HashMap::default(),
             ast_to_entity: HashMap::default(),
             defined_at: HashMap::default(),
             // refers_to: HashMap::default(),
@@ -162,6 +318,9 @@ impl DBStorage {
     pub fn set_file(&mut self, filename: &str, contents: String) {
         self.file_contents
             .insert(filename.to_owned(), Arc::new(contents));
+        // Any module could import the file.
+        self.modules.clear();
+        self.definitions.clear();
     }
 
     pub fn module_name(&self, filename: String) -> Path {
@@ -188,7 +347,7 @@ impl DBStorage {
             .map(|sym| match sym {
                 Symbol::Named(sym, None) => sym.to_owned(),
                 Symbol::Named(sym, Some(ext)) => format!("{}.{}", sym, ext),
-                Symbol::Anon() => "?".to_owned(),
+                Symbol::Anon(_) => "?".to_owned(),
             })
             .collect();
         let file_name = parts.join("/");
@@ -202,16 +361,20 @@ impl DBStorage {
         file_name
     }
 
-    pub fn get_externs(&self) -> Result<HashMap<String, Extern>, TError> {
-        get_externs()
+    pub fn get_externs(&self) -> Result<&HashMap<String, Extern>, TError> {
+        if let Some(externs) = self.externs.get() {
+            return Ok(externs);
+        }
+        let externs = get_externs()?;
+        Ok(self.externs.get_or_init(|| externs))
     }
 
     pub fn get_extern_names(&self) -> Result<Vec<String>, TError> {
-        Ok(get_externs()?.keys().cloned().collect())
+        Ok(self.get_externs()?.keys().cloned().collect())
     }
 
     pub fn get_extern(&self, name: String) -> Result<Option<Extern>, TError> {
-        Ok(get_externs()?.get(&name).cloned())
+        Ok(self.get_externs()?.get(&name).cloned())
     }
 
     pub fn get_extern_operator(&self, name: String) -> Result<Semantic, TError> {
@@ -246,6 +409,266 @@ impl DBStorage {
         };
         self.parse_string(module, contents)
     }
+
+    // Parses a module, replacing each `import(it=...)` with the module that it names.
+    pub fn parse_module(&mut self, module: Path) -> Result<Node, TError> {
+        if let Some(ast) = self.modules.get(&module) {
+            return Ok(ast.clone());
+        }
+        let ast = self.parse_module_uncached(module.clone())?;
+        self.modules.insert(module, ast.clone());
+        Ok(ast)
+    }
+
+    fn parse_module_uncached(&mut self, module: Path) -> Result<Node, TError> {
+        let mut imported = set![self.filename(module.clone())];
+        let mut defs = vec![];
+        let mut modules = HashMap::new();
+        let ast = self.parse_file(module)?;
+        let ast = self.resolve_imports(ast, &mut defs, &mut imported, &mut modules)?;
+        // Definitions shadow the modules with the same name.
+        for def in defs.iter() {
+            if let Node::LetNode(def) = def {
+                modules.remove(&def.name);
+            }
+        }
+        let ast = match ast {
+            Some(ast) => self.resolve_namespaces(ast, &modules)?,
+            None => return Ok(unit_type().into_node()),
+        };
+        // The members of imported modules can also be used without the module's name, unless
+        // they are defined here too. Members of later imports shadow those of earlier ones.
+        let defs = top_level_definitions(&ast);
+        let mut members = HashMap::new();
+        for def in defs.iter() {
+            if let Some((_module, name)) = def.name.split_once('.') {
+                members.insert(name.to_string(), def.name.clone());
+            }
+        }
+        let members = shadow(&members, defs.iter().map(|def| &def.name));
+        Ok(rename_uses(ast, &members))
+    }
+
+    // The directories of the files being compiled, then those in `TAKO_PATH`.
+    pub fn module_search_path(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = vec![];
+        for file in self.options.files.iter() {
+            let dir = std::path::Path::new(file)
+                .parent()
+                .map(|dir| dir.to_path_buf())
+                .unwrap_or_default();
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        if dirs.is_empty() {
+            dirs.push(PathBuf::new());
+        }
+        if let Some(paths) = std::env::var_os("TAKO_PATH") {
+            dirs.extend(std::env::split_paths(&paths));
+        }
+        dirs
+    }
+
+    // Finds the file for a module (e.g. `math.tk` for `math`) on the module search path.
+    pub fn find_module(&self, name: &str) -> Option<String> {
+        self.module_search_path()
+            .iter()
+            .map(|dir| dir.join(format!("{}.tk", name)))
+            .map(|file| file.to_string_lossy().replace("\\", "/"))
+            .find(|file| {
+                self.file_contents.contains_key(file) || std::path::Path::new(file).is_file()
+            })
+    }
+
+    // Modules that have already been imported are left out (i.e. there is nothing to import).
+    fn resolve_imports(
+        &mut self,
+        ast: Node,
+        defs: &mut Vec<Node>,
+        imported: &mut BTreeSet<String>,
+        modules: &mut HashMap<String, Path>,
+    ) -> Result<Option<Node>, TError> {
+        match ast {
+            Node::BinOpNode(BinOp {
+                name,
+                left,
+                right,
+                info,
+            }) if name == ";" || name == "," => {
+                let left = self.resolve_imports(*left, defs, imported, modules)?;
+                let right = self.resolve_imports(*right, defs, imported, modules)?;
+                Ok(match (left, right) {
+                    (Some(left), Some(right)) => Some(
+                        BinOp {
+                            name,
+                            left: Box::new(left),
+                            right: Box::new(right),
+                            info,
+                        }
+                        .into_node(),
+                    ),
+                    (left, right) => left.or(right),
+                })
+            }
+            Node::LetNode(_) => {
+                defs.push(ast.clone());
+                Ok(Some(ast))
+            }
+            Node::ApplyNode(apply) if matches!(&*apply.inner, Node::SymNode(sym) if sym.name == "import") =>
+            {
+                let info = apply.info.clone();
+                let target = if let Some(target) = apply.args.iter().find(|arg| arg.name == "it") {
+                    target
+                } else {
+                    return Err(TError::ParseError(
+                        "Expected a module to import, e.g. `import('math')`".to_string(),
+                        info,
+                    ));
+                };
+                // Import targets are evaluated at compile time, using the definitions before them,
+                // so they must be constant (e.g. they can't read the program's arguments).
+                let mut program = *target.value.clone();
+                for def in defs.iter().rev() {
+                    program = BinOp {
+                        name: ";".to_string(),
+                        left: Box::new(def.clone()),
+                        right: Box::new(program),
+                        info: info.clone(),
+                    }
+                    .into_node();
+                }
+                let effects = TypeGraphBuilder::default().effects(self, &program)?;
+                if !effects.is_empty() {
+                    let effects: Vec<String> = effects.into_iter().collect();
+                    return Err(TError::UnexpectedEffects(
+                        format!("import targets can't use {}", effects.join(", ")),
+                        target.value.get_info(),
+                    ));
+                }
+                let root = Root {
+                    ast: program,
+                    table: Table::default(),
+                };
+                let name = match Interpreter::default().visit_root(self, &root)? {
+                    Val::PrimVal(Prim::Str(name)) => name,
+                    val => {
+                        return Err(TError::TypeMismatch(
+                            "Expected the name of a module to import".to_string(),
+                            Box::new(val),
+                            info,
+                        ))
+                    }
+                };
+                let file = self
+                    .find_module(&name)
+                    .ok_or_else(|| TError::UnknownModule(name.clone(), info.clone()))?;
+                modules.insert(name.clone(), self.module_name(file.clone()));
+                if !imported.insert(file.clone()) {
+                    return Ok(None);
+                }
+                let ast = qualify(self.parse_file(self.module_name(file))?, &name);
+                self.resolve_imports(ast, defs, imported, modules)
+            }
+            ast => Ok(Some(ast)),
+        }
+    }
+
+    // Replaces `module.name` with the member's qualified name, checking that the module defines it.
+    fn resolve_namespaces(
+        &mut self,
+        ast: Node,
+        modules: &HashMap<String, Path>,
+    ) -> Result<Node, TError> {
+        Ok(match ast {
+            Node::BinOpNode(BinOp {
+                name,
+                left,
+                right,
+                info,
+            }) => {
+                if let (".", Node::SymNode(module)) = (name.as_str(), &*left) {
+                    let member = match &*right {
+                        Node::SymNode(member) => Some(member),
+                        Node::ApplyNode(Apply { inner, .. }) => match &**inner {
+                            Node::SymNode(member) => Some(member),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    if let (Some(path), Some(member)) = (modules.get(&module.name), member) {
+                        let search = vec![Symbol::new(&member.name)];
+                        if self.find_symbol(path.clone(), search)?.is_none() {
+                            return Err(TError::UnknownMember(
+                                format!("{}.{}", module.name, member.name),
+                                info,
+                            ));
+                        }
+                        // The member keeps its module's name, so that modules can define the same
+                        // names.
+                        let member = Sym {
+                            name: format!("{}.{}", module.name, member.name),
+                            info: member.info.clone(),
+                        }
+                        .into_node();
+                        return match *right {
+                            Node::ApplyNode(Apply { args, info, .. }) => self.resolve_namespaces(
+                                Apply {
+                                    inner: Box::new(member),
+                                    args,
+                                    info,
+                                }
+                                .into_node(),
+                                modules,
+                            ),
+                            _ => Ok(member),
+                        };
+                    }
+                }
+                BinOp {
+                    name,
+                    left: Box::new(self.resolve_namespaces(*left, modules)?),
+                    right: Box::new(self.resolve_namespaces(*right, modules)?),
+                    info,
+                }
+                .into_node()
+            }
+            Node::UnOpNode(UnOp { name, inner, info }) => UnOp {
+                name,
+                inner: Box::new(self.resolve_namespaces(*inner, modules)?),
+                info,
+            }
+            .into_node(),
+            Node::ApplyNode(Apply { inner, args, info }) => Apply {
+                inner: Box::new(self.resolve_namespaces(*inner, modules)?),
+                args: args
+                    .into_iter()
+                    .map(|arg| self.resolve_namespaces(arg.into_node(), modules)?.as_let())
+                    .collect::<Result<Vec<Let>, TError>>()?,
+                info,
+            }
+            .into_node(),
+            Node::AbsNode(Abs { name, value, info }) => Abs {
+                name,
+                value: Box::new(self.resolve_namespaces(*value, modules)?),
+                info,
+            }
+            .into_node(),
+            Node::LetNode(Let {
+                name,
+                args,
+                value,
+                info,
+            }) => Let {
+                name,
+                args,
+                value: Box::new(self.resolve_namespaces(*value, modules)?),
+                info,
+            }
+            .into_node(),
+            ast => ast,
+        })
+    }
     pub fn infer(&mut self, expr: Node, env: Val) -> Result<Val, TError> {
         use crate::passes::type_checker::infer;
         if self.debug_level() > 0 {
@@ -254,13 +677,44 @@ impl DBStorage {
         infer(self, &expr, &env)
     }
 
+    pub fn build_type_graph(&mut self, module: Path) -> Result<TypeGraph, TError> {
+        TypeGraphBuilder::process(&module, self)
+    }
+
+    // Checks a module's effects and annotations, allowing values of unexpected types.
+    pub fn check_effects(&mut self, module: Path) -> Result<Vec<TError>, TError> {
+        TypeGraphBuilder::check_deferring_values(self, &module)
+    }
+
+    pub fn check(&mut self, module: Path) -> Result<String, TError> {
+        if self.debug_level() > 0 {
+            eprintln!("checking types for file ... {}", path_to_string(&module));
+        }
+        let graph = self.build_type_graph(module.clone())?;
+        let mut definitions: Vec<(&Path, &Val)> = graph
+            .iter()
+            .filter(|(path, _)| path.len() == module.len() + 1 && path.starts_with(&module))
+            .collect();
+        definitions.sort_by_key(|(path, _)| path_to_string(path));
+        let report: Vec<String> = definitions
+            .iter()
+            .map(|(path, ty)| format!("{}: {:?}", path_to_string(&path[module.len()..]), ty))
+            .collect();
+        Ok(report.join("\n"))
+    }
+
     pub fn look_up_definitions(&mut self, context: Path) -> Result<Root, TError> {
         use crate::passes::definition_finder::DefinitionFinder;
         let module = to_file_path(&context);
+        if let Some(root) = self.definitions.get(&module) {
+            return Ok(root.clone());
+        }
         if self.debug_level() > 0 {
             eprintln!("look up definitions >> {}", path_to_string(&module));
         }
-        DefinitionFinder::process(&module, self)
+        let root = DefinitionFinder::process(&module, self)?;
+        self.definitions.insert(module, root.clone());
+        Ok(root)
     }
 
     pub fn compile_to_cpp(&mut self, module: Path) -> Result<(String, HashSet<String>), TError> {
@@ -271,6 +725,14 @@ impl DBStorage {
         CodeGenerator::process(&module, self)
     }
 
+    pub fn generate_tests(&mut self, module: Path) -> Result<String, TError> {
+        use crate::passes::test_generator::TestGenerator;
+        if self.debug_level() > 0 {
+            eprintln!("generating tests for file ... {}", path_to_string(&module));
+        }
+        TestGenerator::default().run(self, module)
+    }
+
     pub fn build_with_gpp(&mut self, module: Path) -> Result<String, TError> {
         let (res, flags) = self.compile_to_cpp(module.clone())?;
         if self.debug_level() > 0 {
@@ -291,20 +753,19 @@ impl DBStorage {
         write!(f, "{}", res).expect("couldn't write to file");
 
         let mut cmd = Command::new("g++");
-        for arg in flags.iter() {
-            cmd.arg(arg);
-        }
-        let output = cmd
-            .arg("-std=c++14")
+        cmd.arg("-std=c++14")
             .arg("-Wall")
             .arg("-Werror")
             .arg("-Wfatal-errors")
             .arg("-O3")
             .arg(outf)
             .arg("-o")
-            .arg(execf)
-            .output()
-            .expect("could not run g++");
+            .arg(execf);
+        // Libraries have to be linked after the code that uses them.
+        for arg in flags.iter() {
+            cmd.arg(arg);
+        }
+        let output = cmd.output().expect("could not run g++");
         if !output.status.success() {
             let s = String::from_utf8(output.stderr)
                 .expect("Illegal utf8 stderr from backend compiler");
@@ -348,8 +809,8 @@ impl DBStorage {
         }
         let table = self.look_up_definitions(context.clone())?.table;
         loop {
-            if let Some(Symbol::Anon()) = context.last() {
-                context.pop(); // Cannot look inside an 'anon'.
+            if let Some(Symbol::Anon(0)) = context.last() {
+                context.pop(); // Cannot look inside a call's arguments.
             }
             let mut search: Vec<Symbol> = context.clone();
             search.extend(path.clone());
@@ -371,11 +832,6 @@ impl DBStorage {
                 );
             }
             if context.is_empty() {
-                eprintln!(
-                    "   not found {} at {}",
-                    path_to_string(&path),
-                    path_to_string(&search)
-                );
                 return Ok(None);
             }
             context.pop(); // Up one, go again.
@@ -11,6 +11,14 @@ pub enum TError {
     CppCompilerError(String, Option<i32>, Info),
     #[error("unknown symbol `{0}` in {2} at {1}")]
     UnknownSymbol(String, Info, String),
+    #[error("could not find module `{0}` at {1}")]
+    UnknownModule(String, Info),
+    #[error("`{0}` is not defined by its module at {1}")]
+    UnknownMember(String, Info),
+    #[error("no field `{0}` in {1:?} at {2}")]
+    UnknownField(String, Box<Val>, Info),
+    #[error("unknown method `{0}` at {1}")]
+    UnknownMethod(String, Info),
     #[error("out of scope type variable `{0}` at {1}")]
     OutOfScopeTypeVariable(String, Info),
     #[error("unknown infix operator `{0}` at {1}")]
@@ -35,8 +43,54 @@ pub enum TError {
     TypeMismatch(String, Box<Val>, Info),
     #[error("type mismatch, arguments {0}, {1} vs {2} at {3}")]
     TypeMismatch2(String, Box<Val>, Box<Val>, Info),
+    #[error("type mismatch in `{0}`, expected {1} but found {2} at {3}")]
+    UnexpectedType(String, Box<Val>, Box<Val>, Info),
+    #[error("unexpected effects, {0} at {1}")]
+    UnexpectedEffects(String, Info),
     #[error("runtime requirement failed at {0}")]
     RequirementFailure(Info),
+    #[error("runtime postcondition failed at {0}")]
+    PostconditionFailure(Info),
+    #[error("match is missing cases for {0} at {1}")]
+    NonExhaustiveMatch(String, Info),
+    #[error("clause of `{0}` is never used, as earlier clauses match the same arguments at {1}")]
+    UnreachableClause(String, Info),
+    #[error("clauses of `{0}` don't cover every argument, add a clause without patterns at {1}")]
+    IncompleteClauses(String, Info),
+    #[error("clauses of `{0}` take different numbers of arguments at {1}")]
+    ClauseArity(String, Info),
+    #[error("implementation is missing operations {0} at {1}")]
+    IncompleteImplementation(String, Info),
+    #[error("`{0}` is not implemented for {1} at {2}")]
+    MissingImplementation(String, Box<Val>, Info),
+    #[error("`{0}` is already implemented for {1} at {2}")]
+    DuplicateImplementation(String, Box<Val>, Info),
+    #[error("`{0}` is already a constructor of `{1}` at {2}")]
+    DuplicateConstructor(String, String, Info),
+    #[error("index {0} is out of bounds for a {1} of length {2} at {3}")]
+    IndexOutOfBounds(i32, &'static str, usize, Info),
+    #[error("key {0} is missing from map at {1}")]
+    MissingKey(String, Info),
+    #[error("map has {0} keys but {1} values at {2}")]
+    MismatchedKeys(usize, usize, Info),
+    #[error("division by zero at {0}")]
+    DivisionByZero(Info),
+    #[error("cannot assign to `{0}` as it was not declared with `:=` at {1}")]
+    ImmutableAssignment(String, Info),
+    #[error("cannot assign to `{0}` in a closure, as closures only capture its value at {1}")]
+    CapturedAssignment(String, Info),
+    #[error("cannot use `{0}` in a closure, as it was declared with `:=` and closures only capture its value, copy it with `=` first at {1}")]
+    CapturedVariable(String, Info),
+    #[error("effect handler exited without resuming, returning {1} at {2}")]
+    HandlerExit(usize, Box<Val>, Info),
+    #[error("`resume` must be a statement of its own or the last step of a handler clause at {0}")]
+    MisplacedResume(Info),
+    #[error("`resume` can only be used in the clauses of a handler at {0}")]
+    ResumeOutsideHandler(Info),
+    #[error("recursion limit of {0} nested calls reached at {1}")]
+    RecursionLimit(usize, Info),
+    #[error("{0} cannot be compiled to C++ yet at {1}")]
+    UnsupportedInCpp(String, Info),
 
     #[error("parse failed, {0} at {1}")]
     ParseError(String, Info),
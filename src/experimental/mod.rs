@@ -1,4 +1,2 @@
 mod lambda;
 mod ski;
-mod type_graph;
-mod type_graph_builder;
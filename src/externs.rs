@@ -1,32 +1,1029 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
 
 use crate::ast::{Info, Node, ToNode};
 use crate::database::DBStorage;
 use crate::errors::TError;
 use crate::primitives::{
-    bit_type, builtin, i32_type, int32, never_type, number_type, string, string_type, type_type,
-    unit_type, variable, Prim::*, Val, Val::*,
+    bigint_type, bit_type, boolean, builtin, char_type, constructor_of, f64_type, i32_type,
+    i64_type, int32, is_tuple, list_type, map_type, never_type, number_type, optional_type,
+    set_type, string, string_type, type_type, u32_type, u64_type, u8_type, unit_type, variable,
+    with_effects, Float, Prim::*, TypeSet, Val, Val::*,
 };
 
 pub type Res = Result<Val, TError>;
 
-pub fn prim_add_strs(l: &Val, r: &Val, _info: Info) -> Res {
-    let to_str = |v: &Val| {
-        if let PrimVal(Str(s)) = v {
-            s.to_string()
-        } else {
-            format!("{}", v)
+// Containers are lowered to the standard library's, with helpers for the operations on them.
+// Strings are UTF-8, so their characters (i.e. code points) are stored as short strings.
+pub const CONTAINERS: &str = "#include <map>
+#include <ostream>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+namespace tako {
+template <typename T> struct value { using type = T; };
+template <> struct value<const char*> { using type = std::string; };
+inline std::vector<std::string> chars(const std::string& s) {
+  std::vector<std::string> out;
+  for (size_t i = 0; i < s.size();) {
+    const unsigned char c = s[i];
+    const size_t n = c >= 0xf0 ? 4 : c >= 0xe0 ? 3 : c >= 0xc0 ? 2 : 1;
+    out.push_back(s.substr(i, n));
+    i += n;
+  }
+  return out;
+}
+inline int len(const std::string& s) {
+  return chars(s).size();
+}
+inline int len(const char* s) {
+  return len(std::string(s));
+}
+inline std::string index(const std::string& s, const int i) {
+  return chars(s).at(i);
+}
+inline std::string slice(const std::string& s, const int start, const int end) {
+  const auto cs = chars(s);
+  std::string out;
+  for (int i = start < 0 ? 0 : start; i < end && i < (int)cs.size(); i++) {
+    out += cs[i];
+  }
+  return out;
+}
+inline std::vector<std::string> split(const std::string& s, const std::string& sep) {
+  std::vector<std::string> out;
+  size_t start = 0;
+  for (size_t at; !sep.empty() && (at = s.find(sep, start)) != std::string::npos;) {
+    out.push_back(s.substr(start, at - start));
+    start = at + sep.size();
+  }
+  out.push_back(s.substr(start));
+  return out;
+}
+inline std::string join(const std::vector<std::string>& xs, const std::string& sep) {
+  std::string out;
+  for (size_t i = 0; i < xs.size(); i++) {
+    out += (i ? sep : \"\") + xs[i];
+  }
+  return out;
+}
+inline int find(const std::string& s, const std::string& x) {
+  const size_t at = s.find(x);
+  return at == std::string::npos ? -1 : len(s.substr(0, at));
+}
+inline std::string replace(const std::string& s, const std::string& from, const std::string& to) {
+  return from.empty() ? s : join(split(s, from), to);
+}
+// Values in containers are written like the interpreter writes them, with strings quoted.
+template <typename T>
+auto show(std::ostream& out, const T& x, int) -> decltype(x.to_string(), void()) {
+  out << x.to_string();
+}
+template <typename T>
+void show(std::ostream& out, const T& x, long) {
+  out << x;
+}
+inline void show(std::ostream& out, const std::string& s, int) {
+  out << '\\'' << s << '\\'';
+}
+inline void show(std::ostream& out, const char* s, int) {
+  out << '\\'' << s << '\\'';
+}
+template <typename T, typename... Ts>
+std::vector<typename value<T>::type> list(T first, Ts... rest) {
+  return {first, rest...};
+}
+template <typename C>
+int len(const C& c) {
+  return c.size();
+}
+template <typename T>
+T index(const std::vector<T>& xs, const int i) {
+  return xs.at(i);
+}
+template <typename T, typename F>
+auto map(const std::vector<T>& xs, const F f) {
+  std::vector<decltype(f(xs.front()))> out;
+  for (const auto& x : xs) {
+    out.push_back(f(x));
+  }
+  return out;
+}
+template <typename T, typename F>
+std::vector<T> filter(const std::vector<T>& xs, const F f) {
+  std::vector<T> out;
+  for (const auto& x : xs) {
+    if (f(x)) {
+      out.push_back(x);
+    }
+  }
+  return out;
+}
+template <typename T, typename A, typename F>
+A fold(const std::vector<T>& xs, A acc, const F f) {
+  for (const auto& x : xs) {
+    acc = f(acc, x);
+  }
+  return acc;
+}
+template <typename T>
+std::set<T> to_set(const std::vector<T>& xs) {
+  return std::set<T>(xs.begin(), xs.end());
+}
+template <typename T>
+std::vector<T> to_list(const std::set<T>& xs) {
+  return std::vector<T>(xs.begin(), xs.end());
+}
+template <typename T>
+bool contains(const std::set<T>& xs, const typename std::set<T>::value_type& x) {
+  return xs.count(x) > 0;
+}
+template <typename T>
+std::set<T> insert(std::set<T> xs, const typename std::set<T>::value_type& x) {
+  xs.insert(x);
+  return xs;
+}
+template <typename K, typename V>
+std::map<K, V> to_map(const std::vector<K>& keys, const std::vector<V>& values) {
+  if (keys.size() != values.size()) {
+    throw std::invalid_argument(\"map has \" + std::to_string(keys.size()) + \" keys but \" +
+                                std::to_string(values.size()) + \" values\");
+  }
+  std::map<K, V> out;
+  for (size_t i = 0; i < keys.size(); i++) {
+    out.erase(keys[i]);
+    out.emplace(keys[i], values[i]);
+  }
+  return out;
+}
+template <typename K, typename V>
+V get(const std::map<K, V>& m, const typename std::map<K, V>::key_type& k) {
+  return m.at(k);
+}
+template <typename K, typename V>
+std::map<K, V> put(
+    std::map<K, V> m,
+    const typename std::map<K, V>::key_type& k,
+    const typename std::map<K, V>::mapped_type& v) {
+  m.erase(k);
+  m.emplace(k, v);
+  return m;
+}
+template <typename K, typename V>
+std::vector<K> keys(const std::map<K, V>& m) {
+  std::vector<K> out;
+  for (const auto& kv : m) {
+    out.push_back(kv.first);
+  }
+  return out;
+}
+template <typename K, typename V>
+std::vector<V> values(const std::map<K, V>& m) {
+  std::vector<V> out;
+  for (const auto& kv : m) {
+    out.push_back(kv.second);
+  }
+  return out;
+}
+}
+namespace std{
+template <typename T>
+ostream& operator<<(ostream& out, const vector<T>& xs) {
+  out << \"[\";
+  for (size_t i = 0; i < xs.size(); i++) {
+    out << (i ? \", \" : \"\");
+    tako::show(out, xs[i], 0);
+  }
+  return out << \"]\";
+}
+template <typename T>
+ostream& operator<<(ostream& out, const set<T>& xs) {
+  out << \"{\";
+  for (auto it = xs.begin(); it != xs.end(); it++) {
+    out << (it == xs.begin() ? \"\" : \", \");
+    tako::show(out, *it, 0);
+  }
+  return out << \"}\";
+}
+template <typename K, typename V>
+ostream& operator<<(ostream& out, const map<K, V>& m) {
+  out << \"{\";
+  for (auto it = m.begin(); it != m.end(); it++) {
+    out << (it == m.begin() ? \"\" : \", \");
+    tako::show(out, it->first, 0);
+    out << \": \";
+    tako::show(out, it->second, 0);
+  }
+  return out << \"}\";
+}
+}";
+
+// Letters change case one code point at a time, like in the interpreter (see `map_letter`). The
+// UTF-8 is decoded here and the mappings are looked up in tables of ranges (see `case_ranges`),
+// so the result doesn't depend on which locales the machine has.
+const CASES: &str = "#include <cstdint>
+#include <stdexcept>
+#include <string>
+namespace tako {
+// Code points from `first` to `last`, `step` apart, change case by adding `delta`.
+struct case_range {
+  uint32_t first;
+  uint32_t last;
+  int32_t delta;
+  uint32_t step;
+};
+template <size_t N>
+uint32_t map_letter(const case_range (&ranges)[N], const uint32_t c) {
+  size_t lo = 0;
+  size_t hi = N;
+  while (lo < hi) {
+    const size_t mid = (lo + hi) / 2;
+    if (ranges[mid].last < c) {
+      lo = mid + 1;
+    } else {
+      hi = mid;
+    }
+  }
+  if (lo < N && ranges[lo].first <= c && (c - ranges[lo].first) % ranges[lo].step == 0) {
+    return c + ranges[lo].delta;
+  }
+  return c;
+}
+template <size_t N>
+std::string map_letters(const std::string& s, const case_range (&ranges)[N]) {
+  std::string out;
+  for (size_t i = 0; i < s.size();) {
+    const unsigned char lead = s[i];
+    const size_t n = lead >= 0xf0 ? 4 : lead >= 0xe0 ? 3 : lead >= 0xc0 ? 2 : 1;
+    if ((lead >= 0x80 && lead < 0xc0) || lead >= 0xf8 || i + n > s.size()) {
+      throw std::invalid_argument(\"string is not valid UTF-8\");
+    }
+    uint32_t c = n == 1 ? lead : lead & (0x7f >> n);
+    for (size_t j = 1; j < n; j++) {
+      const unsigned char next = s[i + j];
+      if ((next & 0xc0) != 0x80) {
+        throw std::invalid_argument(\"string is not valid UTF-8\");
+      }
+      c = (c << 6) | (next & 0x3f);
+    }
+    c = map_letter(ranges, c);
+    if (c < 0x80) {
+      out += char(c);
+    } else if (c < 0x800) {
+      out += char(0xc0 | (c >> 6));
+      out += char(0x80 | (c & 0x3f));
+    } else if (c < 0x10000) {
+      out += char(0xe0 | (c >> 12));
+      out += char(0x80 | ((c >> 6) & 0x3f));
+      out += char(0x80 | (c & 0x3f));
+    } else {
+      out += char(0xf0 | (c >> 18));
+      out += char(0x80 | ((c >> 12) & 0x3f));
+      out += char(0x80 | ((c >> 6) & 0x3f));
+      out += char(0x80 | (c & 0x3f));
+    }
+    i += n;
+  }
+  return out;
+}
+inline std::string upper(const std::string& s) {
+  static const case_range ranges[] = {
+    {0x61, 0x7a, -32, 1},
+    {0xb5, 0xb5, 743, 1},
+    {0xe0, 0xf6, -32, 1},
+    {0xf8, 0xfe, -32, 1},
+    {0xff, 0xff, 121, 1},
+    {0x101, 0x12f, -1, 2},
+    {0x131, 0x131, -232, 1},
+    {0x133, 0x137, -1, 2},
+    {0x13a, 0x148, -1, 2},
+    {0x14b, 0x177, -1, 2},
+    {0x17a, 0x17e, -1, 2},
+    {0x17f, 0x17f, -300, 1},
+    {0x180, 0x180, 195, 1},
+    {0x183, 0x185, -1, 2},
+    {0x188, 0x188, -1, 1},
+    {0x18c, 0x18c, -1, 1},
+    {0x192, 0x192, -1, 1},
+    {0x195, 0x195, 97, 1},
+    {0x199, 0x199, -1, 1},
+    {0x19a, 0x19a, 163, 1},
+    {0x19b, 0x19b, 42561, 1},
+    {0x19e, 0x19e, 130, 1},
+    {0x1a1, 0x1a5, -1, 2},
+    {0x1a8, 0x1a8, -1, 1},
+    {0x1ad, 0x1ad, -1, 1},
+    {0x1b0, 0x1b0, -1, 1},
+    {0x1b4, 0x1b6, -1, 2},
+    {0x1b9, 0x1b9, -1, 1},
+    {0x1bd, 0x1bd, -1, 1},
+    {0x1bf, 0x1bf, 56, 1},
+    {0x1c5, 0x1c5, -1, 1},
+    {0x1c6, 0x1c6, -2, 1},
+    {0x1c8, 0x1c8, -1, 1},
+    {0x1c9, 0x1c9, -2, 1},
+    {0x1cb, 0x1cb, -1, 1},
+    {0x1cc, 0x1cc, -2, 1},
+    {0x1ce, 0x1dc, -1, 2},
+    {0x1dd, 0x1dd, -79, 1},
+    {0x1df, 0x1ef, -1, 2},
+    {0x1f2, 0x1f2, -1, 1},
+    {0x1f3, 0x1f3, -2, 1},
+    {0x1f5, 0x1f5, -1, 1},
+    {0x1f9, 0x21f, -1, 2},
+    {0x223, 0x233, -1, 2},
+    {0x23c, 0x23c, -1, 1},
+    {0x23f, 0x240, 10815, 1},
+    {0x242, 0x242, -1, 1},
+    {0x247, 0x24f, -1, 2},
+    {0x250, 0x250, 10783, 1},
+    {0x251, 0x251, 10780, 1},
+    {0x252, 0x252, 10782, 1},
+    {0x253, 0x253, -210, 1},
+    {0x254, 0x254, -206, 1},
+    {0x256, 0x257, -205, 1},
+    {0x259, 0x259, -202, 1},
+    {0x25b, 0x25b, -203, 1},
+    {0x25c, 0x25c, 42319, 1},
+    {0x260, 0x260, -205, 1},
+    {0x261, 0x261, 42315, 1},
+    {0x263, 0x263, -207, 1},
+    {0x264, 0x264, 42343, 1},
+    {0x265, 0x265, 42280, 1},
+    {0x266, 0x266, 42308, 1},
+    {0x268, 0x268, -209, 1},
+    {0x269, 0x269, -211, 1},
+    {0x26a, 0x26a, 42308, 1},
+    {0x26b, 0x26b, 10743, 1},
+    {0x26c, 0x26c, 42305, 1},
+    {0x26f, 0x26f, -211, 1},
+    {0x271, 0x271, 10749, 1},
+    {0x272, 0x272, -213, 1},
+    {0x275, 0x275, -214, 1},
+    {0x27d, 0x27d, 10727, 1},
+    {0x280, 0x280, -218, 1},
+    {0x282, 0x282, 42307, 1},
+    {0x283, 0x283, -218, 1},
+    {0x287, 0x287, 42282, 1},
+    {0x288, 0x288, -218, 1},
+    {0x289, 0x289, -69, 1},
+    {0x28a, 0x28b, -217, 1},
+    {0x28c, 0x28c, -71, 1},
+    {0x292, 0x292, -219, 1},
+    {0x29d, 0x29d, 42261, 1},
+    {0x29e, 0x29e, 42258, 1},
+    {0x345, 0x345, 84, 1},
+    {0x371, 0x373, -1, 2},
+    {0x377, 0x377, -1, 1},
+    {0x37b, 0x37d, 130, 1},
+    {0x3ac, 0x3ac, -38, 1},
+    {0x3ad, 0x3af, -37, 1},
+    {0x3b1, 0x3c1, -32, 1},
+    {0x3c2, 0x3c2, -31, 1},
+    {0x3c3, 0x3cb, -32, 1},
+    {0x3cc, 0x3cc, -64, 1},
+    {0x3cd, 0x3ce, -63, 1},
+    {0x3d0, 0x3d0, -62, 1},
+    {0x3d1, 0x3d1, -57, 1},
+    {0x3d5, 0x3d5, -47, 1},
+    {0x3d6, 0x3d6, -54, 1},
+    {0x3d7, 0x3d7, -8, 1},
+    {0x3d9, 0x3ef, -1, 2},
+    {0x3f0, 0x3f0, -86, 1},
+    {0x3f1, 0x3f1, -80, 1},
+    {0x3f2, 0x3f2, 7, 1},
+    {0x3f3, 0x3f3, -116, 1},
+    {0x3f5, 0x3f5, -96, 1},
+    {0x3f8, 0x3f8, -1, 1},
+    {0x3fb, 0x3fb, -1, 1},
+    {0x430, 0x44f, -32, 1},
+    {0x450, 0x45f, -80, 1},
+    {0x461, 0x481, -1, 2},
+    {0x48b, 0x4bf, -1, 2},
+    {0x4c2, 0x4ce, -1, 2},
+    {0x4cf, 0x4cf, -15, 1},
+    {0x4d1, 0x52f, -1, 2},
+    {0x561, 0x586, -48, 1},
+    {0x10d0, 0x10fa, 3008, 1},
+    {0x10fd, 0x10ff, 3008, 1},
+    {0x13f8, 0x13fd, -8, 1},
+    {0x1c80, 0x1c80, -6254, 1},
+    {0x1c81, 0x1c81, -6253, 1},
+    {0x1c82, 0x1c82, -6244, 1},
+    {0x1c83, 0x1c84, -6242, 1},
+    {0x1c85, 0x1c85, -6243, 1},
+    {0x1c86, 0x1c86, -6236, 1},
+    {0x1c87, 0x1c87, -6181, 1},
+    {0x1c88, 0x1c88, 35266, 1},
+    {0x1c8a, 0x1c8a, -1, 1},
+    {0x1d79, 0x1d79, 35332, 1},
+    {0x1d7d, 0x1d7d, 3814, 1},
+    {0x1d8e, 0x1d8e, 35384, 1},
+    {0x1e01, 0x1e95, -1, 2},
+    {0x1e9b, 0x1e9b, -59, 1},
+    {0x1ea1, 0x1eff, -1, 2},
+    {0x1f00, 0x1f07, 8, 1},
+    {0x1f10, 0x1f15, 8, 1},
+    {0x1f20, 0x1f27, 8, 1},
+    {0x1f30, 0x1f37, 8, 1},
+    {0x1f40, 0x1f45, 8, 1},
+    {0x1f51, 0x1f57, 8, 2},
+    {0x1f60, 0x1f67, 8, 1},
+    {0x1f70, 0x1f71, 74, 1},
+    {0x1f72, 0x1f75, 86, 1},
+    {0x1f76, 0x1f77, 100, 1},
+    {0x1f78, 0x1f79, 128, 1},
+    {0x1f7a, 0x1f7b, 112, 1},
+    {0x1f7c, 0x1f7d, 126, 1},
+    {0x1fb0, 0x1fb1, 8, 1},
+    {0x1fbe, 0x1fbe, -7205, 1},
+    {0x1fd0, 0x1fd1, 8, 1},
+    {0x1fe0, 0x1fe1, 8, 1},
+    {0x1fe5, 0x1fe5, 7, 1},
+    {0x214e, 0x214e, -28, 1},
+    {0x2170, 0x217f, -16, 1},
+    {0x2184, 0x2184, -1, 1},
+    {0x24d0, 0x24e9, -26, 1},
+    {0x2c30, 0x2c5f, -48, 1},
+    {0x2c61, 0x2c61, -1, 1},
+    {0x2c65, 0x2c65, -10795, 1},
+    {0x2c66, 0x2c66, -10792, 1},
+    {0x2c68, 0x2c6c, -1, 2},
+    {0x2c73, 0x2c73, -1, 1},
+    {0x2c76, 0x2c76, -1, 1},
+    {0x2c81, 0x2ce3, -1, 2},
+    {0x2cec, 0x2cee, -1, 2},
+    {0x2cf3, 0x2cf3, -1, 1},
+    {0x2d00, 0x2d25, -7264, 1},
+    {0x2d27, 0x2d27, -7264, 1},
+    {0x2d2d, 0x2d2d, -7264, 1},
+    {0xa641, 0xa66d, -1, 2},
+    {0xa681, 0xa69b, -1, 2},
+    {0xa723, 0xa72f, -1, 2},
+    {0xa733, 0xa76f, -1, 2},
+    {0xa77a, 0xa77c, -1, 2},
+    {0xa77f, 0xa787, -1, 2},
+    {0xa78c, 0xa78c, -1, 1},
+    {0xa791, 0xa793, -1, 2},
+    {0xa794, 0xa794, 48, 1},
+    {0xa797, 0xa7a9, -1, 2},
+    {0xa7b5, 0xa7c3, -1, 2},
+    {0xa7c8, 0xa7ca, -1, 2},
+    {0xa7cd, 0xa7db, -1, 2},
+    {0xa7f6, 0xa7f6, -1, 1},
+    {0xab53, 0xab53, -928, 1},
+    {0xab70, 0xabbf, -38864, 1},
+    {0xff41, 0xff5a, -32, 1},
+    {0x10428, 0x1044f, -40, 1},
+    {0x104d8, 0x104fb, -40, 1},
+    {0x10597, 0x105a1, -39, 1},
+    {0x105a3, 0x105b1, -39, 1},
+    {0x105b3, 0x105b9, -39, 1},
+    {0x105bb, 0x105bc, -39, 1},
+    {0x10cc0, 0x10cf2, -64, 1},
+    {0x10d70, 0x10d85, -32, 1},
+    {0x118c0, 0x118df, -32, 1},
+    {0x16e60, 0x16e7f, -32, 1},
+    {0x16ebb, 0x16ed3, -27, 1},
+    {0x1e922, 0x1e943, -34, 1}
+  };
+  return map_letters(s, ranges);
+}
+inline std::string lower(const std::string& s) {
+  static const case_range ranges[] = {
+    {0x41, 0x5a, 32, 1},
+    {0xc0, 0xd6, 32, 1},
+    {0xd8, 0xde, 32, 1},
+    {0x100, 0x12e, 1, 2},
+    {0x132, 0x136, 1, 2},
+    {0x139, 0x147, 1, 2},
+    {0x14a, 0x176, 1, 2},
+    {0x178, 0x178, -121, 1},
+    {0x179, 0x17d, 1, 2},
+    {0x181, 0x181, 210, 1},
+    {0x182, 0x184, 1, 2},
+    {0x186, 0x186, 206, 1},
+    {0x187, 0x187, 1, 1},
+    {0x189, 0x18a, 205, 1},
+    {0x18b, 0x18b, 1, 1},
+    {0x18e, 0x18e, 79, 1},
+    {0x18f, 0x18f, 202, 1},
+    {0x190, 0x190, 203, 1},
+    {0x191, 0x191, 1, 1},
+    {0x193, 0x193, 205, 1},
+    {0x194, 0x194, 207, 1},
+    {0x196, 0x196, 211, 1},
+    {0x197, 0x197, 209, 1},
+    {0x198, 0x198, 1, 1},
+    {0x19c, 0x19c, 211, 1},
+    {0x19d, 0x19d, 213, 1},
+    {0x19f, 0x19f, 214, 1},
+    {0x1a0, 0x1a4, 1, 2},
+    {0x1a6, 0x1a6, 218, 1},
+    {0x1a7, 0x1a7, 1, 1},
+    {0x1a9, 0x1a9, 218, 1},
+    {0x1ac, 0x1ac, 1, 1},
+    {0x1ae, 0x1ae, 218, 1},
+    {0x1af, 0x1af, 1, 1},
+    {0x1b1, 0x1b2, 217, 1},
+    {0x1b3, 0x1b5, 1, 2},
+    {0x1b7, 0x1b7, 219, 1},
+    {0x1b8, 0x1b8, 1, 1},
+    {0x1bc, 0x1bc, 1, 1},
+    {0x1c4, 0x1c4, 2, 1},
+    {0x1c5, 0x1c5, 1, 1},
+    {0x1c7, 0x1c7, 2, 1},
+    {0x1c8, 0x1c8, 1, 1},
+    {0x1ca, 0x1ca, 2, 1},
+    {0x1cb, 0x1db, 1, 2},
+    {0x1de, 0x1ee, 1, 2},
+    {0x1f1, 0x1f1, 2, 1},
+    {0x1f2, 0x1f4, 1, 2},
+    {0x1f6, 0x1f6, -97, 1},
+    {0x1f7, 0x1f7, -56, 1},
+    {0x1f8, 0x21e, 1, 2},
+    {0x220, 0x220, -130, 1},
+    {0x222, 0x232, 1, 2},
+    {0x23a, 0x23a, 10795, 1},
+    {0x23b, 0x23b, 1, 1},
+    {0x23d, 0x23d, -163, 1},
+    {0x23e, 0x23e, 10792, 1},
+    {0x241, 0x241, 1, 1},
+    {0x243, 0x243, -195, 1},
+    {0x244, 0x244, 69, 1},
+    {0x245, 0x245, 71, 1},
+    {0x246, 0x24e, 1, 2},
+    {0x370, 0x372, 1, 2},
+    {0x376, 0x376, 1, 1},
+    {0x37f, 0x37f, 116, 1},
+    {0x386, 0x386, 38, 1},
+    {0x388, 0x38a, 37, 1},
+    {0x38c, 0x38c, 64, 1},
+    {0x38e, 0x38f, 63, 1},
+    {0x391, 0x3a1, 32, 1},
+    {0x3a3, 0x3ab, 32, 1},
+    {0x3cf, 0x3cf, 8, 1},
+    {0x3d8, 0x3ee, 1, 2},
+    {0x3f4, 0x3f4, -60, 1},
+    {0x3f7, 0x3f7, 1, 1},
+    {0x3f9, 0x3f9, -7, 1},
+    {0x3fa, 0x3fa, 1, 1},
+    {0x3fd, 0x3ff, -130, 1},
+    {0x400, 0x40f, 80, 1},
+    {0x410, 0x42f, 32, 1},
+    {0x460, 0x480, 1, 2},
+    {0x48a, 0x4be, 1, 2},
+    {0x4c0, 0x4c0, 15, 1},
+    {0x4c1, 0x4cd, 1, 2},
+    {0x4d0, 0x52e, 1, 2},
+    {0x531, 0x556, 48, 1},
+    {0x10a0, 0x10c5, 7264, 1},
+    {0x10c7, 0x10c7, 7264, 1},
+    {0x10cd, 0x10cd, 7264, 1},
+    {0x13a0, 0x13ef, 38864, 1},
+    {0x13f0, 0x13f5, 8, 1},
+    {0x1c89, 0x1c89, 1, 1},
+    {0x1c90, 0x1cba, -3008, 1},
+    {0x1cbd, 0x1cbf, -3008, 1},
+    {0x1e00, 0x1e94, 1, 2},
+    {0x1e9e, 0x1e9e, -7615, 1},
+    {0x1ea0, 0x1efe, 1, 2},
+    {0x1f08, 0x1f0f, -8, 1},
+    {0x1f18, 0x1f1d, -8, 1},
+    {0x1f28, 0x1f2f, -8, 1},
+    {0x1f38, 0x1f3f, -8, 1},
+    {0x1f48, 0x1f4d, -8, 1},
+    {0x1f59, 0x1f5f, -8, 2},
+    {0x1f68, 0x1f6f, -8, 1},
+    {0x1f88, 0x1f8f, -8, 1},
+    {0x1f98, 0x1f9f, -8, 1},
+    {0x1fa8, 0x1faf, -8, 1},
+    {0x1fb8, 0x1fb9, -8, 1},
+    {0x1fba, 0x1fbb, -74, 1},
+    {0x1fbc, 0x1fbc, -9, 1},
+    {0x1fc8, 0x1fcb, -86, 1},
+    {0x1fcc, 0x1fcc, -9, 1},
+    {0x1fd8, 0x1fd9, -8, 1},
+    {0x1fda, 0x1fdb, -100, 1},
+    {0x1fe8, 0x1fe9, -8, 1},
+    {0x1fea, 0x1feb, -112, 1},
+    {0x1fec, 0x1fec, -7, 1},
+    {0x1ff8, 0x1ff9, -128, 1},
+    {0x1ffa, 0x1ffb, -126, 1},
+    {0x1ffc, 0x1ffc, -9, 1},
+    {0x2126, 0x2126, -7517, 1},
+    {0x212a, 0x212a, -8383, 1},
+    {0x212b, 0x212b, -8262, 1},
+    {0x2132, 0x2132, 28, 1},
+    {0x2160, 0x216f, 16, 1},
+    {0x2183, 0x2183, 1, 1},
+    {0x24b6, 0x24cf, 26, 1},
+    {0x2c00, 0x2c2f, 48, 1},
+    {0x2c60, 0x2c60, 1, 1},
+    {0x2c62, 0x2c62, -10743, 1},
+    {0x2c63, 0x2c63, -3814, 1},
+    {0x2c64, 0x2c64, -10727, 1},
+    {0x2c67, 0x2c6b, 1, 2},
+    {0x2c6d, 0x2c6d, -10780, 1},
+    {0x2c6e, 0x2c6e, -10749, 1},
+    {0x2c6f, 0x2c6f, -10783, 1},
+    {0x2c70, 0x2c70, -10782, 1},
+    {0x2c72, 0x2c72, 1, 1},
+    {0x2c75, 0x2c75, 1, 1},
+    {0x2c7e, 0x2c7f, -10815, 1},
+    {0x2c80, 0x2ce2, 1, 2},
+    {0x2ceb, 0x2ced, 1, 2},
+    {0x2cf2, 0x2cf2, 1, 1},
+    {0xa640, 0xa66c, 1, 2},
+    {0xa680, 0xa69a, 1, 2},
+    {0xa722, 0xa72e, 1, 2},
+    {0xa732, 0xa76e, 1, 2},
+    {0xa779, 0xa77b, 1, 2},
+    {0xa77d, 0xa77d, -35332, 1},
+    {0xa77e, 0xa786, 1, 2},
+    {0xa78b, 0xa78b, 1, 1},
+    {0xa78d, 0xa78d, -42280, 1},
+    {0xa790, 0xa792, 1, 2},
+    {0xa796, 0xa7a8, 1, 2},
+    {0xa7aa, 0xa7aa, -42308, 1},
+    {0xa7ab, 0xa7ab, -42319, 1},
+    {0xa7ac, 0xa7ac, -42315, 1},
+    {0xa7ad, 0xa7ad, -42305, 1},
+    {0xa7ae, 0xa7ae, -42308, 1},
+    {0xa7b0, 0xa7b0, -42258, 1},
+    {0xa7b1, 0xa7b1, -42282, 1},
+    {0xa7b2, 0xa7b2, -42261, 1},
+    {0xa7b3, 0xa7b3, 928, 1},
+    {0xa7b4, 0xa7c2, 1, 2},
+    {0xa7c4, 0xa7c4, -48, 1},
+    {0xa7c5, 0xa7c5, -42307, 1},
+    {0xa7c6, 0xa7c6, -35384, 1},
+    {0xa7c7, 0xa7c9, 1, 2},
+    {0xa7cb, 0xa7cb, -42343, 1},
+    {0xa7cc, 0xa7da, 1, 2},
+    {0xa7dc, 0xa7dc, -42561, 1},
+    {0xa7f5, 0xa7f5, 1, 1},
+    {0xff21, 0xff3a, 32, 1},
+    {0x10400, 0x10427, 40, 1},
+    {0x104b0, 0x104d3, 40, 1},
+    {0x10570, 0x1057a, 39, 1},
+    {0x1057c, 0x1058a, 39, 1},
+    {0x1058c, 0x10592, 39, 1},
+    {0x10594, 0x10595, 39, 1},
+    {0x10c80, 0x10cb2, 64, 1},
+    {0x10d50, 0x10d65, 32, 1},
+    {0x118a0, 0x118bf, 32, 1},
+    {0x16e40, 0x16e5f, 32, 1},
+    {0x16ea0, 0x16eb8, 27, 1},
+    {0x1e900, 0x1e921, 34, 1}
+  };
+  return map_letters(s, ranges);
+}
+}";
+
+// Optional values are `tako::optional`s or `tako::none`, other values are always present.
+// C++14 has no `std::optional`, so this is a minimal version of it.
+pub const OPTIONALS: &str = "namespace tako {
+struct none_t {};
+constexpr none_t none{};
+template <typename T>
+struct optional {
+  bool has_value;
+  T value;
+  optional(none_t) : has_value(false), value() {}
+  optional(const T& value) : has_value(true), value(value) {}
+  explicit operator bool() const { return has_value; }
+  const T& operator*() const { return value; }
+};
+template <typename T>
+optional<T> some(const T& value) {
+  return optional<T>(value);
+}
+template <typename T>
+bool operator==(const optional<T>& value, none_t) {
+  return !value;
+}
+template <typename T>
+bool operator!=(const optional<T>& value, none_t) {
+  return bool(value);
+}
+template <typename T>
+bool operator==(none_t, const optional<T>& value) {
+  return !value;
+}
+template <typename T>
+bool operator!=(none_t, const optional<T>& value) {
+  return bool(value);
+}
+template <typename T>
+const T& value_of(const optional<T>& value) {
+  return *value;
+}
+template <typename T>
+const T& value_of(const T& value) {
+  return value;
+}
+template <typename T, typename U>
+T or_else(const optional<T>& value, const U& fallback) {
+  return value ? *value : fallback;
+}
+template <typename U>
+U or_else(none_t, const U& fallback) {
+  return fallback;
+}
+template <typename T, typename U>
+T or_else(const T& value, const U&) {
+  return value;
+}
+template <typename T, typename F>
+auto and_then(const optional<T>& value, F f) -> optional<decltype(f(*value))> {
+  if (value) {
+    return f(*value);
+  }
+  return none;
+}
+template <typename F>
+none_t and_then(none_t, F) {
+  return none;
+}
+template <typename T, typename F>
+auto and_then(const T& value, F f) {
+  return f(value);
+}
+}";
+
+// Arbitrary precision integers wrap GMP's `mpz_class`, whose operators build expressions that
+// refer to their operands, so that results can be stored with `auto` after the operands are gone.
+pub const BIGINTS: &str = "#include <gmpxx.h>
+#include <ostream>
+namespace tako {
+struct bigint {
+  mpz_class value;
+  explicit bigint(const char* digits) : value(digits) {}
+  bigint(const mpz_class& value) : value(value) {}
+};
+inline bigint operator+(const bigint& l, const bigint& r) { return mpz_class(l.value + r.value); }
+inline bigint operator-(const bigint& l, const bigint& r) { return mpz_class(l.value - r.value); }
+inline bigint operator*(const bigint& l, const bigint& r) { return mpz_class(l.value * r.value); }
+inline bigint operator/(const bigint& l, const bigint& r) { return mpz_class(l.value / r.value); }
+inline bigint operator%(const bigint& l, const bigint& r) { return mpz_class(l.value % r.value); }
+inline bigint operator-(const bigint& n) { return mpz_class(-n.value); }
+inline bool operator==(const bigint& l, const bigint& r) { return l.value == r.value; }
+inline bool operator!=(const bigint& l, const bigint& r) { return l.value != r.value; }
+inline bool operator<(const bigint& l, const bigint& r) { return l.value < r.value; }
+inline bool operator<=(const bigint& l, const bigint& r) { return l.value <= r.value; }
+inline bool operator>(const bigint& l, const bigint& r) { return l.value > r.value; }
+inline bool operator>=(const bigint& l, const bigint& r) { return l.value >= r.value; }
+inline bigint pow(const bigint& l, const int exp) {
+  mpz_class out;
+  mpz_pow_ui(out.get_mpz_t(), l.value.get_mpz_t(), exp);
+  return out;
+}
+inline std::ostream& operator<<(std::ostream& out, const bigint& n) {
+  return out << n.value;
+}
+}";
+
+// Bytes and floats wrap their C++ types, so that arithmetic on bytes is cast back to a byte rather
+// than promoted to `int`, and so that both are written like the interpreter writes them.
+pub const FIXED_NUMBERS: &str = "#include <cmath>
+#include <cstdint>
+#include <cstdio>
+#include <cstdlib>
+#include <ostream>
+#include <string>
+namespace tako {
+struct u8 {
+  uint8_t value;
+  constexpr u8(uint8_t value = 0) : value(value) {}
+  constexpr operator uint8_t() const { return value; }
+};
+inline u8 operator+(u8 l, u8 r) { return uint8_t(l.value + r.value); }
+inline u8 operator-(u8 l, u8 r) { return uint8_t(l.value - r.value); }
+inline u8 operator*(u8 l, u8 r) { return uint8_t(l.value * r.value); }
+inline u8 operator/(u8 l, u8 r) { return uint8_t(l.value / r.value); }
+inline u8 operator%(u8 l, u8 r) { return uint8_t(l.value % r.value); }
+inline u8 operator-(u8 n) { return uint8_t(-n.value); }
+inline u8 pow(u8 l, int exp) {
+  u8 out = 1;
+  for (; exp > 0; exp--) {
+    out = out * l;
+  }
+  return out;
+}
+// Bytes are written as numbers, rather than as characters.
+inline std::ostream& operator<<(std::ostream& out, u8 n) {
+  return out << +n.value;
+}
+struct f64 {
+  double value;
+  constexpr f64(double value = 0) : value(value) {}
+  constexpr operator double() const { return value; }
+};
+inline f64 operator+(f64 l, f64 r) { return l.value + r.value; }
+inline f64 operator-(f64 l, f64 r) { return l.value - r.value; }
+inline f64 operator*(f64 l, f64 r) { return l.value * r.value; }
+inline f64 operator/(f64 l, f64 r) { return l.value / r.value; }
+inline f64 operator%(f64 l, f64 r) { return std::fmod(l.value, r.value); }
+inline f64 operator-(f64 x) { return -x.value; }
+inline f64 pow(f64 l, int exp) { return std::pow(l.value, exp); }
+inline f64 pow(f64 l, f64 exp) { return std::pow(l.value, exp.value); }
+// Floats are written with the fewest digits that read back as the same float, with a `.0` on
+// whole numbers and an exponent on very large or small numbers.
+inline std::ostream& operator<<(std::ostream& out, f64 x) {
+  if (std::isnan(x.value)) {
+    return out << \"NaN\";
+  }
+  if (std::isinf(x.value)) {
+    return out << (x.value < 0 ? \"-inf\" : \"inf\");
+  }
+  char buffer[32];
+  for (int precision = 0; precision <= 17; precision++) {
+    std::snprintf(buffer, sizeof buffer, \"%.*e\", precision, x.value);
+    if (std::strtod(buffer, nullptr) == x.value) {
+      break;
+    }
+  }
+  const std::string written = buffer;
+  const size_t e = written.find('e');
+  const int exp = std::stoi(written.substr(e + 1));
+  std::string digits;
+  for (const char c : written.substr(0, e)) {
+    if (c >= '0' && c <= '9') {
+      digits += c;
+    }
+  }
+  const double size = std::fabs(x.value);
+  out << (std::signbit(x.value) ? \"-\" : \"\");
+  if (size != 0 && (size < 1e-4 || size >= 1e16)) {
+    out << digits[0] << (digits.size() > 1 ? \".\" + digits.substr(1) : \"\") << 'e' << exp;
+  } else if (exp < 0) {
+    out << \"0.\" << std::string(-exp - 1, '0') << digits;
+  } else if (digits.size() <= size_t(exp) + 1) {
+    out << digits << std::string(exp + 1 - digits.size(), '0') << \".0\";
+  } else {
+    out << digits.substr(0, exp + 1) << '.' << digits.substr(exp + 1);
+  }
+  return out;
+}
+}";
+
+// Lets any printable value be converted to a string.
+const TO_STRING: &str = "#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? \"true\" : \"false\";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}";
+
+// Tuples and records are written as they are written in Tako, e.g. `(1, 'a')` and `(x=1, y=2)`, with
+// their values written like the values in containers (see `CONTAINERS`). Records are local structs,
+// so they write themselves with a `to_string` member that calls `record`.
+pub const PRODUCTS: &str = "#include <ostream>
+#include <sstream>
+#include <string>
+#include <tuple>
+#include <utility>
+namespace tako {
+template <typename T, size_t... I>
+void show_tuple(std::ostream& out, const T& t, std::index_sequence<I...>) {
+  (void)std::initializer_list<int>{(out << (I ? \", \" : \"\"), show(out, std::get<I>(t), 0), 0)...};
+}
+inline void show_fields(std::ostream&) {}
+template <typename T, typename... Ts>
+void show_fields(std::ostream& out, const char* name, const T& x, const Ts&... rest) {
+  out << name << '=';
+  show(out, x, 0);
+  out << (sizeof...(rest) ? \", \" : \"\");
+  show_fields(out, rest...);
+}
+template <typename... Ts>
+std::string record(const Ts&... fields) {
+  std::stringstream out;
+  out << '(';
+  show_fields(out, fields...);
+  out << ')';
+  return out.str();
+}
+}
+namespace std {
+template <typename... Ts>
+ostream& operator<<(ostream& out, const tuple<Ts...>& t) {
+  out << '(';
+  tako::show_tuple(out, t, index_sequence_for<Ts...>{});
+  return out << ')';
+}
+}";
+
+// Converts a value to the text that would be printed for it.
+pub fn to_str(v: &Val) -> String {
+    match v {
+        PrimVal(Str(s)) => s.to_string(),
+        PrimVal(Char(c)) => c.to_string(),
+        Struct(_) => show(v),
+        v => format!("{}", v),
+    }
+}
+
+// Writes records as they are written, e.g. `(x=1, y='two')`, and other values as they are shown
+// in containers.
+fn show(v: &Val) -> String {
+    match v {
+        Struct(fields) if !fields.is_empty() && constructor_of(v).is_none() => {
+            let tuple = is_tuple(fields);
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(name, val)| match tuple {
+                    true => show(val),
+                    false => format!("{}={}", name, show(val)),
+                })
+                .collect();
+            format!("({})", fields.join(", "))
         }
-    };
-    Ok(PrimVal(Str(format!("{}{}", to_str(l), to_str(r)))))
+        v => format!("{:?}", v),
+    }
+}
+
+pub fn prim_add_strs(l: &Val, r: &Val, _info: Info) -> Res {
+    match (l, r) {
+        // Lists are joined, rather than being shown as text.
+        (List(l), List(r)) => Ok(List(l.iter().chain(r).cloned().collect())),
+        (l, r) => Ok(PrimVal(Str(format!("{}{}", to_str(l), to_str(r))))),
+    }
 }
 
 pub fn prim_pow(l: &Val, r: &Val, info: Info) -> Res {
+    let fail = || {
+        TError::TypeMismatch2(
+            "^".to_string(),
+            Box::new((*l).clone()),
+            Box::new((*r).clone()),
+            info.clone(),
+        )
+    };
+    // Integers can only be raised to non-negative powers.
+    let exp = match r {
+        PrimVal(I32(r)) => u32::try_from(*r).ok(),
+        _ => None,
+    };
+    Ok(PrimVal(match (l, r, exp) {
+        (PrimVal(I32(l)), PrimVal(Bool(r)), _) => I32(if *r { *l } else { 1 }),
+        (PrimVal(I32(l)), _, Some(exp)) => I32(l.wrapping_pow(exp)),
+        (PrimVal(I64(l)), _, Some(exp)) => I64(l.wrapping_pow(exp)),
+        (PrimVal(U8(l)), _, Some(exp)) => U8(l.wrapping_pow(exp)),
+        (PrimVal(U32(l)), _, Some(exp)) => U32(l.wrapping_pow(exp)),
+        (PrimVal(U64(l)), _, Some(exp)) => U64(l.wrapping_pow(exp)),
+        (PrimVal(BigInt(l)), _, Some(exp)) => BigInt(l.pow(exp)),
+        (PrimVal(F64(l)), PrimVal(I32(r)), _) => F64(Float(l.0.powi(*r))),
+        (PrimVal(F64(l)), PrimVal(F64(r)), _) => F64(Float(l.0.powf(r.0))),
+        _ => return Err(fail()),
+    }))
+}
+
+pub fn prim_index(l: &Val, r: &Val, info: Info) -> Res {
     match (l, r) {
-        (PrimVal(I32(l)), PrimVal(Bool(r))) => Ok(int32(if *r { *l } else { 1 })),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(i32::pow(*l, *r as u32))), // TODO: require pos pow
+        (List(vals), PrimVal(I32(index))) => usize::try_from(*index)
+            .ok()
+            .and_then(|index| vals.get(index))
+            .cloned()
+            .ok_or(TError::IndexOutOfBounds(*index, "list", vals.len(), info)),
+        (PrimVal(Str(s)), PrimVal(I32(index))) => usize::try_from(*index)
+            .ok()
+            .and_then(|index| s.chars().nth(index))
+            .map(|chr| PrimVal(Char(chr)))
+            .ok_or_else(|| TError::IndexOutOfBounds(*index, "string", s.chars().count(), info)),
         (l, r) => Err(TError::TypeMismatch2(
-            "^".to_string(),
+            "[]".to_string(),
             Box::new((*l).clone()),
             Box::new((*r).clone()),
             info,
@@ -37,6 +1034,51 @@ pub fn prim_pow(l: &Val, r: &Val, info: Info) -> Res {
 pub type Args = HashMap<String, Box<dyn Fn() -> Res>>;
 pub type FuncImpl = Box<dyn Fn(&DBStorage, Args, Info) -> Res>;
 
+// Changes the case of a letter on its own, which the C++ implementation shares (see `CASES`).
+// Letters without a single letter in the other case (e.g. `ß` and `İ`) are kept.
+fn map_letter<I: ExactSizeIterator<Item = char>>(c: char, f: impl Fn(char) -> I) -> char {
+    let mut mapped = f(c);
+    match (mapped.len(), mapped.next()) {
+        (1, Some(m)) => m,
+        _ => c,
+    }
+}
+
+fn map_letters<I: ExactSizeIterator<Item = char>>(s: &str, f: impl Fn(char) -> I) -> String {
+    s.chars().map(|c| map_letter(c, &f)).collect()
+}
+
+// Lists the letters that change case as C++ `case_range`s, which is how the tables in `CASES`
+// are made. Each range is either a run of letters or every other letter (e.g. `Ā`, `Ă`, `Ą`),
+// which all change by the same amount.
+#[cfg(test)]
+fn case_ranges<I: ExactSizeIterator<Item = char>>(f: impl Fn(char) -> I) -> String {
+    let mut ranges: Vec<(u32, u32, i64, u32)> = vec![];
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        let mapped = map_letter(c, &f);
+        if mapped == c {
+            continue;
+        }
+        let (c, delta) = (c as u32, mapped as i64 - c as i64);
+        if let Some((first, last, last_delta, step)) = ranges.last_mut() {
+            let gap = c - *last;
+            if *last_delta == delta && gap <= 2 && (first == last || *step == gap) {
+                *last = c;
+                *step = gap;
+                continue;
+            }
+        }
+        ranges.push((c, c, delta, 1));
+    }
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|(first, last, delta, step)| {
+            format!("    {{0x{:x}, 0x{:x}, {}, {}}}", first, last, delta, step)
+        })
+        .collect();
+    ranges.join(",\n")
+}
+
 fn get_symbol(args: &Args, sym: &str, info: &Info) -> Res {
     if let Some(val) = args.get(sym) {
         val()
@@ -49,12 +1091,68 @@ fn get_symbol(args: &Args, sym: &str, info: &Info) -> Res {
     }
 }
 
+fn get_str(args: &Args, sym: &str, info: &Info) -> Result<String, TError> {
+    match get_symbol(args, sym, info)? {
+        PrimVal(Str(s)) => Ok(s),
+        val => Err(TError::TypeMismatch(
+            format!("Expected {} to be a string", sym),
+            Box::new(val),
+            info.clone(),
+        )),
+    }
+}
+
+fn get_i32(args: &Args, sym: &str, info: &Info) -> Result<i32, TError> {
+    match get_symbol(args, sym, info)? {
+        PrimVal(I32(n)) => Ok(n),
+        val => Err(TError::TypeMismatch(
+            format!("Expected {} to be an i32", sym),
+            Box::new(val),
+            info.clone(),
+        )),
+    }
+}
+
+fn get_list(args: &Args, sym: &str, info: &Info) -> Result<Vec<Val>, TError> {
+    match get_symbol(args, sym, info)? {
+        List(vals) => Ok(vals),
+        val => Err(TError::TypeMismatch(
+            format!("Expected {} to be a list", sym),
+            Box::new(val),
+            info.clone(),
+        )),
+    }
+}
+
+fn get_set(args: &Args, sym: &str, info: &Info) -> Result<TypeSet, TError> {
+    match get_symbol(args, sym, info)? {
+        Set(vals) => Ok(vals),
+        val => Err(TError::TypeMismatch(
+            format!("Expected {} to be a set", sym),
+            Box::new(val),
+            info.clone(),
+        )),
+    }
+}
+
+fn get_map(args: &Args, sym: &str, info: &Info) -> Result<BTreeMap<Val, Val>, TError> {
+    match get_symbol(args, sym, info)? {
+        Map(vals) => Ok(vals),
+        val => Err(TError::TypeMismatch(
+            format!("Expected {} to be a map", sym),
+            Box::new(val),
+            info.clone(),
+        )),
+    }
+}
+
 pub fn get_implementation(name: String) -> Option<FuncImpl> {
     match name.as_str() {
         "print" => Some(Box::new(|_, args, info| {
             let val = get_symbol(&args, "it", &info)?;
             match val {
                 PrimVal(Str(s)) => print!("{}", s),
+                PrimVal(Char(c)) => print!("{}", c),
                 s => print!("{:?}", s),
             };
             Ok(int32(0))
@@ -63,6 +1161,7 @@ pub fn get_implementation(name: String) -> Option<FuncImpl> {
             let val = get_symbol(&args, "it", &info)?;
             match val {
                 PrimVal(Str(s)) => eprint!("{}", s),
+                PrimVal(Char(c)) => eprint!("{}", c),
                 s => eprint!("{:?}", s),
             };
             Ok(int32(0))
@@ -143,6 +1242,10 @@ pub fn get_implementation(name: String) -> Option<FuncImpl> {
             };
             std::process::exit(code);
         })),
+        "lib::exists" => Some(Box::new(|storage, args, info| {
+            let name = get_str(&args, "it", &info)?;
+            Ok(boolean(storage.find_module(&name).is_some()))
+        })),
         "parse_i32" => Some(Box::new(|_, args, info| {
             let val = get_symbol(&args, "it", &info)?;
             match val {
@@ -168,12 +1271,149 @@ pub fn get_implementation(name: String) -> Option<FuncImpl> {
                 info,
             )
         })),
+        "List" => Some(Box::new(|_, args, info| {
+            Ok(list_type(get_symbol(&args, "it", &info)?))
+        })),
+        "Set" => Some(Box::new(|_, args, info| {
+            Ok(set_type(get_symbol(&args, "it", &info)?))
+        })),
+        "Map" => Some(Box::new(|_, args, info| {
+            Ok(map_type(
+                get_symbol(&args, "key", &info)?,
+                get_symbol(&args, "value", &info)?,
+            ))
+        })),
+        "len" => Some(Box::new(|_, args, info| {
+            let len = match get_symbol(&args, "it", &info)? {
+                List(vals) => vals.len(),
+                Set(vals) => vals.len(),
+                Map(vals) => vals.len(),
+                PrimVal(Str(s)) => s.chars().count(),
+                val => {
+                    return Err(TError::TypeMismatch(
+                        "Expected len argument to be a container".to_string(),
+                        Box::new(val),
+                        info,
+                    ))
+                }
+            };
+            Ok(int32(len as i32))
+        })),
+        "to_set" => Some(Box::new(|_, args, info| {
+            Ok(Set(get_list(&args, "it", &info)?.into_iter().collect()))
+        })),
+        "to_list" => Some(Box::new(|_, args, info| {
+            Ok(List(get_set(&args, "it", &info)?.into_iter().collect()))
+        })),
+        "contains" => Some(Box::new(|_, args, info| {
+            let vals = get_set(&args, "it", &info)?;
+            Ok(boolean(vals.contains(&get_symbol(&args, "value", &info)?)))
+        })),
+        "insert" => Some(Box::new(|_, args, info| {
+            let mut vals = get_set(&args, "it", &info)?;
+            vals.insert(get_symbol(&args, "value", &info)?);
+            Ok(Set(vals))
+        })),
+        "to_map" => Some(Box::new(|_, args, info| {
+            let keys = get_list(&args, "keys", &info)?;
+            let values = get_list(&args, "values", &info)?;
+            if keys.len() != values.len() {
+                return Err(TError::MismatchedKeys(keys.len(), values.len(), info));
+            }
+            Ok(Map(keys.into_iter().zip(values).collect()))
+        })),
+        "get" => Some(Box::new(|_, args, info| {
+            let vals = get_map(&args, "it", &info)?;
+            let key = get_symbol(&args, "key", &info)?;
+            match vals.get(&key) {
+                Some(val) => Ok(val.clone()),
+                None => Err(TError::MissingKey(format!("{:?}", key), info)),
+            }
+        })),
+        "put" => Some(Box::new(|_, args, info| {
+            let mut vals = get_map(&args, "it", &info)?;
+            vals.insert(
+                get_symbol(&args, "key", &info)?,
+                get_symbol(&args, "value", &info)?,
+            );
+            Ok(Map(vals))
+        })),
+        "keys" => Some(Box::new(|_, args, info| {
+            Ok(List(get_map(&args, "it", &info)?.into_keys().collect()))
+        })),
+        "values" => Some(Box::new(|_, args, info| {
+            Ok(List(get_map(&args, "it", &info)?.into_values().collect()))
+        })),
+        "to_string" => Some(Box::new(|_, args, info| {
+            Ok(string(&to_str(&get_symbol(&args, "it", &info)?)))
+        })),
+        "chars" => Some(Box::new(|_, args, info| {
+            let s = get_str(&args, "it", &info)?;
+            Ok(List(s.chars().map(|chr| PrimVal(Char(chr))).collect()))
+        })),
+        "slice" => Some(Box::new(|_, args, info| {
+            let s = get_str(&args, "it", &info)?;
+            let start = usize::try_from(get_i32(&args, "start", &info)?).unwrap_or(0);
+            let end = usize::try_from(get_i32(&args, "end", &info)?).unwrap_or(0);
+            Ok(string(
+                &s.chars()
+                    .skip(start)
+                    .take(end.saturating_sub(start))
+                    .collect::<String>(),
+            ))
+        })),
+        "split" => Some(Box::new(|_, args, info| {
+            let s = get_str(&args, "it", &info)?;
+            let sep = get_str(&args, "sep", &info)?;
+            if sep.is_empty() {
+                return Ok(List(vec![string(&s)]));
+            }
+            Ok(List(s.split(sep.as_str()).map(string).collect()))
+        })),
+        "join" => Some(Box::new(|_, args, info| {
+            let sep = get_str(&args, "sep", &info)?;
+            let parts: Vec<String> = get_list(&args, "it", &info)?.iter().map(to_str).collect();
+            Ok(string(&parts.join(&sep)))
+        })),
+        "find" => Some(Box::new(|_, args, info| {
+            let s = get_str(&args, "it", &info)?;
+            let value = get_str(&args, "value", &info)?;
+            Ok(int32(match s.find(value.as_str()) {
+                Some(at) => s[..at].chars().count() as i32,
+                None => -1,
+            }))
+        })),
+        "replace" => Some(Box::new(|_, args, info| {
+            let s = get_str(&args, "it", &info)?;
+            let from = get_str(&args, "from", &info)?;
+            if from.is_empty() {
+                return Ok(string(&s));
+            }
+            Ok(string(&s.replace(&from, &get_str(&args, "to", &info)?)))
+        })),
+        "upper" => Some(Box::new(|_, args, info| {
+            Ok(string(&map_letters(&get_str(&args, "it", &info)?, |c| {
+                c.to_uppercase()
+            })))
+        })),
+        "lower" => Some(Box::new(|_, args, info| {
+            Ok(string(&map_letters(&get_str(&args, "it", &info)?, |c| {
+                c.to_lowercase()
+            })))
+        })),
         "argc" => Some(Box::new(|db, _, _info| {
             Ok(int32(db.options.interpreter_args.len() as i32))
         })),
         "argv" => Some(Box::new(|db, args, info| {
             match get_symbol(&args, "it", &info)? {
-                PrimVal(I32(ind)) => Ok(string(&db.options.interpreter_args[ind as usize])),
+                PrimVal(I32(ind)) => {
+                    let args = &db.options.interpreter_args;
+                    usize::try_from(ind)
+                        .ok()
+                        .and_then(|ind| args.get(ind))
+                        .map(|arg| string(arg))
+                        .ok_or(TError::IndexOutOfBounds(ind, "list", args.len(), info))
+                }
                 value => Err(TError::TypeMismatch(
                     "Expected index to be of type i32".to_string(),
                     Box::new(value),
@@ -185,6 +1425,46 @@ pub fn get_implementation(name: String) -> Option<FuncImpl> {
     }
 }
 
+// The operations of the built in interfaces, with `Self` standing for the implementing type.
+pub fn get_interface(name: &str) -> Option<Vec<(String, Val)>> {
+    let operation = |name: &str, arguments: Val, results: Val| {
+        let ty = Function {
+            intros: dict!(),
+            arguments: Box::new(arguments),
+            results: Box::new(results),
+        };
+        (name.to_string(), ty)
+    };
+    match name {
+        "Number" => Some(
+            ["add", "sub", "mul", "div"]
+                .iter()
+                .map(|name| {
+                    let arguments = rec!("left" => variable("Self"), "right" => variable("Self"));
+                    operation(name, arguments, variable("Self"))
+                })
+                .collect(),
+        ),
+        "Display" => Some(vec![operation(
+            "to_string",
+            rec!("it" => variable("Self")),
+            string_type(),
+        )]),
+        _ => None,
+    }
+}
+
+// The operation of a built in interface that an operator uses, e.g. `+` uses `Number`'s `add`.
+pub fn interface_operation(op: &str) -> Option<&'static str> {
+    match op {
+        "+" => Some("add"),
+        "-" => Some("sub"),
+        "*" => Some("mul"),
+        "/" => Some("div"),
+        _ => None,
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum Direction {
     Left,
@@ -193,12 +1473,30 @@ pub enum Direction {
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum Semantic {
-    Operator { binding: i32, assoc: Direction },
+    Operator {
+        binding: i32,
+        assoc: Direction,
+        // The operators of the same binding power that this can be mixed with, without
+        // parentheses. `None` allows any of them.
+        mixes: Option<Vec<String>>,
+    },
     Func,
 }
 
 fn operator(binding: i32, assoc: Direction) -> Semantic {
-    Semantic::Operator { binding, assoc }
+    Semantic::Operator {
+        binding,
+        assoc,
+        mixes: None,
+    }
+}
+
+fn operator_mixing(binding: i32, assoc: Direction, mixes: &[&str]) -> Semantic {
+    Semantic::Operator {
+        binding,
+        assoc,
+        mixes: Some(mixes.iter().map(|op| op.to_string()).collect()),
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -245,11 +1543,6 @@ impl LangImpl {
         self
     }
 
-    fn with_arg_processor(mut self, arg_processor: &str) -> LangImpl {
-        self.arg_processor = arg_processor.to_string();
-        self
-    }
-
     fn with_includes(mut self, includes: &str) -> LangImpl {
         self.includes = includes.to_string();
         self
@@ -266,96 +1559,385 @@ pub fn get_externs() -> Result<HashMap<String, Extern>, TError> {
     use Semantic::Func;
     let mut externs = vec![
         Extern {
-            name: "argc".to_string(),
-            value: builtin("argc"),
+            name: "argc".to_string(),
+            value: builtin("argc"),
+            semantic: Func,
+            ty: with_effects(i32_type(), &["Args"]).into_node(),
+            cpp: LangImpl::new("[&argc](){return argc;}"),
+        },
+        Extern {
+            name: "argv".to_string(),
+            value: builtin("argv"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(with_effects(string_type(), &["Args"])),
+                intros: dict!(),
+                arguments: Box::new(rec!("it" => i32_type())),
+            }.into_node(),
+            cpp: LangImpl::new("([&argv](const int x){return argv[x];})"),
+        },
+        Extern {
+            name: "eprint".to_string(),
+            value: builtin("eprint"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(with_effects(unit_type(), &["Stderr"])),
+                arguments: Box::new(rec! {"it" => string_type()}),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::cerr << ").with_includes("#include <iostream>"),
+        },
+        Extern {
+            name: "exit".to_string(),
+            value: builtin("exit"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(with_effects(never_type(), &["Exit"])),
+                arguments: Box::new(rec! {"it" => i32_type()}),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("[](const int code){exit(code);}")
+                .with_includes("#include <stdlib.h>"),
+        },
+        Extern {
+            name: "lib::exists".to_string(),
+            value: builtin("lib::exists"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(bit_type()),
+                arguments: Box::new(rec! {"it" => string_type()}),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("/*lib::exists: compiled by the code generator*/"),
+        },
+        Extern {
+            name: "match".to_string(),
+            value: builtin("match"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("b")),
+                arguments: Box::new(rec! {"it" => variable("a")}),
+                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("/*match: compiled by the code generator*/"),
+        },
+        Extern {
+            name: "parse_i32".to_string(),
+            value: builtin("parse_i32"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(Union(set![i32_type(), never_type()])),
+                arguments: Box::new(rec! {"it" => string_type()}),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::stoi").with_includes("#include <string>"),
+        },
+        Extern {
+            name: "print".to_string(),
+            value: builtin("print"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(with_effects(unit_type(), &["Stdout"])),
+                arguments: Box::new(rec! {"it" => string_type()}),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::cout << ").with_includes("#include <iostream>"),
+        },
+        Extern {
+            name: "resume".to_string(),
+            value: builtin("resume"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("b")),
+                arguments: Box::new(rec! {"it" => variable("a")}),
+                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako_resume"),
+        },
+        Extern {
+            name: "pointer".to_string(),
+            value: builtin("pointer"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec! {"it" => variable("Type")}),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("std::cout << ").with_includes("#include <iostream>"),
+        },
+        Extern {
+            name: "struct".to_string(),
+            value: builtin("struct"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("a")),
+                arguments: Box::new(variable("a")),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("[](const int code){exit(code);}")
+                .with_includes("#include <stdlib.h>"),
+        },
+        Extern {
+            name: "len".to_string(),
+            value: builtin("len"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(i32_type()),
+                arguments: Box::new(rec!("it" => variable("a"))),
+                intros: dict!("a" => variable("Collection")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::len").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "map".to_string(),
+            value: builtin("map"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(list_type(variable("b"))),
+                arguments: Box::new(rec!("it" => list_type(variable("a")), "f" => Function {
+                    intros: dict!(),
+                    results: Box::new(variable("b")),
+                    arguments: Box::new(rec!("it" => variable("a"))),
+                })),
+                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::map").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "filter".to_string(),
+            value: builtin("filter"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(list_type(variable("a"))),
+                arguments: Box::new(rec!("it" => list_type(variable("a")), "f" => Function {
+                    intros: dict!(),
+                    results: Box::new(bit_type()),
+                    arguments: Box::new(rec!("it" => variable("a"))),
+                })),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::filter").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "fold".to_string(),
+            value: builtin("fold"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("b")),
+                arguments: Box::new(rec!("it" => list_type(variable("a")), "init" => variable("b"), "f" => Function {
+                    intros: dict!(),
+                    results: Box::new(variable("b")),
+                    arguments: Box::new(rec!("acc" => variable("b"), "it" => variable("a"))),
+                })),
+                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::fold").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "to_set".to_string(),
+            value: builtin("to_set"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(set_type(variable("a"))),
+                arguments: Box::new(rec!("it" => list_type(variable("a")))),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::to_set").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "to_list".to_string(),
+            value: builtin("to_list"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(list_type(variable("a"))),
+                arguments: Box::new(rec!("it" => set_type(variable("a")))),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::to_list").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "contains".to_string(),
+            value: builtin("contains"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(bit_type()),
+                arguments: Box::new(rec!("it" => set_type(variable("a")), "value" => variable("a"))),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::contains").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "insert".to_string(),
+            value: builtin("insert"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(set_type(variable("a"))),
+                arguments: Box::new(rec!("it" => set_type(variable("a")), "value" => variable("a"))),
+                intros: dict!("a" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::insert").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "to_map".to_string(),
+            value: builtin("to_map"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(map_type(variable("k"), variable("v"))),
+                arguments: Box::new(rec!("keys" => list_type(variable("k")), "values" => list_type(variable("v")))),
+                intros: dict!("k" => variable("Type"), "v" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::to_map").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "get".to_string(),
+            value: builtin("get"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(Union(set![variable("v"), never_type()])),
+                arguments: Box::new(rec!("it" => map_type(variable("k"), variable("v")), "key" => variable("k"))),
+                intros: dict!("k" => variable("Type"), "v" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::get").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "put".to_string(),
+            value: builtin("put"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(map_type(variable("k"), variable("v"))),
+                arguments: Box::new(rec!("it" => map_type(variable("k"), variable("v")), "key" => variable("k"), "value" => variable("v"))),
+                intros: dict!("k" => variable("Type"), "v" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::put").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "to_string".to_string(),
+            value: builtin("to_string"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(string_type()),
+                arguments: Box::new(rec!("it" => variable("a"))),
+                intros: dict!("a" => variable("Display")),
+            }.into_node(),
+            cpp: LangImpl::new("std::to_string").with_includes(TO_STRING),
+        },
+        Extern {
+            name: "chars".to_string(),
+            value: builtin("chars"),
             semantic: Func,
-            ty: i32_type().into_node(),
-            cpp: LangImpl::new("[&argc](){return argc;}"),
+            ty: Function {
+                results: Box::new(list_type(char_type())),
+                arguments: Box::new(rec!("it" => string_type())),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("tako::chars").with_includes(CONTAINERS),
         },
         Extern {
-            name: "argv".to_string(),
-            value: builtin("argv"),
+            name: "slice".to_string(),
+            value: builtin("slice"),
             semantic: Func,
             ty: Function {
                 results: Box::new(string_type()),
+                arguments: Box::new(rec!(
+                    "it" => string_type(),
+                    "start" => i32_type(),
+                    "end" => i32_type()
+                )),
                 intros: dict!(),
-                arguments: Box::new(rec!("it" => i32_type())),
             }.into_node(),
-            cpp: LangImpl::new("([&argv](const int x){return argv[x];})"),
+            cpp: LangImpl::new("tako::slice").with_includes(CONTAINERS),
         },
         Extern {
-            name: "eprint".to_string(),
-            value: builtin("eprint"),
+            name: "split".to_string(),
+            value: builtin("split"),
             semantic: Func,
             ty: Function {
-                results: Box::new(WithRequirement(
-                    Box::new(unit_type()),
-                    vec!["stderr".to_string()],
-                )),
-                arguments: Box::new(rec! {"it" => string_type()}),
+                results: Box::new(list_type(string_type())),
+                arguments: Box::new(rec!("it" => string_type(), "sep" => string_type())),
                 intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("std::cerr << ").with_includes("#include <iostream>"),
+            cpp: LangImpl::new("tako::split").with_includes(CONTAINERS),
         },
         Extern {
-            name: "exit".to_string(),
-            value: builtin("exit"),
+            name: "join".to_string(),
+            value: builtin("join"),
             semantic: Func,
             ty: Function {
-                results: Box::new(never_type()),
-                arguments: Box::new(rec! {"it" => i32_type()}),
+                results: Box::new(string_type()),
+                arguments: Box::new(rec!("it" => list_type(string_type()), "sep" => string_type())),
                 intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("[](const int code){exit(code);}")
-                .with_includes("#include <stdlib.h>"),
+            cpp: LangImpl::new("tako::join").with_includes(CONTAINERS),
         },
         Extern {
-            name: "parse_i32".to_string(),
-            value: builtin("parse_i32"),
+            name: "find".to_string(),
+            value: builtin("find"),
             semantic: Func,
             ty: Function {
-                results: Box::new(Union(set![i32_type(), never_type()])),
-                arguments: Box::new(rec! {"it" => string_type()}),
+                results: Box::new(i32_type()),
+                arguments: Box::new(rec!("it" => string_type(), "value" => string_type())),
                 intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("std::stoi").with_includes("#include <string>"),
+            cpp: LangImpl::new("tako::find").with_includes(CONTAINERS),
         },
         Extern {
-            name: "print".to_string(),
-            value: builtin("print"),
+            name: "replace".to_string(),
+            value: builtin("replace"),
             semantic: Func,
             ty: Function {
-                results: Box::new(WithRequirement(
-                    Box::new(unit_type()),
-                    vec!["stdout".to_string()],
+                results: Box::new(string_type()),
+                arguments: Box::new(rec!(
+                    "it" => string_type(),
+                    "from" => string_type(),
+                    "to" => string_type()
                 )),
-                arguments: Box::new(rec! {"it" => string_type()}),
                 intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("std::cout << ").with_includes("#include <iostream>"),
+            cpp: LangImpl::new("tako::replace").with_includes(CONTAINERS),
         },
         Extern {
-            name: "pointer".to_string(),
-            value: builtin("pointer"),
+            name: "upper".to_string(),
+            value: builtin("upper"),
             semantic: Func,
             ty: Function {
-                results: Box::new(variable("a")),
-                arguments: Box::new(rec! {"it" => variable("Type")}),
-                intros: dict!("a" => variable("Type")),
+                results: Box::new(string_type()),
+                arguments: Box::new(rec!("it" => string_type())),
+                intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("std::cout << ").with_includes("#include <iostream>"),
+            cpp: LangImpl::new("tako::upper").with_includes(CASES),
         },
         Extern {
-            name: "struct".to_string(),
-            value: builtin("struct"),
+            name: "lower".to_string(),
+            value: builtin("lower"),
             semantic: Func,
             ty: Function {
-                results: Box::new(variable("a")),
-                arguments: Box::new(variable("a")),
-                intros: dict!("a" => variable("Type")),
+                results: Box::new(string_type()),
+                arguments: Box::new(rec!("it" => string_type())),
+                intros: dict!(),
             }.into_node(),
-            cpp: LangImpl::new("[](const int code){exit(code);}")
-                .with_includes("#include <stdlib.h>"),
+            cpp: LangImpl::new("tako::lower").with_includes(CASES),
+        },
+        Extern {
+            name: "keys".to_string(),
+            value: builtin("keys"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(list_type(variable("k"))),
+                arguments: Box::new(rec!("it" => map_type(variable("k"), variable("v")))),
+                intros: dict!("k" => variable("Type"), "v" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::keys").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "values".to_string(),
+            value: builtin("values"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(list_type(variable("v"))),
+                arguments: Box::new(rec!("it" => map_type(variable("k"), variable("v")))),
+                intros: dict!("k" => variable("Type"), "v" => variable("Type")),
+            }.into_node(),
+            cpp: LangImpl::new("tako::values").with_includes(CONTAINERS),
         },
         Extern {
             name: ";".to_string(),
@@ -390,17 +1972,144 @@ pub fn get_externs() -> Result<HashMap<String, Extern>, TError> {
             }.into_node(),
             cpp: LangImpl::operator(" = "),
         },
+        Extern {
+            name: ":=".to_string(),
+            value: builtin(":="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Identifier"), "b" => variable("Type")),
+                results: Box::new(variable("b")),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+            }.into_node(),
+            cpp: LangImpl::operator(" = "),
+        },
+        Extern {
+            name: "<-".to_string(),
+            value: builtin("<-"),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("="),
+        },
+        Extern {
+            name: "+=".to_string(),
+            value: builtin("+="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Number")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("+="),
+        },
+        Extern {
+            name: "-=".to_string(),
+            value: builtin("-="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Number")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("-="),
+        },
+        Extern {
+            name: "*=".to_string(),
+            value: builtin("*="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Number")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("*="),
+        },
+        Extern {
+            name: "/=".to_string(),
+            value: builtin("/="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Number")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("/="),
+        },
+        Extern {
+            name: "%=".to_string(),
+            value: builtin("%="),
+            semantic: operator(40, Right),
+            ty: Function {
+                intros: dict!("a" => variable("Number")),
+                results: Box::new(with_effects(variable("a"), &["Mut"])),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::operator("%="),
+        },
+        Extern {
+            name: ":".to_string(),
+            value: builtin(":"),
+            semantic: operator(41, Left),
+            ty: Function {
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("Type"))),
+            }.into_node(),
+            cpp: LangImpl::operator(":"),
+        },
+        Extern {
+            name: "|=".to_string(),
+            value: builtin("|="),
+            semantic: operator(42, Left),
+            ty: Function {
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => Function {
+                    intros: dict!(),
+                    results: Box::new(bit_type()),
+                    arguments: Box::new(rec!("it" => variable("a"))),
+                })),
+            }.into_node(),
+            cpp: LangImpl::new("/*|=: compiled by the code generator*/"),
+        },
+        Extern {
+            name: "~".to_string(),
+            value: builtin("~"),
+            semantic: operator(43, Left),
+            ty: Function {
+                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+            }.into_node(),
+            cpp: LangImpl::operator("~"),
+        },
         Extern {
             name: "?".to_string(),
             value: builtin("?"),
             semantic: operator(45, Left),
             ty: Function {
-                intros: dict!("a" => variable("Type"), "b" => variable("Type")),
-                results: Box::new(Union(set!(variable("a"), variable("b")))),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("?"),
         },
+        Extern {
+            name: "?:".to_string(),
+            value: builtin("?:"),
+            semantic: operator_mixing(45, Right, &[]),
+            ty: Function {
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => optional_type(variable("a")), "right" => variable("a"))),
+            }.into_node(),
+            cpp: LangImpl::new("tako::or_else")
+                .with_includes(OPTIONALS)
+                .with_arg_joiner(", "),
+        },
         Extern {
             name: "|-".to_string(),
             value: builtin("|-"),
@@ -419,7 +2128,7 @@ pub fn get_externs() -> Result<HashMap<String, Extern>, TError> {
             ty: Function {
                 intros: dict!("a" => variable("Type")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("Type"), "right" => variable("a"))),
+                arguments: Box::new(rec!("left" => bit_type(), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("-|"),
         },
@@ -454,23 +2163,9 @@ pub fn get_externs() -> Result<HashMap<String, Extern>, TError> {
                 results: Box::new(string_type()),
                 arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
             }.into_node(),
-            cpp: LangImpl::operator("+")
-                .with_arg_processor("std::to_string")
-                .with_includes(
-                    "#include <string>
-#include <sstream>
-namespace std{
-template <typename T>
-string to_string(const T& t){
-  stringstream out;
-  out << t;
-  return out.str();
-}
-string to_string(const bool& t){
-  return t ? \"true\" : \"false\";
-}
-}",
-                ),
+            cpp: LangImpl::new("tako::concat")
+                .with_includes(TO_STRING)
+                .with_arg_joiner(", "),
         },
         Extern {
             name: "->".to_string(),
@@ -492,9 +2187,9 @@ string to_string(const bool& t){
             value: builtin("<"),
             semantic: operator(51, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(bit_type()),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("<"),
         },
@@ -503,9 +2198,9 @@ string to_string(const bool& t){
             value: builtin("<="),
             semantic: operator(51, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(bit_type()),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("<="),
         },
@@ -514,9 +2209,9 @@ string to_string(const bool& t){
             value: builtin(">"),
             semantic: operator(51, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(bit_type()),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator(">"),
         },
@@ -525,9 +2220,9 @@ string to_string(const bool& t){
             value: builtin(">="),
             semantic: operator(51, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(bit_type()),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator(">="),
         },
@@ -556,7 +2251,7 @@ string to_string(const bool& t){
         Extern {
             name: "||".to_string(),
             value: builtin("||"),
-            semantic: operator(60, Left),
+            semantic: operator_mixing(60, Left, &[]),
             ty: Function {
                 intros: dict!(),
                 results: Box::new(bit_type()),
@@ -567,7 +2262,7 @@ string to_string(const bool& t){
         Extern {
             name: "&&".to_string(),
             value: builtin("&&"),
-            semantic: operator(60, Left),
+            semantic: operator_mixing(60, Left, &[]),
             ty: Function {
                 intros: dict!(),
                 results: Box::new(bit_type()),
@@ -613,9 +2308,9 @@ string to_string(const bool& t){
             value: builtin("+"),
             semantic: operator(70, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("+"),
         },
@@ -624,9 +2319,9 @@ string to_string(const bool& t){
             value: builtin("*"),
             semantic: operator(80, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("*"),
         },
@@ -635,9 +2330,9 @@ string to_string(const bool& t){
             value: builtin("%"),
             semantic: operator(80, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("%"),
         },
@@ -646,9 +2341,9 @@ string to_string(const bool& t){
             value: builtin("/"),
             semantic: operator(80, Left),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("a"))),
             }.into_node(),
             cpp: LangImpl::operator("/"),
         },
@@ -657,9 +2352,10 @@ string to_string(const bool& t){
             value: builtin("^"),
             semantic: operator(90, Right),
             ty: Function {
-                intros: dict!("a" => variable("Number"), "b" => variable("Number")),
+                intros: dict!("a" => variable("Number")),
                 results: Box::new(variable("a")),
-                arguments: Box::new(rec!("left" => variable("a"), "right" => variable("b"))),
+                // Powers are counted with `I32`s, whatever the type of the base.
+                arguments: Box::new(rec!("left" => variable("a"), "right" => i32_type())),
             }.into_node(),
             cpp: LangImpl::new("pow")
                 .with_includes("#include <cmath>")
@@ -669,7 +2365,7 @@ string to_string(const bool& t){
         Extern {
             name: ".".to_string(),
             value: builtin("."),
-            semantic: operator(100, Right),
+            semantic: operator(100, Left),
             ty: Function {
                 intros: dict!("a" => variable("Type"), "b" => variable("Type"), "c" => variable("Type")),
                 results: Box::new(variable("c")),
@@ -679,6 +2375,32 @@ string to_string(const bool& t){
             }.into_node(),
             cpp: LangImpl::new("[](const auto l, const auto r){return r(l);}"),
         },
+        Extern {
+            name: "?.".to_string(),
+            value: builtin("?."),
+            semantic: operator(100, Left),
+            ty: Function {
+                intros: dict!("a" => variable("Type"), "b" => variable("Type"), "c" => variable("Type")),
+                results: Box::new(optional_type(variable("c"))),
+                arguments: Box::new(
+                    rec!("left" => optional_type(variable("a")), "right" => Function{intros: dict!(), arguments: Box::new(rec!("it" => variable("a"))), results: Box::new(variable("c"))}),
+                ),
+            }.into_node(),
+            cpp: LangImpl::new("tako::and_then").with_includes(OPTIONALS),
+        },
+        Extern {
+            name: "[]".to_string(),
+            value: builtin("[]"),
+            semantic: operator(100, Left),
+            ty: Function {
+                intros: dict!("a" => variable("Type")),
+                results: Box::new(variable("a")),
+                arguments: Box::new(rec!("left" => list_type(variable("a")), "right" => i32_type())),
+            }.into_node(),
+            cpp: LangImpl::new("tako::index")
+                .with_arg_joiner(", ")
+                .with_includes(CONTAINERS),
+        },
         Extern {
             name: "I32".to_string(),
             value: i32_type(),
@@ -686,6 +2408,51 @@ string to_string(const bool& t){
             ty: variable("Type").into_node(),
             cpp: LangImpl::new("int32_t"),
         },
+        Extern {
+            name: "I64".to_string(),
+            value: i64_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("int64_t").with_includes("#include <cstdint>"),
+        },
+        Extern {
+            name: "U8".to_string(),
+            value: u8_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("tako::u8").with_includes(FIXED_NUMBERS),
+        },
+        Extern {
+            name: "U32".to_string(),
+            value: u32_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("uint32_t").with_includes("#include <cstdint>"),
+        },
+        Extern {
+            name: "U64".to_string(),
+            value: u64_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("uint64_t").with_includes("#include <cstdint>"),
+        },
+        Extern {
+            name: "F64".to_string(),
+            value: f64_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("tako::f64").with_includes(FIXED_NUMBERS),
+        },
+        Extern {
+            name: "BigInt".to_string(),
+            value: bigint_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("tako::bigint")
+                .with_includes(BIGINTS)
+                .with_flag("-lgmpxx")
+                .with_flag("-lgmp"),
+        },
         Extern {
             name: "Number".to_string(),
             value: number_type(),
@@ -700,6 +2467,46 @@ string to_string(const bool& t){
             ty: variable("Type").into_node(),
             cpp: LangImpl::new("std::string").with_includes("#include <string>"),
         },
+        Extern {
+            name: "List".to_string(),
+            value: builtin("List"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("Type")),
+                arguments: Box::new(rec!("it" => variable("Type"))),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::vector").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "Set".to_string(),
+            value: builtin("Set"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("Type")),
+                arguments: Box::new(rec!("it" => variable("Type"))),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::set").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "Map".to_string(),
+            value: builtin("Map"),
+            semantic: Func,
+            ty: Function {
+                results: Box::new(variable("Type")),
+                arguments: Box::new(rec!("key" => variable("Type"), "value" => variable("Type"))),
+                intros: dict!(),
+            }.into_node(),
+            cpp: LangImpl::new("std::map").with_includes(CONTAINERS),
+        },
+        Extern {
+            name: "Char".to_string(),
+            value: char_type(),
+            semantic: Func,
+            ty: variable("Type").into_node(),
+            cpp: LangImpl::new("std::string").with_includes("#include <string>"),
+        },
         Extern {
             name: "Bit".to_string(),
             value: bit_type(),
@@ -728,6 +2535,55 @@ string to_string(const bool& t){
             ty: variable("Type").into_node(),
             cpp: LangImpl::new("auto"),
         },
+        Extern {
+            name: "Args".to_string(),
+            value: with_effects(unit_type(), &["Args"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Args: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "Exit".to_string(),
+            value: with_effects(unit_type(), &["Exit"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Exit: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "Stderr".to_string(),
+            value: with_effects(unit_type(), &["Stderr"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Stderr: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "Stdout".to_string(),
+            value: with_effects(unit_type(), &["Stdout"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Stdout: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "Mut".to_string(),
+            value: with_effects(unit_type(), &["Mut"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Mut: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "Print".to_string(),
+            value: with_effects(unit_type(), &["Stderr", "Stdout"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*Print: effects are not represented in C++*/ void"),
+        },
+        Extern {
+            name: "IO".to_string(),
+            value: with_effects(unit_type(), &["Args", "Exit", "Stderr", "Stdout"]),
+            semantic: Func,
+            ty: variable("Effect").into_node(),
+            cpp: LangImpl::new("/*IO: effects are not represented in C++*/ void"),
+        },
     ];
     let mut extern_map: HashMap<String, Extern> = HashMap::new();
     while let Some(extern_def) = externs.pop() {
@@ -735,3 +2591,18 @@ string to_string(const bool& t){
     }
     Ok(extern_map)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpp_case_tables_match_the_interpreter() {
+        for ranges in [
+            case_ranges(|c| c.to_uppercase()),
+            case_ranges(|c| c.to_lowercase()),
+        ] {
+            assert!(CASES.contains(&format!("[] = {{\n{}\n  }};", ranges)));
+        }
+    }
+}
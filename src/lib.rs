@@ -45,6 +45,7 @@ pub mod database;
 pub mod errors;
 pub mod externs;
 pub mod primitives;
+pub mod type_graph;
 
 mod location;
 mod symbol_table;
@@ -88,8 +89,18 @@ pub fn work_on_string<'a>(
     storage.set_file(filename, contents);
 
     use cli_options::Command;
+    if matches!(storage.options.cmd, Command::Build | Command::Interpret) {
+        // Effect and annotation errors stop programs from being built or run, but values of
+        // unexpected types are left for when the code is run, as unused or guarded code may never
+        // use them.
+        storage.check_effects(module_name.clone())?;
+    }
     if storage.options.cmd == Command::Build {
         storage.build_with_gpp(module_name)
+    } else if storage.options.cmd == Command::Check {
+        storage.check(module_name)
+    } else if storage.options.cmd == Command::Test && storage.options.generate_tests {
+        storage.generate_tests(module_name)
     } else {
         let root = storage.look_up_definitions(module_name)?;
         let mut interp = Interpreter::default();
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::sync::Arc;
 
 #[derive(PartialEq, Eq, Clone, Copy, Ord, PartialOrd, Hash)]
 pub struct Pos {
@@ -43,16 +44,16 @@ impl Default for Pos {
 
 #[derive(PartialEq, Eq, Clone, Ord, PartialOrd, Hash)]
 pub struct Loc {
-    pub filename: Option<String>,
+    // Shared by every location in a file (as there are many of them), empty if there is no file.
+    pub filename: Arc<str>,
     pub pos: Pos,
 }
 
 impl std::fmt::Debug for Loc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.filename {
-            Some(file) => write!(f, "{} ", file),
-            None => write!(f, ""),
-        }?;
+        if !self.filename.is_empty() {
+            write!(f, "{} ", self.filename)?;
+        }
         write!(f, "{:?}", self.pos)
     }
 }
@@ -66,7 +67,7 @@ impl Loc {
 impl Default for Loc {
     fn default() -> Self {
         Loc {
-            filename: None,
+            filename: "".into(),
             pos: Pos::default(),
         }
     }
@@ -47,8 +47,8 @@ impl Visitor<State, Node, Root, Path> for DefinitionFinder {
         }
         let mut search: Vec<Symbol> = state.path.clone();
         loop {
-            if let Some(Symbol::Anon()) = search.last() {
-                search.pop(); // Cannot look inside an 'anon'.
+            if let Some(Symbol::Anon(0)) = search.last() {
+                search.pop(); // Cannot look inside a call's arguments.
             }
             search.push(Symbol::new(&expr.name));
             let node = state.table.find_mut(&search);
@@ -93,7 +93,7 @@ impl Visitor<State, Node, Root, Path> for DefinitionFinder {
     }
 
     fn visit_apply(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
-        state.path.push(Symbol::Anon());
+        state.path.push(Symbol::Anon(0));
         let args = expr
             .args
             .iter()
@@ -122,7 +122,10 @@ impl Visitor<State, Node, Root, Path> for DefinitionFinder {
         if storage.debug_level() > 1 {
             eprintln!("visiting {} {}", path_to_string(&state.path), &expr.name);
         }
-        let value = Box::new(self.visit(storage, state, &expr.value)?);
+        state.path.push(Symbol::new(&expr.name));
+        let value = self.visit(storage, state, &expr.value);
+        state.path.pop();
+        let value = Box::new(value?);
         Ok(Abs {
             name: expr.name.clone(),
             value,
@@ -158,7 +161,32 @@ impl Visitor<State, Node, Root, Path> for DefinitionFinder {
     }
 
     fn visit_un_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
-        let inner = Box::new(self.visit(storage, state, &expr.inner)?);
+        let inner = if expr.name == "impl" {
+            // Only the methods are looked up, in the scope of the implementation.
+            let decl = expr.inner.as_let()?;
+            state.path.push(implementation(&decl)?.scope());
+            let value = self.visit(storage, state, &decl.value);
+            state.path.pop();
+            Box::new(
+                Let {
+                    value: Box::new(value?),
+                    ..decl
+                }
+                .into_node(),
+            )
+        } else if expr.name == "{}" {
+            // Blocks are looked up in the scope that was made for them.
+            let block = expr.get_info().defined_at;
+            let scope = block.and_then(|path| path.last().cloned());
+            state
+                .path
+                .push(scope.expect("Could not find scope for block"));
+            let inner = self.visit(storage, state, &expr.inner);
+            state.path.pop();
+            Box::new(inner?)
+        } else {
+            Box::new(self.visit(storage, state, &expr.inner)?)
+        };
         Ok(UnOp {
             name: expr.name.clone(),
             inner,
@@ -168,7 +196,53 @@ impl Visitor<State, Node, Root, Path> for DefinitionFinder {
     }
 
     fn visit_bin_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        if matches!(expr.name.as_str(), ";" | ",") {
+            if let Some(merged) = merge_clauses(expr)? {
+                return self.visit(storage, state, &merged);
+            }
+        }
         let left = Box::new(self.visit(storage, state, &expr.left)?);
+        if let ("." | "?.", Node::ApplyNode(call)) = (expr.name.as_str(), &*expr.right) {
+            // The method call is kept, so that the value is passed as `it` without a definition
+            // for it. With `?.`, the method is only called once the value isn't `()`.
+            let right = self
+                .visit_apply(storage, state, call)
+                .map_err(|err| method_error(call, err))?;
+            return Ok(BinOp {
+                name: expr.name.clone(),
+                left,
+                right: Box::new(right),
+                info: expr.get_info(),
+            }
+            .into_node());
+        }
+        if field_name(expr).is_some() {
+            // Fields belong to the value, so they aren't looked up as definitions.
+            return Ok(BinOp {
+                name: expr.name.clone(),
+                left,
+                right: expr.right.clone(),
+                info: expr.get_info(),
+            }
+            .into_node());
+        }
+        if let ("<-" | "+=" | "-=" | "*=" | "/=" | "%=", Node::SymNode(sym)) =
+            (expr.name.as_str(), &*left)
+        {
+            // Only variables declared with `:=` can be assigned to.
+            let mutable = sym
+                .info
+                .defined_at
+                .as_ref()
+                .and_then(|path| state.table.find(path))
+                .is_some_and(|entry| entry.value.mutable);
+            if !mutable {
+                return Err(TError::ImmutableAssignment(
+                    sym.name.clone(),
+                    expr.get_info(),
+                ));
+            }
+        }
         let right = Box::new(self.visit(storage, state, &expr.right)?);
         Ok(BinOp {
             name: expr.name.clone(),
@@ -1,11 +1,15 @@
 use crate::ast::*;
 use crate::database::DBStorage;
 use crate::errors::TError;
-use crate::externs::{prim_add_strs, prim_pow, Res};
+use crate::externs::{
+    get_interface, interface_operation, prim_add_strs, prim_index, prim_pow, Res,
+};
 use crate::primitives::{
-    boolean, int32, merge_vals, never_type, string, Frame, Prim::*, Val, Val::*,
+    boolean, builtin, constructor_of, data_constructors, data_type, float64, int32, merge_vals,
+    never_type, prim_type, string, unit_type, variable, Float, Frame, Prim::*, Val, Val::*,
 };
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
 
 pub type ImplFn<'a> =
     &'a mut dyn FnMut(&mut DBStorage, HashMap<String, Box<dyn Fn() -> Res>>, Info) -> Res;
@@ -15,16 +19,61 @@ pub type PureImplFn<'a> =
 // Walks the AST interpreting it.
 pub struct Interpreter<'a> {
     pub impls: HashMap<String, ImplFn<'a>>,
+    // Effect handlers installed by `~`, innermost last.
+    handlers: Vec<Handler>,
+    // The values passed to `resume` by the handler clauses that are running, innermost last.
+    resumes: Vec<Option<Val>>,
+    // The methods implementing each interface operation, by the type that they are for.
+    implementations: HashMap<String, HashMap<String, Val>>,
+    // The arguments of each interface operation that choose which method runs.
+    operations: HashMap<String, Vec<String>>,
+    // The most frames that the stack can hold, if it is limited.
+    pub depth_limit: Option<usize>,
+    // The first frame of each function call that is running, innermost last.
+    scopes: Vec<usize>,
+}
+
+// The operations handled by a `~` expression, with the depth of the stack that they were defined at
+// and the first frame of the function call that they were defined in.
+struct Handler {
+    clauses: Frame,
+    depth: usize,
+    scope: usize,
+    // The clauses waiting for the handled expression to finish, innermost last.
+    resumptions: Vec<Resumption>,
+}
+
+// The rest of a handler clause after a `resume` statement, which runs once the handled expression
+// has finished, with its value as the value of `resume`.
+struct Resumption {
+    state: State,
+    name: Option<String>,
+    rest: Vec<Node>,
 }
 
 impl<'a> Default for Interpreter<'a> {
     fn default() -> Interpreter<'a> {
         Interpreter {
             impls: HashMap::new(),
+            handlers: vec![],
+            resumes: vec![],
+            implementations: HashMap::new(),
+            operations: HashMap::new(),
+            depth_limit: None,
+            scopes: vec![],
         }
     }
 }
 
+// Names the type of a value, so that the methods implemented for the type can be found.
+// Values of data types are named by their constructor.
+fn type_key(val: &Val) -> Option<String> {
+    match val {
+        PrimVal(prim) => prim_type(prim).map(|ty| format!("{}", ty)),
+        val => constructor_of(val).map(|(name, _)| name.to_string()),
+    }
+}
+
 fn find_symbol<'a>(state: &'a [Frame], name: &str) -> Option<&'a Val> {
     for frame in state.iter().rev() {
         if let Some(val) = frame.get(name) {
@@ -35,8 +84,116 @@ fn find_symbol<'a>(state: &'a [Frame], name: &str) -> Option<&'a Val> {
     None
 }
 
-fn prim_add(l: &Val, r: &Val, _info: Info) -> Res {
+fn is_assignment(op: &str) -> bool {
+    matches!(op, "<-" | "+=" | "-=" | "*=" | "/=" | "%=")
+}
+
+// Makes a function that captures the values of the local variables that it uses, so that it
+// can be called after their frame has finished. Globals are found when the function is called,
+// so that definitions can refer to themselves and each other.
+fn closure(locals: &[Frame], body: &Node, params: &[String], info: &Info) -> Val {
+    let mut names = BTreeSet::new();
+    symbols_in(body, &mut names);
+    let mut body = body.clone();
+    for name in names.iter().rev() {
+        if params.contains(name) {
+            continue;
+        }
+        if let Some(val) = find_symbol(locals, name) {
+            let capture = Let {
+                name: name.clone(),
+                value: Box::new(val.clone().into_node()),
+                args: None,
+                info: info.clone(),
+            };
+            body = BinOp {
+                name: ";".to_string(),
+                left: Box::new(capture.into_node()),
+                right: Box::new(body),
+                info: info.clone(),
+            }
+            .into_node();
+        }
+    }
+    Lambda(Box::new(body))
+}
+
+// Applies an arithmetic operator to two numbers of the same type, fixed width integers wrap on
+// overflow. Returns None if the values are not numbers of the same type.
+fn prim_arith(op: &str, l: &Val, r: &Val, info: &Info) -> Option<Res> {
+    macro_rules! wrapping {
+        ($ctor: ident, $l: expr, $r: expr) => {
+            Ok(PrimVal($ctor(match op {
+                "+" => $l.wrapping_add(*$r),
+                "-" => $l.wrapping_sub(*$r),
+                "*" => $l.wrapping_mul(*$r),
+                _ if *$r == 0 => return Some(Err(TError::DivisionByZero(info.clone()))),
+                "/" => $l.wrapping_div(*$r),
+                _ => $l.wrapping_rem(*$r),
+            })))
+        };
+    }
+    let (l, r) = match (l, r) {
+        (PrimVal(l), PrimVal(r)) => (l, r),
+        _ => return None,
+    };
+    Some(match (l, r) {
+        (I32(l), I32(r)) => wrapping!(I32, l, r),
+        (I64(l), I64(r)) => wrapping!(I64, l, r),
+        (U8(l), U8(r)) => wrapping!(U8, l, r),
+        (U32(l), U32(r)) => wrapping!(U32, l, r),
+        (U64(l), U64(r)) => wrapping!(U64, l, r),
+        (F64(l), F64(r)) => Ok(float64(match op {
+            "+" => l.0 + r.0,
+            "-" => l.0 - r.0,
+            "*" => l.0 * r.0,
+            "/" => l.0 / r.0,
+            _ => l.0 % r.0,
+        })),
+        (BigInt(l), BigInt(r)) => match op {
+            "+" => Ok(PrimVal(BigInt(l + r))),
+            "-" => Ok(PrimVal(BigInt(l - r))),
+            "*" => Ok(PrimVal(BigInt(l * r))),
+            _ if *r == 0.into() => Err(TError::DivisionByZero(info.clone())),
+            "/" => Ok(PrimVal(BigInt(l / r))),
+            _ => Ok(PrimVal(BigInt(l % r))),
+        },
+        _ => return None,
+    })
+}
+
+// Compares two numbers of the same type. Floats are unordered if either is NaN.
+fn compare_numbers(l: &Val, r: &Val) -> Option<Option<Ordering>> {
+    match (l, r) {
+        (PrimVal(F64(l)), PrimVal(F64(r))) => Some(l.0.partial_cmp(&r.0)),
+        (PrimVal(I32(_)), PrimVal(I32(_)))
+        | (PrimVal(I64(_)), PrimVal(I64(_)))
+        | (PrimVal(U8(_)), PrimVal(U8(_)))
+        | (PrimVal(U32(_)), PrimVal(U32(_)))
+        | (PrimVal(U64(_)), PrimVal(U64(_)))
+        | (PrimVal(BigInt(_)), PrimVal(BigInt(_))) => Some(Some(l.cmp(r))),
+        _ => None,
+    }
+}
+
+fn prim_neg(i: &Val) -> Option<Val> {
+    Some(PrimVal(match i {
+        PrimVal(I32(n)) => I32(n.wrapping_neg()),
+        PrimVal(I64(n)) => I64(n.wrapping_neg()),
+        PrimVal(U8(n)) => U8(n.wrapping_neg()),
+        PrimVal(U32(n)) => U32(n.wrapping_neg()),
+        PrimVal(U64(n)) => U64(n.wrapping_neg()),
+        PrimVal(F64(x)) => F64(Float(-x.0)),
+        PrimVal(BigInt(n)) => BigInt(-n),
+        _ => return None,
+    }))
+}
+
+fn prim_add(l: &Val, r: &Val, info: Info) -> Res {
     use crate::primitives::sum;
+    if let Some(res) = prim_arith("+", l, r, &info) {
+        return res;
+    }
     match (l, r) {
         (PrimVal(Bool(l)), PrimVal(Bool(r))) => {
             Ok(int32(if *l { 1 } else { 0 } + if *r { 1 } else { 0 }))
@@ -44,12 +201,19 @@ fn prim_add(l: &Val, r: &Val, _info: Info) -> Res {
         (PrimVal(Bool(l)), PrimVal(I32(r))) => Ok(int32(r.wrapping_add(if *l { 1 } else { 0 }))),
         (PrimVal(Bool(l)), PrimVal(Str(r))) => Ok(PrimVal(Str(l.to_string() + &r.to_string()))),
         (PrimVal(I32(l)), PrimVal(Bool(r))) => Ok(int32(l.wrapping_add(if *r { 1 } else { 0 }))),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(l.wrapping_add(*r))),
         (PrimVal(I32(l)), PrimVal(Str(r))) => Ok(PrimVal(Str(l.to_string() + &r.to_string()))),
         (PrimVal(Str(l)), PrimVal(Bool(r))) => Ok(PrimVal(Str(l.to_string() + &r.to_string()))),
         (PrimVal(Str(l)), PrimVal(I32(r))) => Ok(PrimVal(Str(l.to_string() + &r.to_string()))),
         (PrimVal(Str(l)), PrimVal(Str(r))) => Ok(PrimVal(Str(l.to_string() + &r.to_string()))),
-        (l, r) => Ok(sum(vec![l.clone(), r.clone()])?),
+        // Values that aren't types can't be summed, so they are reported where they were added.
+        (l, r) => sum(vec![l.clone(), r.clone()]).map_err(|_| {
+            TError::TypeMismatch2(
+                "+".to_string(),
+                Box::new(l.clone()),
+                Box::new(r.clone()),
+                info,
+            )
+        }),
         //(l, r) => Err(TError::TypeMismatch2(
         //"+".to_string(),
         //Box::new((*l).clone()),
@@ -59,11 +223,34 @@ fn prim_add(l: &Val, r: &Val, _info: Info) -> Res {
     }
 }
 
+// Values of data types are equal when they have the same constructor and equal fields.
+fn data_eq(l: &Val, r: &Val, info: &Info) -> Option<Result<bool, TError>> {
+    let ((l_name, l_fields), (r_name, r_fields)) = (constructor_of(l)?, constructor_of(r)?);
+    if l_name != r_name {
+        return Some(Ok(false));
+    }
+    for ((_, l), (_, r)) in l_fields.iter().zip(r_fields) {
+        match prim_eq(l, r, info.clone()) {
+            Ok(PrimVal(Bool(true))) => {}
+            Ok(_) => return Some(Ok(false)),
+            Err(err) => return Some(Err(err)),
+        }
+    }
+    Some(Ok(true))
+}
+
 fn prim_eq(l: &Val, r: &Val, info: Info) -> Res {
+    if let Some(ord) = compare_numbers(l, r) {
+        return Ok(boolean(ord == Some(Ordering::Equal)));
+    }
+    if let Some(eq) = data_eq(l, r, &info) {
+        return eq.map(boolean);
+    }
     match (l, r) {
         (PrimVal(Bool(l)), PrimVal(Bool(r))) => Ok(boolean(*l == *r)),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(boolean(l == r)),
         (PrimVal(Str(l)), PrimVal(Str(r))) => Ok(boolean(l == r)),
+        // Optional values are checked against `()`.
+        (l, r) if *l == unit_type() || *r == unit_type() => Ok(boolean(l == r)),
         (l, r) => Err(TError::TypeMismatch2(
             "==".to_string(),
             Box::new((*l).clone()),
@@ -74,10 +261,16 @@ fn prim_eq(l: &Val, r: &Val, info: Info) -> Res {
 }
 
 fn prim_neq(l: &Val, r: &Val, info: Info) -> Res {
+    if let Some(ord) = compare_numbers(l, r) {
+        return Ok(boolean(ord != Some(Ordering::Equal)));
+    }
+    if let Some(eq) = data_eq(l, r, &info) {
+        return eq.map(|eq| boolean(!eq));
+    }
     match (l, r) {
         (PrimVal(Bool(l)), PrimVal(Bool(r))) => Ok(boolean(*l != *r)),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(boolean(l != r)),
         (PrimVal(Str(l)), PrimVal(Str(r))) => Ok(boolean(l != r)),
+        (l, r) if *l == unit_type() || *r == unit_type() => Ok(boolean(l != r)),
         (l, r) => Err(TError::TypeMismatch2(
             "!=".to_string(),
             Box::new((*l).clone()),
@@ -88,9 +281,11 @@ fn prim_neq(l: &Val, r: &Val, info: Info) -> Res {
 }
 
 fn prim_gt(l: &Val, r: &Val, info: Info) -> Res {
+    if let Some(ord) = compare_numbers(l, r) {
+        return Ok(boolean(ord == Some(Ordering::Greater)));
+    }
     match (l, r) {
         (PrimVal(Bool(l)), PrimVal(Bool(r))) => Ok(boolean(*l & !(*r))),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(boolean(l > r)),
         (PrimVal(Str(l)), PrimVal(Str(r))) => Ok(boolean(l > r)),
         (l, r) => Err(TError::TypeMismatch2(
             ">".to_string(),
@@ -102,9 +297,14 @@ fn prim_gt(l: &Val, r: &Val, info: Info) -> Res {
 }
 
 fn prim_gte(l: &Val, r: &Val, info: Info) -> Res {
+    if let Some(ord) = compare_numbers(l, r) {
+        return Ok(boolean(matches!(
+            ord,
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        )));
+    }
     match (l, r) {
         (PrimVal(Bool(l)), PrimVal(Bool(r))) => Ok(boolean(*l >= *r)),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(boolean(l >= r)),
         (PrimVal(Str(l)), PrimVal(Str(r))) => Ok(boolean(l >= r)),
         (l, r) => Err(TError::TypeMismatch2(
             ">=".to_string(),
@@ -116,9 +316,11 @@ fn prim_gte(l: &Val, r: &Val, info: Info) -> Res {
 }
 
 fn prim_sub(l: &Val, r: &Val, info: Info) -> Res {
+    if let Some(res) = prim_arith("-", l, r, &info) {
+        return res;
+    }
     match (l, r) {
-        (PrimVal(I32(l)), PrimVal(Bool(r))) => Ok(int32(l - if *r { 1 } else { 0 })),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(l - r)),
+        (PrimVal(I32(l)), PrimVal(Bool(r))) => Ok(int32(l.wrapping_sub(if *r { 1 } else { 0 }))),
         (l, r) => Err(TError::TypeMismatch2(
             "-".to_string(),
             Box::new((*l).clone()),
@@ -130,6 +332,9 @@ fn prim_sub(l: &Val, r: &Val, info: Info) -> Res {
 
 fn prim_mul(l: &Val, r: &Val, info: Info) -> Res {
     use crate::primitives::record;
+    if let Some(res) = prim_arith("*", l, r, &info) {
+        return res;
+    }
     let fail = || {
         Err(TError::TypeMismatch2(
             "*".to_string(),
@@ -145,33 +350,32 @@ fn prim_mul(l: &Val, r: &Val, info: Info) -> Res {
         (PrimVal(Str(l)), PrimVal(Bool(r))) => Ok(string(if *r { l } else { "" })),
         (PrimVal(Bool(_)), PrimVal(_)) => fail(),
         (PrimVal(_), PrimVal(Bool(_))) => fail(),
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(l.wrapping_mul(*r))),
-        (l, r) => Ok(record(vec![l.clone(), r.clone()])?),
+        (l, r) => record(vec![l.clone(), r.clone()]).or_else(|_| fail()),
     }
 }
 
 fn prim_div(l: &Val, r: &Val, info: Info) -> Res {
-    match (l, r) {
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(l / r)),
-        (l, r) => Err(TError::TypeMismatch2(
-            "/".to_string(),
-            Box::new((*l).clone()),
-            Box::new((*r).clone()),
-            info,
-        )),
+    if let Some(res) = prim_arith("/", l, r, &info) {
+        return res;
     }
+    Err(TError::TypeMismatch2(
+        "/".to_string(),
+        Box::new((*l).clone()),
+        Box::new((*r).clone()),
+        info,
+    ))
 }
 
 fn prim_mod(l: &Val, r: &Val, info: Info) -> Res {
-    match (l, r) {
-        (PrimVal(I32(l)), PrimVal(I32(r))) => Ok(int32(l % r)),
-        (l, r) => Err(TError::TypeMismatch2(
-            "%".to_string(),
-            Box::new((*l).clone()),
-            Box::new((*r).clone()),
-            info,
-        )),
+    if let Some(res) = prim_arith("%", l, r, &info) {
+        return res;
     }
+    Err(TError::TypeMismatch2(
+        "%".to_string(),
+        Box::new((*l).clone()),
+        Box::new((*r).clone()),
+        info,
+    ))
 }
 
 fn prim_and(l: &Val, r: &Val, info: Info) -> Res {
@@ -208,7 +412,24 @@ fn prim_type_arrow(l: Val, r: Val, _info: Info) -> Res {
 }
 
 pub fn prim_type_and(l: Val, r: Val) -> Res {
-    Ok(Val::Product(set!(l, r)))
+    Ok(match (l, r) {
+        // Effects are attached to the type of the value that is produced, e.g. `I32 & Stdout`.
+        (WithRequirement(ty, effs), other) | (other, WithRequirement(ty, effs)) => {
+            let ty = if *ty == unit_type() {
+                other
+            } else {
+                prim_type_and(*ty, other)?
+            };
+            let (ty, mut effs) = match ty {
+                WithRequirement(ty, more) => (*ty, [effs, more].concat()),
+                ty => (ty, effs),
+            };
+            effs.sort();
+            effs.dedup();
+            WithRequirement(Box::new(ty), effs)
+        }
+        (l, r) => Product(set!(l, r)),
+    })
 }
 
 fn prim_type_or(l: Val, r: Val, _info: Info) -> Res {
@@ -216,7 +437,549 @@ fn prim_type_or(l: Val, r: Val, _info: Info) -> Res {
 }
 
 // TODO: Return nodes.
+// Applies a constructor, filling in its fields with the arguments.
+fn construct(ctor: Val, args: &Frame, info: Info) -> Res {
+    let (name, fields) = match constructor_of(&ctor) {
+        Some((name, fields)) => (name.to_string(), fields.to_vec()),
+        None => return Ok(ctor),
+    };
+    // Constructors need a value for each of their fields, and have no other arguments.
+    if args.len() != fields.len() || fields.iter().any(|(field, _)| !args.contains_key(field)) {
+        let mut found: Vec<(String, Val)> = args
+            .iter()
+            .map(|(arg, val)| (arg.clone(), val.clone()))
+            .collect();
+        found.sort_by(|(a, _), (b, _)| a.cmp(b));
+        return Err(TError::UnexpectedType(
+            name,
+            Box::new(Struct(fields)),
+            Box::new(Struct(found)),
+            info,
+        ));
+    }
+    let mut vals = match ctor {
+        Struct(vals) => vals,
+        ctor => return Ok(ctor),
+    };
+    for (field, val) in vals.iter_mut().skip(1) {
+        *val = args[field].clone();
+    }
+    Ok(Struct(vals))
+}
+
 type State = Vec<Frame>;
+
+impl<'a> Interpreter<'a> {
+    // The first frame of the innermost function call that is running.
+    fn scope(&self) -> usize {
+        self.scopes.last().copied().unwrap_or(1)
+    }
+
+    // Finds a variable where it is visible to the code that is running, i.e. in the frames of the
+    // innermost function call or in the globals, but not in the frames of the function's callers.
+    fn lookup<'s>(&self, state: &'s [Frame], name: &str) -> Option<&'s Val> {
+        find_symbol(&state[self.scope()..], name).or_else(|| find_symbol(&state[..1], name))
+    }
+
+    // Evaluates `name <- value` (or a compound assignment), updating the closest definition.
+    fn visit_assign(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        let info = expr.clone().get_info();
+        let name = match &*expr.left {
+            Node::SymNode(sym) => &sym.name,
+            left => {
+                return Err(TError::InternalError(
+                    format!("Cannot assign to {}", left),
+                    info,
+                ))
+            }
+        };
+        let r = self.visit(storage, state, &expr.right)?;
+        let scope = self.scope();
+        let (globals, locals) = state.split_at_mut(1);
+        let frame = locals[scope - 1..]
+            .iter_mut()
+            .rev()
+            .chain(globals.iter_mut())
+            .find(|frame| frame.contains_key(name))
+            .ok_or_else(|| {
+                TError::UnknownSymbol(name.to_string(), info.clone(), "interpreter::?".to_string())
+            })?;
+        let l = frame.get(name).expect("Variable missing from its frame");
+        let value = match expr.name.as_str() {
+            "+=" => prim_add(l, &r, info)?,
+            "-=" => prim_sub(l, &r, info)?,
+            "*=" => prim_mul(l, &r, info)?,
+            "/=" => prim_div(l, &r, info)?,
+            "%=" => prim_mod(l, &r, info)?,
+            _ => r,
+        };
+        frame.insert(name.to_string(), value.clone());
+        Ok(value)
+    }
+
+    // Runs a `while` loop in place, rather than as the recursive function it is defined as, so
+    // that long and nested loops don't use up the stack.
+    fn visit_loop(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        cond: &Node,
+        body: &Node,
+        info: Info,
+    ) -> Res {
+        state.push(Frame::new());
+        let res = loop {
+            match self.visit(storage, state, cond) {
+                Ok(PrimVal(Bool(true))) => {}
+                Ok(PrimVal(Bool(false))) => break Ok(unit_type()),
+                Ok(val) => {
+                    break Err(TError::TypeMismatch(
+                        "while".to_string(),
+                        Box::new(val),
+                        info,
+                    ))
+                }
+                Err(err) => break Err(err),
+            }
+            if let Err(err) = self.visit(storage, state, body) {
+                break Err(err);
+            }
+        };
+        state.pop();
+        res
+    }
+
+    // Evaluates the fields of a tuple, e.g. `(3, y=4)` is a struct with the fields `0` and `y`.
+    fn visit_tuple(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let mut fields = vec![];
+        for (name, field) in tuple_fields(&expr.inner)? {
+            let val = self.visit(storage, state, field)?;
+            // Named fields are also defined, e.g. so that handlers can find their clauses.
+            let val = match field {
+                Node::LetNode(_) => state
+                    .last()
+                    .and_then(|frame| frame.get(&name))
+                    .cloned()
+                    .unwrap_or(val),
+                _ => val,
+            };
+            fields.push((name, val));
+        }
+        Ok(Struct(fields))
+    }
+
+    // Evaluates `[a, b, c]`, building a list of the elements.
+    fn visit_list(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let mut vals = vec![];
+        for element in elements(&expr.inner) {
+            vals.push(self.visit(storage, state, element)?);
+        }
+        Ok(List(vals))
+    }
+
+    // Calls a function value with some arguments, e.g. the function passed to `map`.
+    fn call(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        func: &Val,
+        args: Vec<(&str, Val)>,
+        info: Info,
+    ) -> Res {
+        let args = args
+            .into_iter()
+            .map(|(name, val)| Let {
+                name: name.to_string(),
+                value: Box::new(val.into_node()),
+                args: None,
+                info: info.clone(),
+            })
+            .collect();
+        self.visit_apply(
+            storage,
+            state,
+            &Apply {
+                inner: Box::new(func.clone().into_node()),
+                args,
+                info,
+            },
+        )
+    }
+
+    // Evaluates `map`, `filter` and `fold`, which call `f` on each element of a list.
+    fn visit_traversal(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        name: &str,
+        args: &Frame,
+        info: Info,
+    ) -> Res {
+        let vals = match args.get("it") {
+            Some(List(vals)) => vals.clone(),
+            val => {
+                return Err(TError::TypeMismatch(
+                    format!("Expected {} argument to be a list", name),
+                    Box::new(val.cloned().unwrap_or_else(unit_type)),
+                    info,
+                ))
+            }
+        };
+        let func = args.get("f").cloned().ok_or_else(|| {
+            TError::UnknownSymbol("f".to_string(), info.clone(), name.to_string())
+        })?;
+        match name {
+            "map" => {
+                let mut out = vec![];
+                for val in vals {
+                    out.push(self.call(storage, state, &func, vec![("it", val)], info.clone())?);
+                }
+                Ok(List(out))
+            }
+            "filter" => {
+                let mut out = vec![];
+                for val in vals {
+                    match self.call(
+                        storage,
+                        state,
+                        &func,
+                        vec![("it", val.clone())],
+                        info.clone(),
+                    )? {
+                        PrimVal(Bool(true)) => out.push(val),
+                        PrimVal(Bool(false)) => {}
+                        res => {
+                            return Err(TError::TypeMismatch(
+                                "Expected filter to return a bit".to_string(),
+                                Box::new(res),
+                                info,
+                            ))
+                        }
+                    }
+                }
+                Ok(List(out))
+            }
+            _ => {
+                let mut acc = args.get("init").cloned().ok_or_else(|| {
+                    TError::UnknownSymbol("init".to_string(), info.clone(), name.to_string())
+                })?;
+                for val in vals {
+                    let args = vec![("acc", acc), ("it", val)];
+                    acc = self.call(storage, state, &func, args, info.clone())?;
+                }
+                Ok(acc)
+            }
+        }
+    }
+
+    // Evaluates `type Name = ...`, defining the type and its constructors.
+    fn visit_type(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        // Fields can refer to the type itself, by name.
+        let frame = state.last_mut().expect("Stack frame missing");
+        frame.insert(decl.name.clone(), variable(&decl.name));
+        let mut alternatives = vec![];
+        for ctor in constructors(&decl.value)? {
+            let mut fields = vec![];
+            for field in ctor.fields.iter() {
+                let ty = match &field.info.ty {
+                    Some(ty) => self.visit(storage, state, ty)?,
+                    None => unit_type(),
+                };
+                fields.push((field.name.clone(), ty));
+            }
+            alternatives.push((ctor.name, fields));
+        }
+        let ty = data_type(alternatives);
+        let frame = state.last_mut().expect("Stack frame missing");
+        // Each constructor is its alternative of the type, with the fields' types to be filled in.
+        if let Union(alternatives) = &ty {
+            for alternative in alternatives.iter() {
+                if let Some((name, _)) = constructor_of(alternative) {
+                    frame.insert(name.to_string(), alternative.clone());
+                }
+            }
+        }
+        frame.insert(decl.name.clone(), ty.clone());
+        Ok(Struct(vec![(decl.name, ty)]))
+    }
+
+    // Evaluates `interface Name = op(arg: Self): Type, ...`, defining the interface's operations.
+    fn visit_interface(&mut self, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let frame = state.last_mut().expect("Stack frame missing");
+        for sig in signatures(&decl.value)? {
+            let receivers = sig
+                .args
+                .iter()
+                .filter(|arg| {
+                    matches!(arg.info.ty.as_deref(), Some(Node::SymNode(sym)) if sym.name == "Self")
+                })
+                .map(|arg| arg.name.clone())
+                .collect();
+            self.operations.insert(sig.name.clone(), receivers);
+            frame.insert(sig.name.clone(), builtin(&sig.name));
+        }
+        let ty = variable(&decl.name);
+        frame.insert(decl.name.clone(), ty.clone());
+        Ok(Struct(vec![(decl.name, ty)]))
+    }
+
+    // Evaluates `impl Interface(Type) = (op(args) = ..., ...)`, registering the methods.
+    fn visit_impl(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let imp = implementation(&decl)?;
+        let ty = self.lookup(state, imp.ty).cloned().ok_or_else(|| {
+            TError::UnknownSymbol(
+                imp.ty.to_string(),
+                decl.get_info(),
+                "interpreter::?".to_string(),
+            )
+        })?;
+        let keys: Vec<String> = match data_constructors(&ty) {
+            Some(ctors) => ctors.iter().map(|(name, _)| name.to_string()).collect(),
+            None => vec![format!("{}", ty)],
+        };
+        for (op, op_ty) in get_interface(imp.interface).unwrap_or_default() {
+            let receivers = match op_ty {
+                Function { arguments, .. } => arguments
+                    .into_struct()
+                    .into_iter()
+                    .filter(|(_, ty)| *ty == variable("Self"))
+                    .map(|(name, _)| name)
+                    .collect(),
+                _ => vec![],
+            };
+            self.operations.insert(op, receivers);
+        }
+        state.push(Frame::new());
+        let res = self.visit(storage, state, &decl.value);
+        let methods = state.pop().expect("Stack frame missing");
+        res?;
+        for (op, method) in methods {
+            let impls = self.implementations.entry(op).or_default();
+            for key in keys.iter() {
+                impls.insert(key.clone(), method.clone());
+            }
+        }
+        Ok(unit_type())
+    }
+
+    // Finds the method that implements an operation for the type of a value.
+    fn method(&self, op: &str, receiver: &Val) -> Option<Val> {
+        self.implementations
+            .get(op)?
+            .get(&type_key(receiver)?)
+            .cloned()
+    }
+
+    // Finds the argument of an operation that chooses which method runs.
+    fn receiver<'b>(&self, op: &str, args: &'b Frame) -> Option<&'b Val> {
+        self.operations
+            .get(op)?
+            .iter()
+            .find_map(|arg| args.get(arg))
+    }
+
+    // Converts a value to text with its type's implementation of `Display`, if it has one.
+    fn display(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        val: Val,
+        info: &Info,
+    ) -> Res {
+        match self.method("to_string", &val) {
+            Some(method) => self.call(storage, state, &method, vec![("it", val)], info.clone()),
+            None => Ok(val),
+        }
+    }
+
+    // Evaluates `match(value, Ctor(fields) = result, ...)`, running the clause for the value.
+    fn visit_match(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
+        let info = expr.get_info();
+        let (values, clauses): (Vec<&Let>, Vec<&Let>) =
+            expr.args.iter().partition(|arg| arg.name == "it");
+        let value = match values.as_slice() {
+            [value] => self.visit(storage, state, &value.value)?,
+            _ => {
+                return Err(TError::InternalError(
+                    "Expected a value to match on".to_string(),
+                    info,
+                ))
+            }
+        };
+        let (name, fields) = constructor_of(&value).ok_or_else(|| {
+            TError::TypeMismatch(
+                "expected a value of a data type to match on".to_string(),
+                Box::new(value.clone()),
+                info.clone(),
+            )
+        })?;
+        for clause in clauses.iter().filter(|clause| clause.name != "_") {
+            if self
+                .lookup(state, &clause.name)
+                .and_then(constructor_of)
+                .is_none()
+            {
+                return Err(TError::TypeMismatch(
+                    format!("`{}` is not a constructor", clause.name),
+                    Box::new(value.clone()),
+                    clause.get_info(),
+                ));
+            }
+        }
+        // A `_` clause matches any constructor without a clause of its own.
+        let clause = clauses
+            .iter()
+            .find(|clause| clause.name == name)
+            .or_else(|| clauses.iter().find(|clause| clause.name == "_"))
+            .ok_or_else(|| TError::NonExhaustiveMatch(format!("`{}`", name), info))?;
+        // Fields are bound by position, like the arguments of clauses, and `_` skips one.
+        let args: Vec<&Let> = clause.args.iter().flatten().collect();
+        if !args.is_empty() && args.len() != fields.len() {
+            return Err(TError::TypeMismatch(
+                format!(
+                    "`{}` has {} fields, but its case binds {}",
+                    name,
+                    fields.len(),
+                    args.len()
+                ),
+                Box::new(value.clone()),
+                clause.get_info(),
+            ));
+        }
+        let mut frame = Frame::new();
+        for (arg, (_, field)) in args.iter().zip(fields.iter()) {
+            if arg.name != "_" {
+                frame.insert(arg.name.clone(), field.clone());
+            }
+        }
+        state.push(frame);
+        let res = self.visit(storage, state, &clause.value);
+        state.pop();
+        res
+    }
+
+    // Evaluates `body ~ handlers`, running the body with the handlers' operations replaced.
+    fn visit_handle(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        // The handler's definitions are collected from the frame they are defined in.
+        state.push(Frame::new());
+        let res = self.visit(storage, state, &expr.right);
+        let clauses = state.pop().expect("Stack frame missing");
+        let res = res?;
+        if clauses.is_empty() {
+            return Err(TError::TypeMismatch(
+                "expected definitions of the operations to handle".to_string(),
+                Box::new(res),
+                expr.get_info(),
+            ));
+        }
+        for clause in clauses.values() {
+            if let Lambda(clause) = clause {
+                check_resumes(clause)?;
+            }
+        }
+        let index = self.handlers.len();
+        self.handlers.push(Handler {
+            clauses,
+            depth: state.len(),
+            scope: self.scope(),
+            resumptions: vec![],
+        });
+        let res = self.visit(storage, state, &expr.left);
+        let resumptions = std::mem::take(&mut self.handlers[index].resumptions);
+        self.handlers.truncate(index);
+        let mut res = match res {
+            Err(TError::HandlerExit(handler, val, _)) if handler == index => Ok(*val),
+            res => res,
+        };
+        // Clauses that resumed continue with the value of the expression, innermost first.
+        for resumption in resumptions.into_iter().rev() {
+            let Resumption {
+                mut state,
+                name,
+                rest,
+            } = resumption;
+            let mut val = res?;
+            if let Some(name) = name {
+                state
+                    .last_mut()
+                    .expect("Stack frame missing")
+                    .insert(name, val.clone());
+            }
+            for stmt in rest.iter() {
+                val = self.visit(storage, &mut state, stmt)?;
+            }
+            res = Ok(val);
+        }
+        res
+    }
+
+    // Runs the innermost handler for an operation, with the operation's arguments in `args`.
+    fn handle_operation(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &State,
+        name: &str,
+        args: Frame,
+        info: Info,
+    ) -> Option<Res> {
+        let index = self
+            .handlers
+            .iter()
+            .rposition(|handler| handler.clauses.contains_key(name))?;
+        let handler = &self.handlers[index];
+        let clause = match &handler.clauses[name] {
+            Lambda(clause) => clause.clone(),
+            // Handling an operation with a value resumes with that value.
+            val => return Some(Ok(val.clone())),
+        };
+        // The clause runs where the handler was defined, without the handlers inside it.
+        let mut clause_state = state[..handler.depth].to_vec();
+        clause_state.push(args);
+        self.scopes.push(handler.scope);
+        let inner_handlers = self.handlers.split_off(index);
+        let stmts = statements(&clause);
+        let split = stmts
+            .iter()
+            .position(|stmt| resume_statement(stmt).is_some())
+            .filter(|at| at + 1 < stmts.len());
+        if let Some(at) = split {
+            // The statements after `resume` wait for the rest of the handled expression.
+            let res = stmts[..at]
+                .iter()
+                .try_for_each(|stmt| self.visit(storage, &mut clause_state, stmt).map(|_| ()));
+            let (name, call) = resume_statement(stmts[at]).expect("Resume statement missing");
+            self.resumes.push(None);
+            let res = res.and_then(|()| self.visit(storage, &mut clause_state, call));
+            let resumed = self.resumes.pop().flatten();
+            self.scopes.pop();
+            self.handlers.extend(inner_handlers);
+            if let Err(err) = res {
+                return Some(Err(err));
+            }
+            self.handlers[index].resumptions.push(Resumption {
+                state: clause_state,
+                name,
+                rest: stmts[at + 1..].iter().map(|stmt| (*stmt).clone()).collect(),
+            });
+            return Some(Ok(resumed.unwrap_or_else(unit_type)));
+        }
+        self.resumes.push(None);
+        let res = self.visit(storage, &mut clause_state, &clause);
+        let resumed = self.resumes.pop().flatten();
+        self.scopes.pop();
+        self.handlers.extend(inner_handlers);
+        Some(match (res, resumed) {
+            // The operation's caller continues with the value that was passed to `resume`.
+            (Ok(_), Some(val)) => Ok(val),
+            // Otherwise the clause's value is the value of the whole `~` expression.
+            (Ok(val), None) => Err(TError::HandlerExit(index, Box::new(val), info)),
+            (Err(err), _) => Err(err),
+        })
+    }
+}
 impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
     fn visit_root(&mut self, storage: &mut DBStorage, root: &Root) -> Res {
         let mut base_frame = map! {};
@@ -232,7 +995,7 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             eprintln!("evaluating sym {}", expr.clone().into_node());
         }
         let name = &expr.name;
-        let value = find_symbol(state, name);
+        let value = self.lookup(state, name);
         if let Some(prim) = value {
             if storage.debug_level() > 0 {
                 eprintln!("{} = (from stack) {}", name, prim.clone().into_node());
@@ -348,13 +1111,43 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
         if storage.debug_level() > 1 {
             eprintln!("evaluating apply {}", expr.clone().into_node());
         }
+        if let Node::SymNode(sym) = &*expr.inner {
+            if self.lookup(state, &sym.name) == Some(&builtin("match")) {
+                return self.visit_match(storage, state, expr);
+            }
+        }
+        if let Some(limit) = self.depth_limit {
+            if state.len() >= limit {
+                return Err(TError::RecursionLimit(limit, expr.get_info()));
+            }
+        }
         state.push(Frame::new());
         expr.args
             .iter()
             .map(|arg| self.visit_let(storage, state, arg))
             .collect::<Result<Vec<Val>, TError>>()?;
+        // Operations can be replaced by the handlers that are running.
+        if let Node::SymNode(sym) = &*expr.inner {
+            let args = state.last().cloned().unwrap_or_default();
+            if let Some(res) =
+                self.handle_operation(storage, state, &sym.name, args, expr.get_info())
+            {
+                state.pop();
+                return res;
+            }
+        }
         // Retrive the inner
         let inner = self.visit(storage, state, &*expr.inner)?;
+        // Interface operations run the method implemented for their arguments' type.
+        let method = match &inner {
+            PrimVal(BuiltIn(name)) => {
+                let args = state.last().expect("Stack frame missing");
+                self.receiver(name, args)
+                    .and_then(|receiver| self.method(name, receiver))
+            }
+            _ => None,
+        };
+        let inner = method.unwrap_or(inner);
         // Run the inner
         if storage.debug_level() > 2 {
             eprintln!(
@@ -364,10 +1157,46 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             );
         }
         let res = match inner {
-            Val::Lambda(func) => self.visit(storage, state, &*func)?,
+            Val::Lambda(func) => {
+                // The function can't see its caller's variables, only its own and the globals.
+                // Local functions can still see themselves, so that they can recurse.
+                if let Node::SymNode(sym) = &*expr.inner {
+                    if !state[0].contains_key(&sym.name) {
+                        let frame = state.last_mut().expect("Stack frame missing");
+                        frame
+                            .entry(sym.name.clone())
+                            .or_insert_with(|| Lambda(func.clone()));
+                    }
+                }
+                self.scopes.push(state.len() - 1);
+                let res = self.visit(storage, state, &func);
+                self.scopes.pop();
+                res?
+            }
             Val::PrimVal(prim) => {
                 use crate::primitives::Prim;
                 match prim {
+                    Prim::BuiltIn(name) if matches!(name.as_str(), "map" | "filter" | "fold") => {
+                        let args = state.last().cloned().unwrap_or_default();
+                        self.visit_traversal(storage, state, &name, &args, expr.get_info())?
+                    }
+                    Prim::BuiltIn(name) if name == "resume" => {
+                        let val = state
+                            .last()
+                            .and_then(|frame| frame.get("it"))
+                            .cloned()
+                            .unwrap_or_else(unit_type);
+                        match self.resumes.last_mut() {
+                            Some(resumed) => *resumed = Some(val.clone()),
+                            None => {
+                                return Err(TError::InternalError(
+                                    "`resume` called outside of a handler".to_string(),
+                                    expr.get_info(),
+                                ))
+                            }
+                        }
+                        val
+                    }
                     Prim::BuiltIn(name) => {
                         if storage.debug_level() > 2 {
                             eprintln!("looking up interpreter impl {}", name);
@@ -383,8 +1212,11 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                             }
                             frame_vals
                         };
+                        // Builtins return early, so they drop the arguments' frame themselves.
                         if let Some(extern_impl) = &mut self.impls.get_mut(&name) {
-                            return extern_impl(storage, frame(), expr.get_info());
+                            let res = extern_impl(storage, frame(), expr.get_info());
+                            state.pop();
+                            return res;
                         }
                         if storage.debug_level() > 2 {
                             eprintln!("looking up default impl {}", &name);
@@ -392,10 +1224,29 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                         if let Some(default_impl) =
                             crate::externs::get_implementation(name.to_owned())
                         {
-                            return default_impl(storage, frame(), expr.get_info());
+                            let res = default_impl(storage, frame(), expr.get_info());
+                            state.pop();
+                            return res;
+                        }
+                        if self.operations.contains_key(&name) {
+                            let args = state.last().expect("Stack frame missing");
+                            let receiver = self.receiver(&name, args).cloned();
+                            return Err(TError::MissingImplementation(
+                                name,
+                                Box::new(receiver.unwrap_or_else(unit_type)),
+                                expr.get_info(),
+                            ));
                         }
                         panic!("Built a 'Built in' with unknown built in named {}", name);
                     }
+                    // Values can't be given arguments, e.g. `x y` when `x` is a number.
+                    prim if !expr.args.is_empty() => {
+                        return Err(TError::TypeMismatch(
+                            "Expected a function to call".to_string(),
+                            Box::new(Val::PrimVal(prim)),
+                            expr.get_info(),
+                        ))
+                    }
                     prim => Val::PrimVal(prim),
                 }
             }
@@ -414,6 +1265,10 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                 }
                 self.visit_val(storage, state, &*results)?
             }
+            val if constructor_of(&val).is_some() => {
+                let args = state.last().expect("Stack frame missing");
+                construct(val, args, expr.get_info())?
+            }
             val => val,
         };
         state.pop();
@@ -433,15 +1288,12 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             eprintln!("introducing abstraction {}", expr.clone().into_node());
         }
 
-        // Add a new scope
-        let mut frame = Frame::new();
-        frame.insert(expr.name.clone(), Variable(expr.name.clone()));
-        state.push(frame);
-        let result = self.visit(storage, state, &expr.value)?;
-        // Drop the finished scope
-        state.pop();
-        // TODO: Rewrap in the abstraction
-        Ok(result)
+        Ok(closure(
+            &state[self.scope()..],
+            &expr.value,
+            std::slice::from_ref(&expr.name),
+            &expr.get_info(),
+        ))
     }
 
     fn visit_let(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Let) -> Res {
@@ -449,8 +1301,14 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             eprintln!("evaluating let {}", expr.clone().into_node());
         }
 
-        if expr.args.is_some() {
-            let val = Val::Lambda(expr.value.clone());
+        if let Some(args) = &expr.args {
+            let params: Vec<String> = args.iter().map(|arg| arg.name.clone()).collect();
+            let val = closure(
+                &state[self.scope()..],
+                &expr.value,
+                &params,
+                &expr.get_info(),
+            );
             state
                 .last_mut()
                 .expect("Stack frame missing")
@@ -471,6 +1329,31 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
         if storage.debug_level() > 1 {
             eprintln!("evaluating unop {}", expr.clone().into_node());
         }
+        if expr.name == "type" {
+            return self.visit_type(storage, state, expr);
+        }
+        if expr.name == "interface" {
+            return self.visit_interface(state, expr);
+        }
+        if expr.name == "impl" {
+            return self.visit_impl(storage, state, expr);
+        }
+        if expr.name == "[]" {
+            return self.visit_list(storage, state, expr);
+        }
+        if expr.name == "()" {
+            return self.visit_tuple(storage, state, expr);
+        }
+        if let (true, Some((cond, body))) = (expr.name == "{}", loop_parts(&expr.inner)) {
+            return self.visit_loop(storage, state, cond, body, expr.get_info());
+        }
+        if expr.name == "{}" {
+            // Definitions in a block are dropped with its scope.
+            state.push(Frame::new());
+            let result = self.visit(storage, state, &expr.inner);
+            state.pop();
+            return result;
+        }
         let i = self.visit(storage, state, &expr.inner)?;
         let info = expr.clone().get_info();
         match expr.name.as_str() {
@@ -480,29 +1363,82 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                 _ => Err(TError::TypeMismatch("!".to_string(), Box::new(i), info)),
             },
             "+" => match i {
-                PrimVal(I32(n)) => Ok(int32(n)),
+                PrimVal(I32(_) | I64(_) | U8(_) | U32(_) | U64(_) | F64(_) | BigInt(_)) => Ok(i),
                 Lambda(_) => Ok(Lambda(Box::new(expr.clone().into_node()))),
                 _ => Err(TError::TypeMismatch("+".to_string(), Box::new(i), info)),
             },
-            "-" => match i {
-                PrimVal(I32(n)) => Ok(int32(-n)),
-                Lambda(_) => Ok(Lambda(Box::new(expr.clone().into_node()))),
-                _ => Err(TError::TypeMismatch("-".to_string(), Box::new(i), info)),
+            "-" => match prim_neg(&i) {
+                Some(n) => Ok(n),
+                None if matches!(i, Lambda(_)) => Ok(Lambda(Box::new(expr.clone().into_node()))),
+                None => Err(TError::TypeMismatch("-".to_string(), Box::new(i), info)),
             },
             op => Err(TError::UnknownPrefixOperator(op.to_string(), info)),
         }
     }
 
     fn visit_bin_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        if let Some(call) = method_call(expr) {
+            return self.visit_apply(storage, state, &call);
+        }
         if storage.debug_level() > 1 {
             eprintln!("evaluating binop {}", expr.clone().into_node());
         }
+        if expr.name == "~" {
+            return self.visit_handle(storage, state, expr);
+        }
+        if is_assignment(&expr.name) {
+            return self.visit_assign(storage, state, expr);
+        }
+        if let (true, Node::BinOpNode(req)) = (expr.name == "?", &*expr.left) {
+            if req.name == "-|" {
+                // A requirement with an alternative is control flow, not a contract, so the
+                // alternative only runs when the condition fails, not when the first branch does.
+                return match self.visit(storage, state, &req.left) {
+                    // A condition on an argument that wasn't passed fails too, which gives the
+                    // argument a default (see examples/defaulting.tk).
+                    Ok(PrimVal(Bool(false))) | Err(TError::UnknownSymbol(..)) => {
+                        self.visit(storage, state, &expr.right)
+                    }
+                    Err(err) => Err(err),
+                    Ok(_) => self.visit(storage, state, &req.right),
+                };
+            }
+        }
         let info = expr.clone().get_info();
         let l = self.visit(storage, state, &expr.left);
+        if expr.name == "[]" && matches!(l, Ok(Lambda(_)) | Ok(PrimVal(BuiltIn(_)))) {
+            return self.visit(storage, state, &index_call(expr));
+        }
+        // Operators on other types use the type's implementation of `Number`.
+        if let (Some(op), Ok(left)) = (interface_operation(&expr.name), &l) {
+            match self.method(op, left) {
+                Some(method) => {
+                    let right = self.visit(storage, state, &expr.right)?;
+                    let args = vec![("left", left.clone()), ("right", right)];
+                    return self.call(storage, state, &method, args, info);
+                }
+                None if constructor_of(left).is_some() => {
+                    return Err(TError::MissingImplementation(
+                        op.to_string(),
+                        Box::new(left.clone()),
+                        info,
+                    ))
+                }
+                None => {}
+            }
+        }
+        if expr.name == "++" {
+            let (l, r) = (l?, self.visit(storage, state, &expr.right)?);
+            if let (List(_), List(_)) = (&l, &r) {
+                return prim_add_strs(&l, &r, info);
+            }
+            let l = self.display(storage, state, l, &info)?;
+            let r = self.display(storage, state, r, &info)?;
+            return prim_add_strs(&l, &r, info);
+        }
         let mut r = || self.visit(storage, state, &expr.right);
         match expr.name.as_str() {
             "+" => prim_add(&l?, &r()?, info),
-            "++" => prim_add_strs(&l?, &r()?, info),
             "==" => prim_eq(&l?, &r()?, info),
             "!=" => prim_neq(&l?, &r()?, info),
             ">" => prim_gt(&l?, &r()?, info),
@@ -514,6 +1450,7 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             "/" => prim_div(&l?, &r()?, info),
             "%" => prim_mod(&l?, &r()?, info),
             "^" => prim_pow(&l?, &r()?, info),
+            "[]" => prim_index(&l?, &r()?, info),
             "&&" => prim_and(&l?, &r()?, info),
             "||" => prim_or(&l?, &r()?, info),
             "->" => prim_type_arrow(l?, r()?, info),
@@ -526,6 +1463,16 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
             }
             "." => {
                 let l = l?;
+                if let Some(field) = field_name(expr) {
+                    let vals = match &l {
+                        Struct(vals) => vals.as_slice(),
+                        _ => &[],
+                    };
+                    return match vals.iter().find(|(name, _)| *name == field) {
+                        Some((_, val)) => Ok(val.clone()),
+                        None => Err(TError::UnknownField(field, Box::new(l), info)),
+                    };
+                }
                 let r = r()?;
                 self.visit_apply(
                     storage,
@@ -547,7 +1494,23 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                 let right = r()?;
                 Ok(right)
             }
+            "?:" => match l? {
+                l if l == unit_type() => r(),
+                l => Ok(l),
+            },
+            "?." => match l? {
+                l if l == unit_type() => Ok(l),
+                l => {
+                    let access = optional_access(expr, l.into_node());
+                    match method_call(&access) {
+                        Some(call) => self.visit_apply(storage, state, &call),
+                        None => self.visit_bin_op(storage, state, &access),
+                    }
+                }
+            },
             "?" => match l {
+                // Exiting a handler is control flow, not a failure.
+                Err(TError::HandlerExit(..)) => l,
                 Err(_) => r(),
                 l => l,
             },
@@ -557,6 +1520,28 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
                 Ok(_) => r(),
                 l => l,
             },
+            "|=" => {
+                let l = l?;
+                // The postcondition is a predicate over the result, bound to `it`.
+                let (name, cond) = match &*expr.right {
+                    Node::AbsNode(abs) => (abs.name.clone(), &abs.value),
+                    right => {
+                        return Err(TError::InternalError(
+                            format!("Expected a postcondition over `it`, found {}", right),
+                            info,
+                        ))
+                    }
+                };
+                let mut frame = Frame::new();
+                frame.insert(name, l.clone());
+                state.push(frame);
+                let holds = self.visit(storage, state, cond);
+                state.pop();
+                match holds? {
+                    PrimVal(Bool(false)) => Err(TError::PostconditionFailure(info)),
+                    _ => Ok(l),
+                }
+            }
             op => Err(TError::UnknownInfixOperator(op.to_string(), info)),
         }
     }
@@ -565,7 +1550,7 @@ impl<'a> Visitor<State, Val, Val> for Interpreter<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::primitives::{boolean, int32, number_type, string, string_type};
+    use crate::primitives::{boolean, int32, number_type, string, string_type, unit_type};
     use Node::*;
 
     fn get_db() -> DBStorage {
@@ -669,6 +1654,17 @@ mod tests {
         assert_eq!(eval_str(db, "x=3;x"), Ok(int32(3)));
     }
 
+    #[test]
+    fn parse_and_eval_postcondition() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "x(n)=n*2|=it>n;x(n=3)"), Ok(int32(6)));
+        assert!(matches!(
+            eval_str(db, "x(n)=n*2|=it>n;x(n=0)"),
+            Err(TError::PostconditionFailure(_))
+        ));
+        assert_eq!(eval_str(db, "x(n)=n*2|=it>n;x(n=0)?0"), Ok(int32(0)));
+    }
+
     #[test]
     fn parse_and_eval_let_with_args() {
         let db = &mut get_db();
@@ -775,6 +1771,693 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_and_eval_assignment_updates_variable() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "x := 1; x <- x + 4; x *= 3; x -= 1; x"),
+            Ok(int32(14))
+        );
+        assert_eq!(eval_str(db, "x := 17; x /= 2; x %= 5; x"), Ok(int32(3)));
+    }
+
+    #[test]
+    fn parse_and_eval_assignment_in_function_updates_outer_variable() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "total := 0; add(n) = (total += n); add(n=1); add(n=2); total"
+            ),
+            Ok(int32(3))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_assignment_to_immutable_variable() {
+        let db = &mut get_db();
+        match eval_str(db, "x = 1; x += 1") {
+            Err(TError::ImmutableAssignment(name, _)) => assert_eq!(name, "x"),
+            res => panic!("expected an immutable assignment error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_match_on_constructor() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "type Shape = Circle(r: I32) | Rect(w: I32, h: I32) | Empty;
+                area(s) = match(it=s, Circle(r) = 3*r*r, Rect(w, h) = w*h, Empty = 0);
+                area(s=Rect(w=2, h=5)) + area(s=Empty)"
+            ),
+            Ok(int32(10))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_match_without_case() {
+        let db = &mut get_db();
+        match eval_str(
+            db,
+            "type Shape = Circle(r: I32) | Empty; match(it=Empty, Circle(r) = r)",
+        ) {
+            Err(TError::NonExhaustiveMatch(cases, _)) => assert_eq!(cases, "`Empty`"),
+            res => panic!("expected a non exhaustive match error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_match_with_wildcard() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "type Shape = Circle(r: I32) | Empty; match(it=Empty, Circle(r) = r, _ = 7)"
+            ),
+            Ok(int32(7))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_match_with_unknown_constructor() {
+        let db = &mut get_db();
+        match eval_str(
+            db,
+            "type Shape = Circle(r: I32) | Empty; match(it=Empty, Circle(r) = r, Square = 1, Empty = 0)",
+        ) {
+            Err(TError::TypeMismatch(msg, _, _)) => {
+                assert_eq!(msg, "`Square` is not a constructor")
+            }
+            res => panic!("expected an unknown constructor error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_interface_operations() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "interface Sized = size(it: Self): I32;
+                type Shape = Square(w: I32) | Empty;
+                impl Sized(Shape) = size(it) = match(it=it, Square(w) = w*w, Empty = 0);
+                impl Sized(String) = size(it) = len(it=it);
+                size(it=Square(w=3)) + size(it=Empty) + size(it=\"abc\")"
+            ),
+            Ok(int32(12))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_operators_and_display_of_data_types() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "type Money = Cents(c: I32);
+                cents(m) = match(it=m, Cents(c) = c);
+                impl Number(Money) = (
+                    add(left, right) = Cents(c=cents(m=left) + cents(m=right)),
+                    sub(left, right) = Cents(c=cents(m=left) - cents(m=right)),
+                    mul(left, right) = Cents(c=cents(m=left) * cents(m=right)),
+                    div(left, right) = Cents(c=cents(m=left) / cents(m=right))
+                );
+                impl Display(Money) = to_string(it) = \"{cents(m=it)}c\";
+                \"{Cents(c=1) + Cents(c=2) * Cents(c=3)}\""
+            ),
+            Ok(string("7c"))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_operation_without_implementation() {
+        let db = &mut get_db();
+        match eval_str(db, "interface Sized = size(it: Self): I32; size(it=1)") {
+            Err(TError::MissingImplementation(op, val, _)) => {
+                assert_eq!((op.as_str(), *val), ("size", int32(1)))
+            }
+            res => panic!("expected a missing implementation, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_imports() {
+        let db = &mut get_db();
+        db.set_file("maths.tk", "square(it) = it * it".to_string());
+        assert_eq!(eval_str(db, "import('maths'); square(it=3)"), Ok(int32(9)));
+    }
+
+    #[test]
+    fn parse_and_eval_imports_chosen_at_compile_time() {
+        let db = &mut get_db();
+        db.set_file("fallback.tk", "answer = 2".to_string());
+        db.set_file("maths.tk", "answer = 1".to_string());
+        assert_eq!(
+            eval_str(
+                db,
+                "pick(it) = lib::exists(it=it) -| it ? 'fallback';
+                import(it=pick(it='fast_maths'));
+                answer"
+            ),
+            Ok(int32(2))
+        );
+        assert_eq!(
+            eval_str(
+                db,
+                "import(it=lib::exists(it='maths') -| 'maths' ? 'fallback'); answer"
+            ),
+            Ok(int32(1))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_import_of_program_arguments() {
+        let db = &mut get_db();
+        match eval_str(db, "import(it=argv(it=1)); 1") {
+            Err(TError::UnexpectedEffects(msg, _)) => {
+                assert_eq!(msg, "import targets can't use Args")
+            }
+            res => panic!("expected an effects error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_import_of_missing_module() {
+        let db = &mut get_db();
+        match eval_str(db, "import('missing'); 1") {
+            Err(TError::UnknownModule(name, _)) => assert_eq!(name, "missing"),
+            res => panic!("expected an unknown module, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_namespaced_call() {
+        let db = &mut get_db();
+        db.set_file("maths.tk", "square(it) = it * it".to_string());
+        assert_eq!(
+            eval_str(db, "import('maths'); maths.square(it=3)"),
+            Ok(int32(9))
+        );
+        match eval_str(db, "import('maths'); maths.tesseract(it=3)") {
+            Err(TError::UnknownMember(name, _)) => assert_eq!(name, "maths.tesseract"),
+            res => panic!("expected an unknown member, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_field_access() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "p = (x=1, y=(z=2)); p.y.z + p.x"),
+            Ok(int32(3))
+        );
+        match eval_str(db, "p = (x=1); p.w") {
+            Err(TError::UnknownField(name, _, _)) => assert_eq!(name, "w"),
+            res => panic!("expected an unknown field, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_method_call() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "scale(it, by) = it * by; 3.scale(by=2).scale(by=5)"),
+            Ok(int32(30))
+        );
+        match eval_str(db, "3.scale(by=2)") {
+            Err(TError::UnknownMethod(name, _)) => assert_eq!(name, "scale"),
+            res => panic!("expected an unknown method, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_clauses() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "fac(0) = 1; fac(n) = n * fac(n=n-1); fac(n=5)"),
+            Ok(int32(120))
+        );
+        let pick = "pick(0, b) = b; pick(a, 0) = a; pick(x, y) = x * y;";
+        assert_eq!(
+            eval_str(db, &format!("{pick} pick(a=0, b=4)")),
+            Ok(int32(4))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{pick} pick(a=3, b=0)")),
+            Ok(int32(3))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{pick} pick(a=2, b=5)")),
+            Ok(int32(10))
+        );
+        assert_eq!(
+            eval_str(db, "not(true) = false; not(false) = true; not(it=false)"),
+            Ok(boolean(true))
+        );
+    }
+
+    #[test]
+    fn clauses_are_checked_for_overlap_and_coverage() {
+        let db = &mut get_db();
+        match eval_str(db, "f(n) = n; f(0) = 1; f(n=2)") {
+            Err(TError::UnreachableClause(name, _)) => assert_eq!(name, "f"),
+            res => panic!("expected an unreachable clause, got {:?}", res),
+        }
+        match eval_str(db, "f(0) = 1; f(0) = 2; f(x) = x; f(x=2)") {
+            Err(TError::UnreachableClause(name, _)) => assert_eq!(name, "f"),
+            res => panic!("expected an unreachable clause, got {:?}", res),
+        }
+        match eval_str(db, "f(0) = 1; f(1) = 2; f(it=2)") {
+            Err(TError::IncompleteClauses(name, _)) => assert_eq!(name, "f"),
+            res => panic!("expected incomplete clauses, got {:?}", res),
+        }
+        match eval_str(db, "f(0) = 1; f(x, y) = 2; f(it=2)") {
+            Err(TError::ClauseArity(name, _)) => assert_eq!(name, "f"),
+            res => panic!("expected mismatched clauses, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_paren_free_call() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "double(it) = it * 2; double 3 + 1"),
+            Ok(int32(7))
+        );
+        assert_eq!(
+            eval_str(db, "double(it) = it * 2; inc(it) = it + 1; double inc 3"),
+            Ok(int32(8))
+        );
+        // Only functions can be called.
+        for code in ["double(it) = it * 2; double(3) 4", "x = 3; y = 4; x y"] {
+            match eval_str(db, code) {
+                Err(TError::TypeMismatch(..)) => {}
+                res => panic!("expected a type error for {code}, got {:?}", res),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_tuples() {
+        let db = &mut get_db();
+        let swap = "swap(p) = (p.1, p.0);";
+        assert_eq!(
+            eval_str(db, &format!("{swap} swap(p=(1, true))")),
+            Ok(Struct(vec![
+                ("0".to_string(), boolean(true)),
+                ("1".to_string(), int32(1))
+            ]))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{swap} let (a, b) = swap(p=(1, 2)); a - b")),
+            Ok(int32(1))
+        );
+        assert_eq!(
+            eval_str(db, "let ((a, b), y=c) = ((1, 2), y=3); a + b * c"),
+            Ok(int32(7))
+        );
+        match eval_str(db, "let (a, b) = (1, y=2); a") {
+            Err(TError::UnknownField(field, _, _)) => assert_eq!(field, "1"),
+            res => panic!("expected an unknown field, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_optionals() {
+        let db = &mut get_db();
+        let half = "half(n) = if n % 2 == 0 { n / 2 } else { () }; double(it) = it * 2;";
+        assert_eq!(
+            eval_str(db, &format!("{half} half(n=4) ?: 0")),
+            Ok(int32(2))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{half} half(n=3) ?: 0")),
+            Ok(int32(0))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{half} half(n=8)?.double()")),
+            Ok(int32(8))
+        );
+        assert_eq!(
+            eval_str(db, &format!("{half} half(n=7)?.double()")),
+            Ok(unit_type())
+        );
+        assert_eq!(
+            eval_str(db, &format!("{half} half(n=7) == ()")),
+            Ok(boolean(true))
+        );
+        assert_eq!(eval_str(db, "p = (x=3); p?.x"), Ok(int32(3)));
+    }
+
+    #[test]
+    fn parse_and_eval_abstraction() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "double = x |- x * 2; double(x=21)"),
+            Ok(int32(42))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_closures_outlive_their_frame() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "adder(n) = x |- x + n; add3 = adder(n=3); add3(x=4)"),
+            Ok(int32(7))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_closures_capture_lexically() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "make(n) = (get() = n; get); n = 5; make(n=1)()"),
+            Ok(int32(1))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_closures_capture_assigned_variables() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "mk() = {c := 0; c += 1; get() = c; get}; g = mk(); g()"),
+            Ok(int32(1))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_block() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "x = {y = 3; y * 2}; x * 5"), Ok(int32(30)));
+    }
+
+    #[test]
+    fn parse_and_eval_if_else() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "f(n) = if n < 0 { 0 } else if n < 10 { 1 } else { 2 }; f(n=5)"
+            ),
+            Ok(int32(1))
+        );
+        assert_eq!(eval_str(db, "x := 1; if x > 0 { x = 3 }; x"), Ok(int32(1)));
+    }
+
+    #[test]
+    fn parse_and_eval_if_else_propagates_errors_from_the_taken_branch() {
+        let db = &mut get_db();
+        match eval_str(db, "if true { 1/0 } else { 5 }") {
+            Err(TError::DivisionByZero(_)) => {}
+            res => panic!("expected a division by zero error, got {:?}", res),
+        }
+        assert_eq!(eval_str(db, "if false { 1/0 } else { 5 }"), Ok(int32(5)));
+    }
+
+    #[test]
+    fn parse_and_eval_requirement_propagates_errors_from_its_condition() {
+        let db = &mut get_db();
+        match eval_str(db, "(1/0 == 0) -| 1 ? 2") {
+            Err(TError::DivisionByZero(_)) => {}
+            res => panic!("expected a division by zero error, got {:?}", res),
+        }
+        assert_eq!(eval_str(db, "(1 == 0) -| 1 ? 2"), Ok(int32(2)));
+    }
+
+    #[test]
+    fn parse_and_eval_while() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "f() = {i := 0; while i < 4 { i += 1 }; i}; f()"),
+            Ok(int32(4))
+        );
+        assert_eq!(
+            eval_str(db, "f(n) = {i := 0; while i < n { i += 1 }; i}; f(n=10000)"),
+            Ok(int32(10000))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_nested_while() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "f() = {n := 0; i := 0; while i < 2 { j := 0; while j < 2 { n += 1; j += 1 }; i += 1 }; n}; f()"
+            ),
+            Ok(int32(4))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_for() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "f() = {t := 0; for x in [1, 2, 3] { t += x }; t}; f()"),
+            Ok(int32(6))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_block_definitions_shadow_and_are_dropped() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "x = 1; {x = 2; x} * 10 + x"), Ok(int32(21)));
+        assert_eq!(
+            eval_str(db, "f(n) = {m = n + 1; m * m}; f(n=2)"),
+            Ok(int32(9))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_functions_find_variables_lexically() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "x = 1; f() = x; {x = 2; f()}"), Ok(int32(1)));
+        assert_eq!(
+            eval_str(db, "f() = y; g(y) = f(); y = 3; g(y=4)"),
+            Ok(int32(3))
+        );
+        assert_eq!(
+            eval_str(db, "f() = {g(n) = n == 0 -| 5 ? g(n=n-1); g(n=3)}; f()"),
+            Ok(int32(5))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_block_definitions_are_not_visible_outside() {
+        let db = &mut get_db();
+        match eval_str(db, "y = {z = 1; z}; z") {
+            Err(TError::UnknownSymbol(name, _, _)) => assert_eq!(name, "z"),
+            res => panic!("expected an unknown symbol error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_wider_integers() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "3_000_000_000i64 * 2i64"),
+            Ok(PrimVal(I64(6_000_000_000)))
+        );
+        assert_eq!(eval_str(db, "250u8 + 10u8"), Ok(PrimVal(U8(4))));
+        assert_eq!(eval_str(db, "2u64 ^ 63"), Ok(PrimVal(U64(1 << 63))));
+    }
+
+    #[test]
+    fn parse_and_eval_floats() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "1.5 * 2.0 - 0.5"), Ok(float64(2.5)));
+        assert_eq!(eval_str(db, "0.1 < 0.2"), Ok(boolean(true)));
+    }
+
+    #[test]
+    fn parse_and_eval_bigint() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "2bigint ^ 100"),
+            Ok(PrimVal(BigInt(
+                "1267650600228229401496703205376".parse().unwrap()
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_mixed_number_types() {
+        let db = &mut get_db();
+        assert!(eval_str(db, "1i64 + 1").is_err());
+    }
+
+    #[test]
+    fn parse_and_eval_division_by_zero() {
+        let db = &mut get_db();
+        match eval_str(db, "1 / 0") {
+            Err(TError::DivisionByZero(_)) => {}
+            res => panic!("expected a division by zero error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_list_traversals() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "xs = map(it=[1, 2, 3], f(it)=it*2);
+                fold(it=filter(it=xs, f(it)=it>2), init=0, f(acc, it)=acc+it) + xs[0]"
+            ),
+            Ok(int32(12))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_strings_are_indexed_by_code_point() {
+        let db = &mut get_db();
+        assert_eq!(eval_str(db, "len(it=\"héllo\")"), Ok(int32(5)));
+        assert_eq!(eval_str(db, "\"héllo\"[1]"), Ok(PrimVal(Char('é'))));
+        assert_eq!(
+            eval_str(db, "slice(it=\"héllo\", start=1, end=3)"),
+            Ok(string("él"))
+        );
+        assert_eq!(
+            eval_str(db, "find(it=\"héllo\", value=\"l\")"),
+            Ok(int32(2))
+        );
+        assert_eq!(
+            eval_str(db, "find(it=\"héllo\", value=\"x\")"),
+            Ok(int32(-1))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_string_operations() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "join(it=split(it=replace(it=\"a-b-c\", from=\"-\", to=\",\"), sep=\",\"), sep=\" \")"
+            ),
+            Ok(string("a b c"))
+        );
+        assert_eq!(eval_str(db, "upper(it=\"abc\")"), Ok(string("ABC")));
+        assert_eq!(eval_str(db, "upper(it=\"straße\")"), Ok(string("STRAßE")));
+        assert_eq!(eval_str(db, "lower(it=\"ΟΔΟΣ\")"), Ok(string("οδοσ")));
+        assert_eq!(eval_str(db, "to_string(it=[1, 2])"), Ok(string("[1, 2]")));
+        assert_eq!(eval_str(db, "to_string(it=`c`) ++ \"!\""), Ok(string("c!")));
+    }
+
+    #[test]
+    fn parse_and_eval_index_out_of_bounds() {
+        let db = &mut get_db();
+        match eval_str(db, "[1, 2][2]") {
+            Err(TError::IndexOutOfBounds(index, _, len, _)) => assert_eq!((index, len), (2, 2)),
+            res => panic!("expected an index out of bounds error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_joined_lists() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "xs = [3, 1, 2]; xs ++ [4]"),
+            Ok(List(vec![int32(3), int32(1), int32(2), int32(4)]))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_set_ignores_duplicates() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "len(it=insert(it=to_set(it=[1, 2, 1]), value=2))"),
+            Ok(int32(2))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_map_missing_key() {
+        let db = &mut get_db();
+        match eval_str(db, "get(it=to_map(keys=[1], values=[2]), key=3)") {
+            Err(TError::MissingKey(key, _)) => assert_eq!(key, "3"),
+            res => panic!("expected a missing key error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_map_with_more_keys_than_values() {
+        let db = &mut get_db();
+        match eval_str(db, "to_map(keys=[1, 2], values=[3])") {
+            Err(TError::MismatchedKeys(keys, values, _)) => assert_eq!((keys, values), (2, 1)),
+            res => panic!("expected a mismatched keys error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_and_eval_handler_resumes_with_value() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "f(x)=ask(it=x)+1;ask(it)=0;f(x=2) ~ (ask(it)=resume(it=it*10))"
+            ),
+            Ok(int32(21))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_handler_without_resume_replaces_value() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "fail(it)=it;(fail(it=3)+100) ~ (fail(it)=it*2)"),
+            Ok(int32(6))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_handler_uses_innermost_clause() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "ask()=0;(ask() ~ (ask()=resume(it=1))) + ask() ~ (ask()=resume(it=2))"
+            ),
+            Ok(int32(3))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_handler_exit_is_not_caught_by_alternative() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(db, "fail()=0;(fail() ? 5) ~ (fail()=7)"),
+            Ok(int32(7))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_handler_continues_after_resume() {
+        let db = &mut get_db();
+        assert_eq!(
+            eval_str(
+                db,
+                "ask()=0;(ask() + ask() * 10) ~ (ask()=(r=resume(it=1); r * 2))"
+            ),
+            Ok(int32(44))
+        );
+    }
+
+    #[test]
+    fn parse_and_eval_handler_resumes_once() {
+        let db = &mut get_db();
+        match eval_str(db, "ask()=0;ask() ~ (ask()=(resume(it=1); resume(it=2)))") {
+            Err(TError::MisplacedResume(_)) => {}
+            res => panic!("expected a misplaced resume error, got {:?}", res),
+        }
+        match eval_str(db, "ask()=0;ask() ~ (ask()=resume(it=1) + 1)") {
+            Err(TError::MisplacedResume(_)) => {}
+            res => panic!("expected a misplaced resume error, got {:?}", res),
+        }
+    }
+
     #[test]
     fn tako_add_eq_rust_eq() {
         use rand::Rng;
@@ -1,8 +1,10 @@
 pub mod interpreter;
 pub mod parser;
 pub mod type_checker;
+pub mod type_graph_builder;
 
 pub mod definition_finder;
 pub mod pretty_print;
 pub mod symbol_table_builder;
+pub mod test_generator;
 pub mod to_cpp;
@@ -7,8 +7,9 @@ use crate::database::{AstNode, AstNodeData, DBStorage};
 use crate::errors::TError;
 use crate::externs::{Direction, Semantic};
 use crate::location::*;
-use crate::primitives::{int32, string, Prim, Val};
+use crate::primitives::{float64, string, unit_type, Prim, Val};
 use crate::tokens::*;
+use std::convert::TryFrom;
 
 fn binding(storage: &mut DBStorage, tok: &Token) -> Result<Semantic, TError> {
     storage.get_extern_operator(tok.value.to_owned())
@@ -28,6 +29,33 @@ fn binding_power(storage: &mut DBStorage, tok: &Token) -> Result<i32, TError> {
     })
 }
 
+fn binding_mixes(storage: &mut DBStorage, tok: &Token, other: &Token) -> Result<bool, TError> {
+    Ok(match binding(storage, tok)? {
+        Semantic::Operator {
+            mixes: Some(mixes), ..
+        } => mixes.contains(&other.value),
+        _ => true,
+    })
+}
+
+// Operators of the same binding power are otherwise resolved silently by associativity, e.g.
+// `a && b || c`, so operators can ask for parentheses instead.
+fn check_mix(storage: &mut DBStorage, prev: &Token, next: &Token) -> Result<(), TError> {
+    if prev.value == next.value
+        || binding_power(storage, prev)? != binding_power(storage, next)?
+        || (binding_mixes(storage, prev, next)? && binding_mixes(storage, next, prev)?)
+    {
+        return Ok(());
+    }
+    Err(TError::ParseError(
+        format!(
+            "ambiguous use of `{}` and `{}`, add parentheses, e.g. `(a {} b) {} c` or `a {} (b {} c)`",
+            prev.value, next.value, prev.value, next.value, prev.value, next.value
+        ),
+        next.get_info(),
+    ))
+}
+
 impl Token {
     pub fn get_info(&self) -> Info {
         self.pos.clone().get_info()
@@ -43,6 +71,63 @@ impl Loc {
     }
 }
 
+// The suffixes that set the type of a number, e.g. `255u8`. Numbers without one are `I32`s.
+const NUMBER_SUFFIXES: [(&str, &str); 7] = [
+    ("i32", "I32"),
+    ("i64", "I64"),
+    ("u8", "U8"),
+    ("u32", "U32"),
+    ("u64", "U64"),
+    ("f64", "F64"),
+    ("bigint", "BigInt"),
+];
+
+// Parses a number, e.g. `1_000`, `0xff`, `0b101`, `1.5` or `10i64`.
+fn number(head: &Token) -> Result<Val, TError> {
+    let error = |msg: String| TError::ParseError(msg, head.get_info());
+    let lit: String = head.value.chars().filter(|c| *c != '_').collect();
+    // The suffix is found first, as `0b` could also start a suffix, e.g. `0bigint`.
+    // Hex digits could be mistaken for an `f64` suffix, and floats are always decimal anyway.
+    let hex = lit.starts_with("0x");
+    let (lit, ty) = match NUMBER_SUFFIXES
+        .iter()
+        .find(|(suffix, ty)| lit.ends_with(suffix) && !(hex && *ty == "F64"))
+    {
+        Some((suffix, ty)) => (&lit[..lit.len() - suffix.len()], Some(*ty)),
+        None => (lit.as_str(), None),
+    };
+    let (radix, digits) = if let Some(digits) = lit.strip_prefix("0x") {
+        (16, digits)
+    } else if let Some(digits) = lit.strip_prefix("0b") {
+        (2, digits)
+    } else {
+        (10, lit)
+    };
+    let ty = match ty {
+        Some(ty) => ty,
+        None if radix == 10 && digits.contains('.') => "F64",
+        None => "I32",
+    };
+    if ty == "F64" {
+        return match radix {
+            10 => digits.parse().map(float64).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| error(format!("Invalid number `{}`", head.value)));
+    }
+    let n = num_bigint::BigInt::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| error(format!("Invalid number `{}`", head.value)))?;
+    let out_of_range = |_| error(format!("`{}` is out of range for {}", head.value, ty));
+    Ok(Val::PrimVal(match ty {
+        "I32" => Prim::I32(i32::try_from(&n).map_err(out_of_range)?),
+        "I64" => Prim::I64(i64::try_from(&n).map_err(out_of_range)?),
+        "U8" => Prim::U8(u8::try_from(&n).map_err(out_of_range)?),
+        "U32" => Prim::U32(u32::try_from(&n).map_err(out_of_range)?),
+        "U64" => Prim::U64(u64::try_from(&n).map_err(out_of_range)?),
+        _ => Prim::BigInt(n),
+    }))
+}
+
 fn nud(
     storage: &mut DBStorage,
     mut toks: VecDeque<Token>,
@@ -50,7 +135,24 @@ fn nud(
     if let Some(head) = toks.pop_front() {
         match head.tok_type {
             TokenType::NumLit => {
-                let val = int32(head.value.parse().expect("Unexpected numeric character"));
+                let val = number(&head)?;
+                Ok((
+                    Node::ValNode(val.clone(), head.get_info()),
+                    AstNode::Value(val).into_data(head.pos),
+                    toks,
+                ))
+            }
+            TokenType::CharLit => {
+                let mut chars = head.value.chars();
+                let val = match (chars.next(), chars.next()) {
+                    (Some(chr), None) => Val::PrimVal(Prim::Char(chr)),
+                    _ => {
+                        return Err(TError::ParseError(
+                            format!("Expected a single character in `{}`", head.value),
+                            head.get_info(),
+                        ))
+                    }
+                };
                 Ok((
                     Node::ValNode(val.clone(), head.get_info()),
                     AstNode::Value(val).into_data(head.pos),
@@ -65,6 +167,10 @@ fn nud(
                     toks,
                 ))
             }
+            TokenType::InterpolatedLit(ref parts) => {
+                let (node, data) = interpolation(storage, &head, parts)?;
+                Ok((node, data, toks))
+            }
             TokenType::Op => {
                 let lbp = binding_power(storage, &head)?;
                 let (right, right_node, new_toks) = expr(storage, toks, lbp)?;
@@ -90,7 +196,18 @@ fn nud(
                 format!("Unexpected close bracket {}", head.value),
                 head.get_info(),
             )),
+            TokenType::OpenBracket if head.value != "(" => bracketed(storage, head, toks),
             TokenType::OpenBracket => {
+                // `()` is unit, i.e. the value of an optional that is missing.
+                if toks.front().map(|t| t.value.as_str()) == Some(")") {
+                    toks.pop_front();
+                    let val = unit_type();
+                    return Ok((
+                        val.clone().into_node(),
+                        AstNode::Value(val).into_data(head.pos),
+                        toks,
+                    ));
+                }
                 let (inner, inner_node, mut new_toks) = expr(storage, toks, 0)?;
                 // TODO require close bracket.
                 let close = new_toks.front();
@@ -100,13 +217,11 @@ fn nud(
                         Some(Token {
                             value: close,
                             tok_type: TokenType::CloseBracket,
-                            pos: _,
+                            ..
                         }),
                     ) => {
                         match (open, close.as_str()) {
                             ("(", ")") => {}
-                            ("[", "]") => {}
-                            ("{", "}") => {}
                             (open, chr) => {
                                 return Err(TError::ParseError(
                                     format!(
@@ -126,7 +241,23 @@ fn nud(
                     }
                 }
                 new_toks.pop_front();
-                Ok((inner, inner_node, new_toks))
+                if !matches!(&inner, Node::BinOpNode(BinOp { name, .. }) if name == ",") {
+                    return Ok((inner, inner_node, new_toks));
+                }
+                // `(a, b=2)` is a tuple, with the fields `0` and `b`.
+                tuple_fields(&inner)?;
+                let op = storage
+                    .store_node(AstNode::Symbol("()".to_string()).into_data(head.pos.clone()));
+                let inner_entity = storage.store_node(inner_node);
+                Ok((
+                    tuple(inner, &head.get_info()),
+                    AstNode::Apply {
+                        inner: op,
+                        children: vec![inner_entity],
+                    }
+                    .into_data(head.pos),
+                    new_toks,
+                ))
             }
             TokenType::Sym => {
                 // TODO: Consider making these globals.
@@ -138,6 +269,15 @@ fn nud(
                         toks,
                     ));
                 }
+                if matches!(head.value.as_str(), "type" | "interface" | "impl") {
+                    return declaration(storage, head, toks);
+                }
+                if is_control_flow(storage, &head, &toks) {
+                    return control_flow(storage, head, toks);
+                }
+                if head.value == "let" {
+                    return let_binding(storage, head, toks);
+                }
                 Ok((
                     Sym {
                         name: head.value.clone(),
@@ -161,6 +301,371 @@ fn nud(
     }
 }
 
+// Desugars an interpolated string, e.g. `"Hello {name}!"`, to `"Hello " ++ name ++ "!"`.
+fn interpolation(
+    storage: &mut DBStorage,
+    head: &Token,
+    parts: &[StringPart],
+) -> Result<(Node, AstNodeData), TError> {
+    let text = |text: &str| {
+        let val = string(text);
+        (
+            Node::ValNode(val.clone(), head.get_info()),
+            AstNode::Value(val).into_data(head.pos.clone()),
+        )
+    };
+    let mut out = None;
+    for part in parts {
+        let right = match part {
+            StringPart::Text(s) if s.is_empty() => continue,
+            StringPart::Text(s) => text(s),
+            StringPart::Code(code, pos) => {
+                let toks = lex_from(code, pos.clone())?;
+                if toks.is_empty() {
+                    return Err(TError::ParseError(
+                        "Expected an expression in `{}`".to_string(),
+                        pos.clone().get_info(),
+                    ));
+                }
+                let (inner, inner_node, left_over) = expr(storage, toks, 0)?;
+                if let Some(tok) = left_over.front() {
+                    return Err(TError::ParseError(
+                        format!("Unexpected {:?} in interpolated expression", tok),
+                        tok.get_info(),
+                    ));
+                }
+                (inner, inner_node)
+            }
+        };
+        out = Some(match out {
+            None if matches!(part, StringPart::Text(_)) => right,
+            // Starting from a string ensures that the result is a string.
+            None => concat(storage, head, text(""), right),
+            Some(left) => concat(storage, head, left, right),
+        });
+    }
+    Ok(out.unwrap_or_else(|| text("")))
+}
+
+fn concat(
+    storage: &mut DBStorage,
+    head: &Token,
+    (left, left_node): (Node, AstNodeData),
+    (right, right_node): (Node, AstNodeData),
+) -> (Node, AstNodeData) {
+    let left_entity = storage.store_node(left_node);
+    let inner = storage.store_node(AstNode::Symbol("++".to_string()).into_data(head.pos.clone()));
+    let right_entity = storage.store_node(right_node);
+    (
+        BinOp {
+            name: "++".to_string(),
+            left: Box::new(left),
+            right: Box::new(right),
+            info: head.get_info(),
+        }
+        .into_node(),
+        AstNode::Apply {
+            inner,
+            children: vec![left_entity, right_entity],
+        }
+        .into_data(head.pos.clone()),
+    )
+}
+
+// Parses the elements of a list, e.g. `[1, 2, 3]`, or the body of a block,
+// e.g. `{y = 3; y * 2}`, after the opening bracket.
+fn bracketed(
+    storage: &mut DBStorage,
+    head: Token,
+    toks: VecDeque<Token>,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    let close = if head.value == "[" { "]" } else { "}" };
+    let name = format!("{}{}", head.value, close);
+    let (inner, inner_node, mut new_toks) = match toks.front() {
+        Some(Token {
+            tok_type: TokenType::CloseBracket,
+            value,
+            ..
+        }) if value == close => {
+            // An empty list or block has no elements, i.e. a unit.
+            let unit = Val::Product(set![]);
+            let node = AstNode::Value(unit.clone()).into_data(head.pos.clone());
+            (unit.into_node(), node, toks)
+        }
+        _ => expr(storage, toks, 0)?,
+    };
+    match new_toks.pop_front() {
+        Some(Token {
+            tok_type: TokenType::CloseBracket,
+            value,
+            ..
+        }) if value == close => {}
+        found => {
+            return Err(TError::ParseError(
+                format!("Unclosed bracket {}, found {:?}", head.value, found),
+                head.get_info(),
+            ))
+        }
+    }
+    let op = storage.store_node(AstNode::Symbol(name.clone()).into_data(head.pos.clone()));
+    let inner_entity = storage.store_node(inner_node);
+    Ok((
+        UnOp {
+            name,
+            inner: Box::new(inner),
+            info: head.get_info(),
+        }
+        .into_node(),
+        AstNode::Apply {
+            inner: op,
+            children: vec![inner_entity],
+        }
+        .into_data(head.pos),
+        new_toks,
+    ))
+}
+
+// Parses the block that is the body of some control flow, e.g. the `{...}` in `while x {...}`.
+fn body(
+    storage: &mut DBStorage,
+    head: &Token,
+    mut toks: VecDeque<Token>,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    match toks.pop_front() {
+        Some(open) if open.tok_type == TokenType::OpenBracket && open.value == "{" => {
+            bracketed(storage, open, toks)
+        }
+        found => Err(TError::ParseError(
+            format!("Expected a block after `{}`, found {:?}", head.value, found),
+            head.get_info(),
+        )),
+    }
+}
+
+// Checks that a keyword is followed by the rest of its control flow, i.e. a condition and a block,
+// or the `x in` of a `for`, otherwise it is an ordinary name (e.g. of a user defined `if`).
+fn is_control_flow(storage: &mut DBStorage, head: &Token, toks: &VecDeque<Token>) -> bool {
+    let is_sym = |tok: Option<&Token>| matches!(tok, Some(tok) if tok.tok_type == TokenType::Sym);
+    match head.value.as_str() {
+        "for" => is_sym(toks.front()) && matches!(toks.get(1), Some(tok) if tok.value == "in"),
+        "if" | "while" => match expr(storage, toks.clone(), 0) {
+            Ok((_, _, rest)) => matches!(
+                rest.front(),
+                Some(tok) if tok.tok_type == TokenType::OpenBracket && tok.value == "{"
+            ),
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
+
+// Parses control flow after its keyword, one of:
+// `if cond {...} else {...}`, `while cond {...}` or `for x in xs {...}`.
+// These are sugar for the operators and definitions that they are lowered to.
+fn control_flow(
+    storage: &mut DBStorage,
+    head: Token,
+    mut toks: VecDeque<Token>,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    let info = head.get_info();
+    let name = if head.value == "for" {
+        let name = match toks.pop_front() {
+            Some(Token {
+                tok_type: TokenType::Sym,
+                value,
+                ..
+            }) => value,
+            found => {
+                return Err(TError::ParseError(
+                    format!("Expected a name for the items in `for`, found {:?}", found),
+                    info,
+                ))
+            }
+        };
+        match toks.pop_front() {
+            Some(tok) if tok.tok_type == TokenType::Sym && tok.value == "in" => {}
+            found => {
+                return Err(TError::ParseError(
+                    format!("Expected `in` after `for {}`, found {:?}", name, found),
+                    info,
+                ))
+            }
+        }
+        name
+    } else {
+        "".to_string()
+    };
+    // The condition, or the items to loop over, ends at the `{` of the body.
+    let (cond, cond_node, toks) = expr(storage, toks, 0)?;
+    let (then, then_node, mut toks) = body(storage, &head, toks)?;
+    let mut children = vec![storage.store_node(cond_node), storage.store_node(then_node)];
+    let node = match head.value.as_str() {
+        "if" => match toks.front() {
+            Some(tok) if tok.tok_type == TokenType::Sym && tok.value == "else" => {
+                let else_tok = toks.pop_front().expect("Missing else");
+                let (then_else, then_else_node, new_toks) = match toks.front() {
+                    Some(tok) if tok.tok_type == TokenType::Sym && tok.value == "if" => {
+                        let if_tok = toks.pop_front().expect("Missing if");
+                        control_flow(storage, if_tok, toks)?
+                    }
+                    _ => body(storage, &else_tok, toks)?,
+                };
+                children.push(storage.store_node(then_else_node));
+                toks = new_toks;
+                if_else(cond, then, then_else, &info)
+            }
+            _ => if_then(cond, then, &info),
+        },
+        "while" => while_loop(cond, then, &info),
+        _ => for_loop(&name, cond, then, &info),
+    };
+    let inner = storage.store_node(AstNode::Symbol(head.value.clone()).into_data(head.pos.clone()));
+    Ok((
+        node,
+        AstNode::Apply { inner, children }.into_data(head.pos),
+        toks,
+    ))
+}
+
+// Parses a definition after `let`, e.g. `let x = 3`, which can also destructure a tuple, e.g.
+// `let (a, b) = pair`.
+fn let_binding(
+    storage: &mut DBStorage,
+    head: Token,
+    toks: VecDeque<Token>,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    let (pattern, pattern_node, mut toks) = nud(storage, toks)?;
+    let eq = match toks.pop_front() {
+        Some(tok) if tok.tok_type == TokenType::Op && tok.value == "=" => tok,
+        found => {
+            return Err(TError::ParseError(
+                format!("Expected `=` after `let {}`, found {:?}", pattern, found),
+                head.get_info(),
+            ))
+        }
+    };
+    let lbp = binding_power(storage, &eq)?
+        - match binding_dir(storage, &eq)? {
+            Direction::Left => 0,
+            Direction::Right => 1,
+        };
+    let (value, value_node, new_toks) = expr_after(storage, toks, lbp, Some(&eq))?;
+    let info = head.get_info();
+    let node = match pattern {
+        Node::SymNode(sym) => Let {
+            name: sym.name,
+            args: None,
+            value: Box::new(value),
+            info,
+        }
+        .into_node(),
+        pattern => destructure(&pattern, value, &info)?,
+    };
+    let inner = storage.store_node(AstNode::Symbol(head.value.clone()).into_data(head.pos.clone()));
+    let children = vec![
+        storage.store_node(pattern_node),
+        storage.store_node(value_node),
+    ];
+    Ok((
+        node,
+        AstNode::Apply { inner, children }.into_data(head.pos),
+        new_toks,
+    ))
+}
+
+// Parses a declaration after its keyword, one of:
+// `type Name = Ctor(field: Type) | ...`,
+// `interface Name = op(arg: Self): Type, ...` or
+// `impl Name(Type) = (op(arg) = ..., ...)`.
+fn declaration(
+    storage: &mut DBStorage,
+    head: Token,
+    toks: VecDeque<Token>,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    let comma = Token {
+        tok_type: TokenType::Op,
+        value: ",".to_string(),
+        pos: head.pos.clone(),
+        new_line: false,
+    };
+    // The declaration ends at the next `,` or `;`.
+    let lbp = binding_power(storage, &comma)?;
+    let (decl, decl_node, new_toks) = expr(storage, toks, lbp)?;
+    let decl = match decl {
+        Node::LetNode(decl) if decl.args.is_none() || head.value == "impl" => decl,
+        decl => {
+            return Err(TError::ParseError(
+                format!("Expected a {} declaration, found {}", head.value, decl),
+                head.get_info(),
+            ))
+        }
+    };
+    match head.value.as_str() {
+        "type" => {
+            let ctors = constructors(&decl.value)?;
+            for (index, ctor) in ctors.iter().enumerate() {
+                if ctors[..index].iter().any(|other| other.name == ctor.name) {
+                    return Err(TError::ParseError(
+                        format!("Duplicate constructor `{}` of `{}`", ctor.name, decl.name),
+                        ctor.info.clone(),
+                    ));
+                }
+                for field in ctor.fields.iter() {
+                    if field.info.ty.is_none() || field.args.is_some() {
+                        return Err(TError::ParseError(
+                            format!(
+                                "Expected a type for field `{}` of `{}`",
+                                field.name, ctor.name
+                            ),
+                            field.get_info(),
+                        ));
+                    }
+                }
+            }
+        }
+        "interface" => {
+            for sig in signatures(&decl.value)? {
+                if sig.info.ty.is_none() {
+                    return Err(TError::ParseError(
+                        format!("Expected a result type for `{}`", sig.name),
+                        sig.info,
+                    ));
+                }
+                for arg in sig.args.iter() {
+                    if arg.info.ty.is_none() || arg.args.is_some() {
+                        return Err(TError::ParseError(
+                            format!(
+                                "Expected a type for argument `{}` of `{}`",
+                                arg.name, sig.name
+                            ),
+                            arg.get_info(),
+                        ));
+                    }
+                }
+            }
+        }
+        _ => {
+            implementation(&decl)?;
+        }
+    }
+    let inner = storage.store_node(AstNode::Symbol(head.value.clone()).into_data(head.pos.clone()));
+    let decl_entity = storage.store_node(decl_node);
+    Ok((
+        UnOp {
+            name: head.value.clone(),
+            inner: Box::new(decl.into_node()),
+            info: head.get_info(),
+        }
+        .into_node(),
+        AstNode::Apply {
+            inner,
+            children: vec![decl_entity],
+        }
+        .into_data(head.pos),
+        new_toks,
+    ))
+}
+
 fn get_defs(args: Node) -> Vec<Let> {
     if let Node::SymNode(symn) = args {
         return vec![symn.as_let()];
@@ -181,12 +686,34 @@ fn get_defs(args: Node) -> Vec<Let> {
             return left;
         }
     }
-    vec![Let {
+    vec![implicit_arg(args)]
+}
+
+// Passes a value as the implicit argument, `it`.
+fn implicit_arg(arg: Node) -> Let {
+    Let {
         name: "it".to_string(),
         args: None,
-        info: args.get_info(),
-        value: Box::new(args),
-    }]
+        info: arg.get_info(),
+        value: Box::new(arg),
+    }
+}
+
+// Whether a value could be a function, i.e. a name or the result of a call.
+fn is_callable(value: &Node) -> bool {
+    matches!(value, Node::SymNode(_) | Node::ApplyNode(_))
+}
+
+// Whether a token starts a value that can be passed to a function without parentheses.
+fn is_value(tok: &Token) -> bool {
+    matches!(
+        tok.tok_type,
+        TokenType::NumLit
+            | TokenType::StringLit
+            | TokenType::InterpolatedLit(_)
+            | TokenType::CharLit
+            | TokenType::Sym
+    )
 }
 
 fn led(
@@ -213,26 +740,69 @@ fn led(
             left.get_info(),
         )),
         Some(head) => match head.tok_type {
-            TokenType::NumLit | TokenType::StringLit | TokenType::Sym => {
+            TokenType::NumLit
+            | TokenType::StringLit
+            | TokenType::InterpolatedLit(_)
+            | TokenType::CharLit
+            | TokenType::Sym => {
                 let pos = head.pos.clone();
+                if head.new_line || !is_callable(&left) {
+                    // Values on a new line are separate, as are values after values that aren't
+                    // functions, i.e. `x\ny` and `3 x` are `x, y` and `3, x`.
+                    toks.push_front(head);
+                    toks.push_front(Token {
+                        tok_type: TokenType::Op,
+                        value: ",".to_string(),
+                        pos,
+                        new_line: false,
+                    });
+                    return Ok((left, left_node, toks));
+                }
+                if let Node::SymNode(sym) = &left {
+                    if matches!(sym.name.as_str(), "if" | "while" | "for") {
+                        return Err(TError::ParseError(
+                            format!("Expected a condition and a block after `{}`", sym.name),
+                            left.get_info(),
+                        ));
+                    }
+                }
+                // `f x` is `f(it=x)`. The argument is a single value, so application binds
+                // tighter than any operator, e.g. `f x + 1` is `f(it=x) + 1`.
+                let info = head.get_info();
                 toks.push_front(head);
-                toks.push_front(Token {
-                    tok_type: TokenType::Op,
-                    value: ",".to_string(),
-                    pos,
-                });
-                Ok((left, left_node, toks))
+                let (mut arg, mut arg_node, mut new_toks) = nud(storage, toks)?;
+                // Calls are nested to the right, i.e. `f g x` is `f(it=g(it=x))`.
+                if is_callable(&arg)
+                    && matches!(new_toks.front(), Some(tok) if is_value(tok) && !tok.new_line)
+                {
+                    (arg, arg_node, new_toks) = led(storage, new_toks, arg, arg_node)?;
+                }
+                Ok((
+                    Apply {
+                        inner: Box::new(left),
+                        args: vec![implicit_arg(arg)],
+                        info,
+                    }
+                    .into_node(),
+                    AstNode::Apply {
+                        inner: storage.store_node(left_node),
+                        children: storage.store_node_set(arg_node),
+                    }
+                    .into_data(pos),
+                    new_toks,
+                ))
             }
             TokenType::Op => {
                 let lbp = binding_power(storage, &head)?;
                 let assoc = binding_dir(storage, &head)?;
-                let (right, right_node, new_toks) = expr(
+                let (mut right, right_node, new_toks) = expr_after(
                     storage,
                     toks,
                     lbp - match assoc {
                         Direction::Left => 0,
                         Direction::Right => 1,
                     },
+                    Some(&head),
                 )?;
                 match head.value.as_str() {
                     ":" => {
@@ -294,8 +864,9 @@ fn led(
                             ))
                         }
                     },
-                    "=" => {
+                    "=" | ":=" => {
                         let right_entity = storage.store_node(right_node);
+                        let mutable = head.value == ":=";
                         match left {
                             Node::SymNode(s) => {
                                 return Ok((
@@ -303,7 +874,11 @@ fn led(
                                         name: s.name.clone(),
                                         args: None,
                                         value: Box::new(right),
-                                        info: head.get_info(),
+                                        info: Info {
+                                            ty: s.info.ty,
+                                            mutable,
+                                            ..head.get_info()
+                                        },
                                     }
                                     .into_node(),
                                     AstNode::Definition {
@@ -315,14 +890,18 @@ fn led(
                                     new_toks,
                                 ))
                             }
-                            Node::ApplyNode(a) => match *a.inner {
+                            Node::ApplyNode(a) if !mutable => match *a.inner {
                                 Node::SymNode(s) => {
                                     return Ok((
                                         Let {
                                             name: s.name.clone(),
                                             args: Some(a.args),
                                             value: Box::new(right),
-                                            info: head.get_info(),
+                                            // The annotation on a call is the type of its result.
+                                            info: Info {
+                                                ty: a.info.ty,
+                                                ..head.get_info()
+                                            },
                                         }
                                         .into_node(),
                                         AstNode::Definition {
@@ -332,7 +911,7 @@ fn led(
                                         }
                                         .into_data(head.pos),
                                         new_toks,
-                                    ))
+                                    ));
                                 }
                                 _ => {
                                     return Err(TError::ParseError(
@@ -349,6 +928,23 @@ fn led(
                             }
                         }
                     }
+                    "<-" | "+=" | "-=" | "*=" | "/=" | "%="
+                        if !matches!(left, Node::SymNode(_)) =>
+                    {
+                        return Err(TError::ParseError(
+                            format!("Cannot assign to {}", left),
+                            head.get_info(),
+                        ));
+                    }
+                    "|=" => {
+                        // The postcondition is a predicate over the result, `it`.
+                        right = Abs {
+                            name: "it".to_string(),
+                            value: Box::new(right),
+                            info: head.get_info(),
+                        }
+                        .into_node();
+                    }
                     _ => {}
                 }
                 let left_entity = storage.store_node(left_node);
@@ -375,6 +971,47 @@ fn led(
                 "Unexpected close bracket".to_string(),
                 head.get_info(),
             )),
+            TokenType::OpenBracket if head.value == "[" => {
+                // Indexing, e.g. `xs[1]`.
+                let (index, index_node, mut new_toks) = expr(storage, toks, 0)?;
+                match new_toks.pop_front() {
+                    Some(Token {
+                        tok_type: TokenType::CloseBracket,
+                        value,
+                        ..
+                    }) if value == "]" => {}
+                    close => {
+                        return Err(TError::ParseError(
+                            format!("Unclosed bracket [, found {:?}", close),
+                            head.get_info(),
+                        ))
+                    }
+                }
+                let left_entity = storage.store_node(left_node);
+                let inner = storage
+                    .store_node(AstNode::Symbol("[]".to_string()).into_data(head.pos.clone()));
+                let index_entity = storage.store_node(index_node);
+                Ok((
+                    BinOp {
+                        info: head.get_info(),
+                        name: "[]".to_string(),
+                        left: Box::new(left),
+                        right: Box::new(index),
+                    }
+                    .into_node(),
+                    AstNode::Apply {
+                        inner,
+                        children: vec![left_entity, index_entity],
+                    }
+                    .into_data(head.pos),
+                    new_toks,
+                ))
+            }
+            TokenType::OpenBracket if head.value == "{" => Err(TError::ParseError(
+                // Blocks after an expression are the bodies of control flow, e.g. `if x {...}`.
+                "Unexpected block after an expression".to_string(),
+                head.get_info(),
+            )),
             TokenType::OpenBracket => {
                 if head.value.as_str() == "("
                     && toks.front().map(|t| &t.value) == Some(&")".to_string())
@@ -404,7 +1041,6 @@ fn led(
                     ) => {
                         match (open, close.as_str()) {
                             ("(", ")") => {}
-                            ("[", "]") => {}
                             ("{", "}") => {}
                             (open, chr) => {
                                 return Err(TError::ParseError(
@@ -453,30 +1089,42 @@ fn expr(
     storage: &mut DBStorage,
     init_toks: VecDeque<Token>,
     init_lbp: i32,
+) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
+    expr_after(storage, init_toks, init_lbp, None)
+}
+
+// Parses an expression, `outer` is the operator that it is the right operand of (if any).
+fn expr_after(
+    storage: &mut DBStorage,
+    init_toks: VecDeque<Token>,
+    init_lbp: i32,
+    outer: Option<&Token>,
 ) -> Result<(Node, AstNodeData, VecDeque<Token>), TError> {
     // TODO: Name update's fields, this is confusing (0 is tree, 1 is toks)
     let init_update = nud(storage, init_toks)?;
     let mut left: Node = init_update.0;
     let mut left_node = init_update.1;
     let mut toks: VecDeque<Token> = init_update.2;
+    let mut prev = outer.cloned();
     loop {
         match toks.front() {
             None => break,
             Some(token) => {
-                if init_lbp >= binding_power(storage, token)? {
+                if token.tok_type == TokenType::CloseBracket
+                    || (token.tok_type == TokenType::OpenBracket && token.value == "{")
+                    || init_lbp >= binding_power(storage, token)?
+                {
                     break;
                 }
+                if token.tok_type == TokenType::Op {
+                    if let Some(prev) = &prev {
+                        check_mix(storage, prev, token)?;
+                    }
+                    prev = Some(token.clone());
+                }
             }
         }
-        let update = led(storage, toks.clone(), left.clone(), left_node.clone());
-        // TODO: Only retry on parse failures...
-        if let Ok(update) = update {
-            left = update.0;
-            left_node = update.1;
-            toks = update.2;
-        } else {
-            return Ok((left, left_node, toks));
-        }
+        (left, left_node, toks) = led(storage, toks, left, left_node)?;
     }
     Ok((left, left_node, toks))
 }
@@ -487,18 +1135,23 @@ fn lex_string(
     contents: &str,
 ) -> Result<VecDeque<Token>, TError> {
     let filename = storage.filename(module.to_vec());
-    let mut toks: VecDeque<Token> = VecDeque::new();
-
-    let mut pos = Loc {
-        filename: Some(filename),
+    let pos = Loc {
+        filename: filename.into(),
         ..Loc::default()
     };
+    lex_from(contents, pos)
+}
+
+fn lex_from(contents: &str, mut pos: Loc) -> Result<VecDeque<Token>, TError> {
+    let mut toks: VecDeque<Token> = VecDeque::new();
     let mut chars = contents.chars().peekable();
     loop {
-        let (next, new_chars) = lex_head(chars, &mut pos);
+        let line = pos.pos.line;
+        let (mut next, new_chars) = lex_head(chars, &mut pos)?;
         if next.tok_type == TokenType::Unknown {
             break; // TODO done / skip?
         }
+        next.new_line = !toks.is_empty() && next.pos.pos.line != line;
         // If valid, take the token and move on.
         toks.push_back(next);
         chars = new_chars;
@@ -570,6 +1223,68 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_num_forms() -> Test {
+        assert_eq!(parse("1_000")?, int32(1000).into_node());
+        assert_eq!(parse("0xff")?, int32(255).into_node());
+        assert_eq!(parse("0b101")?, int32(5).into_node());
+        assert_eq!(parse("1.5")?, float64(1.5).into_node());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_num_with_suffix() -> Test {
+        assert_eq!(parse("7i64")?, Val::PrimVal(Prim::I64(7)).into_node());
+        assert_eq!(parse("0xffu8")?, Val::PrimVal(Prim::U8(255)).into_node());
+        assert_eq!(parse("2f64")?, float64(2.0).into_node());
+        assert_eq!(
+            parse("99999999999999999999bigint")?,
+            Val::PrimVal(Prim::BigInt("99999999999999999999".parse().unwrap())).into_node()
+        );
+        // Suffixes that start like a radix prefix, e.g. `0b` in `0bigint`.
+        assert_eq!(
+            parse("0bigint")?,
+            Val::PrimVal(Prim::BigInt(0.into())).into_node()
+        );
+        assert_eq!(parse("0b11u8")?, Val::PrimVal(Prim::U8(3)).into_node());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_num_out_of_range() {
+        match parse("256u8") {
+            Err(TError::ParseError(msg, _)) => {
+                assert_eq!(msg, "`256u8` is out of range for U8")
+            }
+            res => panic!("expected a parse error, got {:?}", res),
+        }
+        assert!(parse("2147483648").is_err());
+        // Errors inside a definition are reported, rather than ending the definition early.
+        for prog in &["x = 256u8;0", "x = 3_000_000_000;0", "x = 0x;0"] {
+            match parse(prog) {
+                Err(TError::ParseError(msg, _)) => assert!(
+                    msg.contains("out of range") || msg.starts_with("Invalid number"),
+                    "{}",
+                    msg
+                ),
+                res => panic!("expected an invalid literal in {}, got {:?}", prog, res),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_invalid_num() {
+        assert!(parse("12abc").is_err());
+        assert!(parse("0b1f64").is_err());
+    }
+
+    #[test]
+    fn parse_char() -> Test {
+        assert_eq!(parse("`é`")?, Val::PrimVal(Prim::Char('é')).into_node());
+        assert!(parse("`ab`").is_err());
+        Ok(())
+    }
+
     #[test]
     fn parse_str() -> Test {
         assert_eq!(parse("\"hello world\"")?, string("hello world").into_node());
@@ -590,6 +1305,37 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_interpolated_str() -> Test {
+        let x = Sym {
+            name: "x".to_string(),
+            info: Info::default(),
+        }
+        .into_node();
+        assert_eq!(
+            parse("\"a {x}\"")?,
+            BinOp {
+                name: "++".to_string(),
+                left: Box::new(string("a ").into_node()),
+                right: Box::new(x.clone()),
+                info: Info::default()
+            }
+            .into_node()
+        );
+        // Interpolating a single value still gives a string.
+        assert_eq!(
+            parse("\"{x}\"")?,
+            BinOp {
+                name: "++".to_string(),
+                left: Box::new(string("").into_node()),
+                right: Box::new(x),
+                info: Info::default()
+            }
+            .into_node()
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_min_op() -> Test {
         assert_eq!(
@@ -689,6 +1435,105 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_mixed_and_or_needs_parens() {
+        match parse("a && b || c") {
+            Err(TError::ParseError(msg, _)) => assert_eq!(
+                msg,
+                "ambiguous use of `&&` and `||`, add parentheses, e.g. `(a && b) || c` or `a && (b || c)`"
+            ),
+            res => panic!("expected an ambiguity error, got {:?}", res),
+        }
+        for code in ["x = a || b && c", "f(it=a && b || c)"] {
+            match parse(code) {
+                Err(TError::ParseError(msg, _)) => {
+                    assert!(msg.starts_with("ambiguous use"), "{}", msg)
+                }
+                res => panic!("expected an ambiguity error, got {:?}", res),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_mixed_and_or_with_parens() -> Test {
+        assert_eq!(format!("{}", parse("(a && b) || c")?), "((a&&b)||c)");
+        assert_eq!(format!("{}", parse("a && (b || c)")?), "(a&&(b||c))");
+        assert_eq!(format!("{}", parse("a && b && c")?), "((a&&b)&&c)");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_optional_operators() -> Test {
+        assert_eq!(format!("{}", parse("()")?), "Unit");
+        assert_eq!(format!("{}", parse("a?.b ?: c ?: d")?), "((a?.b)?:(c?:d))");
+        assert_eq!(format!("{}", parse("a?.f() ?: 0")?), "((a?.(f)())?:0)");
+        assert!(parse("a ? b ?: c").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_paren_free_calls() -> Test {
+        assert_eq!(format!("{}", parse("f x")?), "(f)(it=x)");
+        assert_eq!(format!("{}", parse("f 3 + 1")?), "((f)(it=3)+1)");
+        assert_eq!(format!("{}", parse("f \"hi\"")?), "(f)(it='hi')");
+        assert_eq!(format!("{}", parse("-f 3")?), "(-(f)(it=3))");
+        assert_eq!(format!("{}", parse("f g 3")?), "(f)(it=(g)(it=3))");
+        assert_eq!(
+            format!("{}", parse("f g x y")?),
+            "(f)(it=(g)(it=(x)(it=y)))"
+        );
+        assert_eq!(format!("{}", parse("f(it=3) 4")?), "((f)(it=3))(it=4)");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_paren_free_calls_keep_operators() -> Test {
+        assert_eq!(format!("{}", parse("!true")?), "(!true)");
+        assert_eq!(format!("{}", parse("-3")?), "(-3)");
+        assert_eq!(format!("{}", parse("f -3")?), "(f-3)");
+        // Values on separate lines aren't calls, nor are values after values that aren't
+        // names or calls.
+        assert_eq!(format!("{}", parse("f\nx")?), "(f,x)");
+        assert_eq!(format!("{}", parse("f // comment\nx")?), "(f,x)");
+        assert_eq!(format!("{}", parse("3 x")?), "(3,x)");
+        assert_eq!(format!("{}", parse("'a' 'b'")?), "('a','b')");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_tuples() -> Test {
+        assert_eq!(format!("{}", parse("(1, 2)")?), "((1,2))");
+        assert_eq!(format!("{}", parse("(1, y=2).y")?), "(((1,y=2)).y)");
+        // Parentheses around a single value only group it.
+        assert_eq!(format!("{}", parse("(1 + 2)")?), "(1+2)");
+        assert_eq!(
+            format!("{}", parse("let (a, b) = p")?),
+            "((let_a_b=p;a=(let_a_b.0));b=(let_a_b.1))"
+        );
+        assert_eq!(
+            format!("{}", parse("let ((a, b), y=c) = p")?),
+            "(((let_a_b_c=p;a=((let_a_b_c.0).0));b=((let_a_b_c.0).1));c=(let_a_b_c.y))"
+        );
+        assert_eq!(format!("{}", parse("let x = 3")?), "x=3");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_tuples_with_misplaced_fields() {
+        for prog in [
+            "(x=1, 2)",
+            "(x=1, x=2)",
+            "point = (x=1, 2)",
+            "let (a, 3) = p",
+            "let (a, b)",
+        ] {
+            match parse(prog) {
+                Err(TError::ParseError(..)) => {}
+                res => panic!("expected a parse error for {}, got {:?}", prog, res),
+            }
+        }
+    }
+
     #[test]
     fn parse_add_str() -> Test {
         assert_eq!(
@@ -749,6 +1594,208 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_mutable_declarations() -> Test {
+        match parse("x := 1; x = 2")? {
+            Node::BinOpNode(op) => match (*op.left, *op.right) {
+                (Node::LetNode(mutable), Node::LetNode(immutable)) => {
+                    assert!(mutable.info.mutable);
+                    assert!(!immutable.info.mutable);
+                }
+                res => panic!("expected two lets, got {:?}", res),
+            },
+            res => panic!("expected a sequence, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_assignment_to_non_variable() {
+        assert!(parse("f(x) += 1").is_err());
+        assert!(parse("f(x) /= 2").is_err());
+        assert!(parse("f(x) %= 2").is_err());
+    }
+
+    #[test]
+    fn parse_type_declaration() -> Test {
+        match parse("type Shape = Circle(r: I32) | Empty")? {
+            Node::UnOpNode(op) => {
+                assert_eq!(op.name, "type");
+                let decl = op.inner.as_let()?;
+                assert_eq!(decl.name, "Shape");
+                let ctors: Vec<String> = constructors(&decl.value)?
+                    .into_iter()
+                    .map(|ctor| ctor.name)
+                    .collect();
+                assert_eq!(ctors, vec!["Circle", "Empty"]);
+            }
+            res => panic!("expected a type declaration, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_type_declaration_with_untyped_field() {
+        assert!(parse("type Shape = Circle(r) | Empty").is_err());
+    }
+
+    #[test]
+    fn parse_type_declaration_with_duplicate_constructor() {
+        match parse("type S = A | A") {
+            Err(TError::ParseError(msg, _)) => assert_eq!(msg, "Duplicate constructor `A` of `S`"),
+            res => panic!("expected a parse error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn parse_interface_declaration() -> Test {
+        match parse("interface Sized = (size(it: Self): I32, grow(it: Self, by: I32): Self)")? {
+            Node::UnOpNode(op) => {
+                assert_eq!(op.name, "interface");
+                let decl = op.inner.as_let()?;
+                assert_eq!(decl.name, "Sized");
+                let ops: Vec<String> = signatures(&decl.value)?
+                    .into_iter()
+                    .map(|sig| sig.name)
+                    .collect();
+                assert_eq!(ops, vec!["size", "grow"]);
+            }
+            res => panic!("expected an interface declaration, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_interface_declaration_without_result_type() {
+        assert!(parse("interface Sized = size(it: Self)").is_err());
+    }
+
+    #[test]
+    fn parse_implementation() -> Test {
+        match parse("impl Sized(Point) = (size(it) = 1, grow(it, by) = it)")? {
+            Node::UnOpNode(op) => {
+                assert_eq!(op.name, "impl");
+                let decl = op.inner.as_let()?;
+                let imp = implementation(&decl)?;
+                assert_eq!((imp.interface, imp.ty), ("Sized", "Point"));
+                let methods: Vec<&str> = imp.methods.iter().map(|m| m.name.as_str()).collect();
+                assert_eq!(methods, vec!["size", "grow"]);
+            }
+            res => panic!("expected an implementation, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_implementation_without_type() {
+        assert!(parse("impl Sized = (size(it) = 1)").is_err());
+    }
+
+    #[test]
+    fn parse_list_literal() -> Test {
+        match parse("[1, 2, 3]")? {
+            Node::UnOpNode(op) => {
+                assert_eq!(op.name, "[]");
+                assert_eq!(elements(&op.inner).len(), 3);
+            }
+            res => panic!("expected a list literal, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_empty_list_literal() -> Test {
+        match parse("[]")? {
+            Node::UnOpNode(op) => assert!(elements(&op.inner).is_empty()),
+            res => panic!("expected a list literal, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_block() -> Test {
+        match parse("{y = 3; y * 2}")? {
+            Node::UnOpNode(op) => {
+                assert_eq!(op.name, "{}");
+                assert_eq!(format!("{}", op.inner), "(y=3;(y*2))");
+            }
+            res => panic!("expected a block, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_unclosed_block() {
+        assert!(parse("{y = 3; y * 2").is_err());
+    }
+
+    #[test]
+    fn parse_if_else() -> Test {
+        match parse("if x > 1 { 2 } else { 3 }")? {
+            Node::BinOpNode(op) => {
+                assert_eq!(op.name, "?");
+                assert_eq!(format!("{}", op.left), "((x>1)-|{2})");
+                assert_eq!(format!("{}", op.right), "{3}");
+            }
+            res => panic!("expected an if, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_if_as_a_name() -> Test {
+        for code in [
+            "if(it=true, then=1, else=2)",
+            "import(if(lib::exists(it=\"fast_maths\"), then=\"fast_maths\", else=\"maths\"))",
+        ] {
+            match parse(code)? {
+                Node::ApplyNode(_) => {}
+                res => panic!("expected a call, got {:?}", res),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_if_needs_a_block() {
+        assert!(parse("if x > 1 2").is_err());
+        assert!(parse("if x > 1 { 2 } else 3").is_err());
+    }
+
+    #[test]
+    fn parse_while() -> Test {
+        match parse("while x < 3 { x += 1 }")? {
+            Node::UnOpNode(op) => assert_eq!(op.name, "{}"),
+            res => panic!("expected a loop, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_for_needs_in() {
+        assert!(parse("for x in xs { x }").is_ok());
+        assert!(parse("for x xs { x }").is_err());
+    }
+
+    #[test]
+    fn parse_index() -> Test {
+        match parse("xs[1+1]")? {
+            Node::BinOpNode(op) => {
+                assert_eq!(op.name, "[]");
+                assert_eq!(format!("{}", op.right), "(1+1)");
+            }
+            res => panic!("expected an index, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_dots_associate_left() -> Test {
+        assert_eq!(format!("{}", parse("p.x.y")?), "((p.x).y)");
+        assert_eq!(format!("{}", parse("p.x.f(n=1)")?), "((p.x).(f)(n=1))");
+        Ok(())
+    }
+
     #[test]
     fn entity_parse_num() -> Test {
         assert_str_eq!(
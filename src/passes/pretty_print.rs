@@ -1,12 +1,15 @@
 use crate::ast::*;
 use crate::database::DBStorage;
 use crate::errors::TError;
-use crate::primitives::Val;
+use crate::primitives::{unit_type, Val};
 use std::fmt::Write;
 
 // Walks the AST interpreting it.
 #[derive(Default)]
-pub struct PrettyPrint {}
+pub struct PrettyPrint {
+    // Print symbols as they were written, rather than where they were defined.
+    pub names_only: bool,
+}
 
 // TODO: Return nodes.
 type Res = Result<(), TError>;
@@ -20,10 +23,9 @@ impl Visitor<State, (), String, Node> for PrettyPrint {
     }
 
     fn visit_sym(&mut self, _storage: &mut DBStorage, state: &mut State, expr: &Sym) -> Res {
-        if let Some(def_at) = expr.get_info().defined_at {
-            write!(state, ".{}", path_to_string(&def_at))?;
-        } else {
-            write!(state, "{}", expr.name)?;
+        match expr.get_info().defined_at {
+            Some(def_at) if !self.names_only => write!(state, ".{}", path_to_string(&def_at))?,
+            _ => write!(state, "{}", expr.name)?,
         }
         Ok(())
     }
@@ -56,10 +58,9 @@ impl Visitor<State, (), String, Node> for PrettyPrint {
     }
 
     fn visit_let(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Let) -> Res {
-        if let Some(def_at) = expr.get_info().defined_at {
-            write!(state, ".{}", path_to_string(&def_at))?;
-        } else {
-            write!(state, "{}", expr.name)?;
+        match expr.get_info().defined_at {
+            Some(def_at) if !self.names_only => write!(state, ".{}", path_to_string(&def_at))?,
+            _ => write!(state, "{}", expr.name)?,
         }
 
         if let Some(args) = &expr.args {
@@ -69,8 +70,14 @@ impl Visitor<State, (), String, Node> for PrettyPrint {
                 if !is_first {
                     write!(state, ", ")?;
                 }
-                self.visit_let(storage, state, arg)?;
                 is_first = false;
+                // Parameters without defaults are shown as they were written.
+                match (&arg.args, &*arg.value) {
+                    (None, Node::ValNode(val, _)) if self.names_only && *val == unit_type() => {
+                        write!(state, "{}", arg.name)?
+                    }
+                    _ => self.visit_let(storage, state, arg)?,
+                }
             }
             write!(state, ")")?;
         }
@@ -79,15 +86,60 @@ impl Visitor<State, (), String, Node> for PrettyPrint {
     }
 
     fn visit_un_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        // Loops are shown as they were written, rather than as the definitions they are lowered to.
+        if let (true, Some((cond, body))) = (expr.name == "{}", loop_parts(&expr.inner)) {
+            write!(state, "while ")?;
+            self.visit(storage, state, cond)?;
+            write!(state, " ")?;
+            return self.visit(storage, state, body);
+        }
+        if let (true, Some((name, items, body))) = (expr.name == "{}", for_parts(&expr.inner)) {
+            write!(state, "for {} in ", name)?;
+            self.visit(storage, state, items)?;
+            write!(state, " ")?;
+            return self.visit(storage, state, body);
+        }
+        if matches!(expr.name.as_str(), "[]" | "{}" | "()") {
+            let (open, close) = expr.name.split_at(1);
+            write!(state, "{}", open)?;
+            if !elements(&expr.inner).is_empty() {
+                self.visit(storage, state, &expr.inner)?;
+            }
+            write!(state, "{}", close)?;
+            return Ok(());
+        }
         write!(state, "({}", expr.name)?;
+        if expr.name.chars().all(char::is_alphabetic) {
+            write!(state, " ")?; // Keywords, e.g. `type`.
+        }
         self.visit(storage, state, &*expr.inner)?;
         write!(state, ")")?;
         Ok(())
     }
 
     fn visit_bin_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        if let Some((cond, then, then_else)) = if_else_parts(expr) {
+            write!(state, "if ")?;
+            self.visit(storage, state, cond)?;
+            write!(state, " ")?;
+            self.visit(storage, state, then)?;
+            write!(state, " else ")?;
+            return self.visit(storage, state, then_else);
+        }
+        if let Some((cond, then)) = if_then_parts(expr) {
+            write!(state, "if ")?;
+            self.visit(storage, state, cond)?;
+            write!(state, " ")?;
+            return self.visit(storage, state, then);
+        }
         write!(state, "(")?;
         self.visit(storage, state, &*expr.left)?;
+        if expr.name == "[]" {
+            write!(state, "[")?;
+            self.visit(storage, state, &expr.right)?;
+            write!(state, "])")?;
+            return Ok(());
+        }
         write!(state, "{}", expr.name)?;
         self.visit(storage, state, &*expr.right)?;
         write!(state, ")")?;
@@ -3,10 +3,15 @@ use crate::database::DBStorage;
 use crate::errors::TError;
 use crate::primitives::Val;
 use crate::symbol_table::*;
+use std::collections::HashMap;
 
 // Walks the AST interpreting it.
 #[derive(Default)]
-pub struct SymbolTableBuilder {}
+pub struct SymbolTableBuilder {
+    blocks: usize,
+    // The data type that declared each constructor.
+    constructors: HashMap<Path, String>,
+}
 
 // TODO: Return nodes.
 type Res = Result<Node, TError>;
@@ -19,7 +24,7 @@ pub struct State {
 
 impl Visitor<State, Node, Root, Path> for SymbolTableBuilder {
     fn visit_root(&mut self, storage: &mut DBStorage, module: &Path) -> Result<Root, TError> {
-        let expr = &storage.parse_file(module.clone())?;
+        let expr = &storage.parse_module(module.clone())?;
         if storage.debug_level() > 0 {
             eprintln!(
                 "building symbol table for file... {}",
@@ -69,7 +74,7 @@ impl Visitor<State, Node, Root, Path> for SymbolTableBuilder {
     }
 
     fn visit_apply(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
-        state.path.push(Symbol::Anon());
+        state.path.push(Symbol::Anon(0));
         let args = expr
             .args
             .iter()
@@ -95,7 +100,7 @@ impl Visitor<State, Node, Root, Path> for SymbolTableBuilder {
         let mut info = expr.get_info();
         state.path.push(Symbol::new(&expr.name));
         info.defined_at = Some(state.path.clone());
-        state.table.get_mut(&state.path);
+        state.table.get_mut(&state.path).value.mutable = info.mutable;
 
         let value = Box::new(self.visit(storage, state, &expr.value)?);
         state.path.pop();
@@ -117,7 +122,7 @@ impl Visitor<State, Node, Root, Path> for SymbolTableBuilder {
         let mut info = expr.get_info();
         state.path.push(Symbol::new(&expr.name));
         info.defined_at = Some(state.path.clone());
-        state.table.get_mut(&state.path);
+        state.table.get_mut(&state.path).value.mutable = info.mutable;
 
         // Consider the function arguments defined in this scope.
         let args = if let Some(args) = &expr.args {
@@ -142,11 +147,57 @@ impl Visitor<State, Node, Root, Path> for SymbolTableBuilder {
     }
 
     fn visit_un_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
-        let inner = Box::new(self.visit(storage, state, &expr.inner)?);
+        if expr.name == "type" {
+            // Constructors are defined alongside the type.
+            let decl = expr.inner.as_let()?;
+            for ctor in constructors(&decl.value)? {
+                let mut path = state.path.clone();
+                path.push(Symbol::new(&ctor.name));
+                if let Some(other) = self.constructors.insert(path.clone(), decl.name.clone()) {
+                    return Err(TError::DuplicateConstructor(ctor.name, other, ctor.info));
+                }
+                state.table.get_mut(&path);
+            }
+        }
+        if expr.name == "interface" {
+            // Operations are defined alongside the interface.
+            for sig in signatures(&expr.inner.as_let()?.value)? {
+                let mut path = state.path.clone();
+                path.push(Symbol::new(&sig.name));
+                state.table.get_mut(&path);
+            }
+        }
+        if expr.name == "impl" {
+            let decl = expr.inner.as_let()?;
+            state.path.push(implementation(&decl)?.scope());
+            let value = self.visit(storage, state, &decl.value);
+            state.path.pop();
+            let decl = Let {
+                value: Box::new(value?),
+                ..decl
+            };
+            return Ok(UnOp {
+                name: expr.name.clone(),
+                inner: Box::new(decl.into_node()),
+                info: expr.get_info(),
+            }
+            .into_node());
+        }
+        let mut info = expr.get_info();
+        if expr.name == "{}" {
+            // Definitions in a block are local to it.
+            self.blocks += 1;
+            state.path.push(Symbol::Anon(self.blocks));
+            info.defined_at = Some(state.path.clone());
+        }
+        let inner = self.visit(storage, state, &expr.inner);
+        if expr.name == "{}" {
+            state.path.pop();
+        }
         Ok(UnOp {
             name: expr.name.clone(),
-            inner,
-            info: expr.get_info(),
+            inner: Box::new(inner?),
+            info,
         }
         .into_node())
     }
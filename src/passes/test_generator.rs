@@ -0,0 +1,577 @@
+use crate::ast::*;
+use crate::database::DBStorage;
+use crate::errors::TError;
+use crate::passes::interpreter::Interpreter;
+use crate::passes::type_checker::infer;
+use crate::primitives::{
+    bit_type, boolean, f64_type, float64, i32_type, i64_type, int32, list_type, string,
+    string_type, u32_type, u64_type, u8_type, Float, Pack, Prim::*, Val, Val::*,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+// Number of generated calls to try for each function.
+const CASES: i32 = 100;
+
+// Number of nested calls that a generated call can make, so that runaway recursion is reported
+// rather than overflowing the stack.
+const DEPTH_LIMIT: usize = 1000;
+
+// Bytes of stack for running the generated calls, which the depth limit must fit within.
+const STACK_SIZE: usize = 64 << 20;
+
+thread_local! {
+    // Whether panics on this thread are caught and reported as failures, rather than printed.
+    static QUIET_PANICS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+// Stops panics on threads that generate tests from being printed, leaving other threads' panics
+// to the hook that was already installed.
+fn quiet_generated_panics() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+    INSTALL.call_once(|| {
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.with(|quiet| quiet.get()) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+// A seeded pseudo random number generator (i.e. SplitMix64), which is enough for choosing
+// arguments, without depending on a random number library.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn gen_bool(&mut self) -> bool {
+        self.next() & 1 == 1
+    }
+
+    // Picks a number from `low` up to, but not including, `high`.
+    fn gen_range(&mut self, low: i32, high: i32) -> i32 {
+        let range = (i64::from(high) - i64::from(low)) as u64;
+        (i64::from(low) + (self.next() % range) as i64) as i32
+    }
+}
+
+// Generates calls to each function in a module, looking for inputs that break its contracts.
+pub struct TestGenerator {
+    rng: Rng,
+}
+
+impl Default for TestGenerator {
+    fn default() -> TestGenerator {
+        // Use a fixed seed so that reported counterexamples are reproducible.
+        TestGenerator {
+            rng: Rng { state: 0x7a6b0 },
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Failure {
+    Error(TError),
+    Panic(String),
+}
+
+impl Failure {
+    fn same_kind(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (Failure::Error(left), Failure::Error(right)) => {
+                std::mem::discriminant(left) == std::mem::discriminant(right)
+            }
+            (Failure::Panic(_), Failure::Panic(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "{}", err),
+            Failure::Panic(msg) => write!(f, "panic: {}", msg),
+        }
+    }
+}
+
+type Args = Vec<(String, Val)>;
+
+// Collects the definitions at the top level of a module.
+fn definitions(node: &Node, defs: &mut Vec<Let>) {
+    match node {
+        Node::LetNode(def) => defs.push(def.clone()),
+        Node::BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == ";" || name == "," => {
+            definitions(left, defs);
+            definitions(right, defs);
+        }
+        _ => {}
+    }
+}
+
+// Chains a module's definitions, so that they are in scope for the given expression.
+fn with_definitions(defs: &[Let], expr: Node, info: &Info) -> Node {
+    let mut program = expr;
+    for def in defs.iter().rev() {
+        program = BinOp {
+            name: ";".to_string(),
+            left: Box::new(def.clone().into_node()),
+            right: Box::new(program),
+            info: info.clone(),
+        }
+        .into_node();
+    }
+    program
+}
+
+// Finds the inferred types of a function's arguments, picking I32 for arguments that could be any
+// number (or any type).
+fn argument_types(
+    storage: &mut DBStorage,
+    defs: &[Let],
+    def: &Let,
+) -> Result<Vec<(String, Val)>, TError> {
+    let info = def.get_info();
+    let sym = Sym {
+        name: def.name.clone(),
+        info: info.clone(),
+    }
+    .into_node();
+    let program = with_definitions(defs, sym, &info);
+    let (intros, arguments) = match infer(storage, &program, &rec![])? {
+        Function {
+            intros, arguments, ..
+        } => (intros, *arguments),
+        ty => (Pack::new(), ty),
+    };
+    let fields = match arguments {
+        Struct(fields) => fields,
+        ty => vec![("it".to_string(), ty)],
+    };
+    fields
+        .into_iter()
+        .map(|(name, ty)| Ok((name, generated_type(&intros, ty, &info)?)))
+        .collect()
+}
+
+// Picks the type of the values to generate for an argument of an inferred type.
+fn generated_type(intros: &Pack, ty: Val, info: &Info) -> Result<Val, TError> {
+    let prims = [
+        bit_type(),
+        string_type(),
+        i32_type(),
+        i64_type(),
+        u8_type(),
+        u32_type(),
+        u64_type(),
+        f64_type(),
+    ];
+    match ty {
+        ty if prims.contains(&ty) => Ok(ty),
+        // Type variables (e.g. of numbers) are either quantified or still being inferred.
+        Variable(ref var) if var.starts_with('_') || intros.iter().any(|(name, _)| name == var) => {
+            Ok(i32_type())
+        }
+        App {
+            ref inner,
+            ref arguments,
+        } if **inner == Variable("List".to_string()) => match &**arguments {
+            Struct(fields) if fields.len() == 1 => Ok(list_type(generated_type(
+                intros,
+                fields[0].1.clone(),
+                info,
+            )?)),
+            _ => Ok(list_type(i32_type())),
+        },
+        ty => Err(TError::TypeMismatch(
+            format!("can't generate arguments of type {}", ty),
+            Box::new(ty),
+            info.clone(),
+        )),
+    }
+}
+
+fn shrink(val: &Val) -> Vec<Val> {
+    match val {
+        PrimVal(I32(n)) if *n != 0 => {
+            let mut smaller = vec![int32(0), int32(n / 2), int32(n - n.signum())];
+            if *n < 0 {
+                smaller.push(int32(-n));
+            }
+            smaller.dedup();
+            smaller.retain(|s| s != val);
+            smaller
+        }
+        PrimVal(I64(n)) if *n != 0 => {
+            let mut smaller = vec![0, n / 2, n - n.signum()];
+            if *n < 0 {
+                smaller.push(-n);
+            }
+            smaller.dedup();
+            smaller.retain(|s| s != n);
+            smaller.into_iter().map(|n| PrimVal(I64(n))).collect()
+        }
+        PrimVal(U8(n)) if *n != 0 => unsigned_shrinks(*n as u64, |n| PrimVal(U8(n as u8))),
+        PrimVal(U32(n)) if *n != 0 => unsigned_shrinks(*n as u64, |n| PrimVal(U32(n as u32))),
+        PrimVal(U64(n)) if *n != 0 => unsigned_shrinks(*n, |n| PrimVal(U64(n))),
+        PrimVal(F64(Float(x))) if *x != 0.0 => {
+            let mut smaller = vec![float64(0.0), float64(x.trunc())];
+            if *x < 0.0 {
+                smaller.push(float64(-x));
+            }
+            smaller.dedup();
+            smaller.retain(|s| s != val);
+            smaller
+        }
+        List(vals) if !vals.is_empty() => {
+            let mut smaller = vec![
+                List(vec![]),
+                List(vals[1..].to_vec()),
+                List(vals[..vals.len() - 1].to_vec()),
+            ];
+            for (index, element) in vals.iter().enumerate() {
+                for element in shrink(element) {
+                    let mut vals = vals.clone();
+                    vals[index] = element;
+                    smaller.push(List(vals));
+                }
+            }
+            smaller.dedup();
+            smaller.retain(|s| s != val);
+            smaller
+        }
+        PrimVal(Bool(true)) => vec![boolean(false)],
+        PrimVal(Str(s)) if !s.is_empty() => {
+            let chars: Vec<char> = s.chars().collect();
+            let mut smaller = vec![
+                string(""),
+                string(&chars[..chars.len() / 2].iter().collect::<String>()),
+                string(&chars[1..].iter().collect::<String>()),
+                string(&chars[..chars.len() - 1].iter().collect::<String>()),
+            ];
+            smaller.dedup();
+            smaller.retain(|s| s != val);
+            smaller
+        }
+        _ => vec![],
+    }
+}
+
+fn unsigned_shrinks(n: u64, make: impl Fn(u64) -> Val) -> Vec<Val> {
+    let mut smaller = vec![0, n / 2, n - 1];
+    smaller.dedup();
+    smaller.retain(|s| *s != n);
+    smaller.into_iter().map(make).collect()
+}
+
+// Writes a value as a literal, with the suffix of its number type.
+fn format_value(val: &Val) -> String {
+    match val {
+        PrimVal(Str(s)) => format!("{:?}", s),
+        PrimVal(I64(n)) => format!("{}i64", n),
+        PrimVal(U8(n)) => format!("{}u8", n),
+        PrimVal(U32(n)) => format!("{}u32", n),
+        PrimVal(U64(n)) => format!("{}u64", n),
+        List(vals) => {
+            let vals: Vec<String> = vals.iter().map(format_value).collect();
+            format!("[{}]", vals.join(", "))
+        }
+        val => format!("{}", val),
+    }
+}
+
+fn format_call(name: &str, args: &[(String, Val)]) -> String {
+    let args: Vec<String> = args
+        .iter()
+        .map(|(name, val)| {
+            let val = format_value(val);
+            if val.starts_with('-') {
+                format!("{}=({})", name, val)
+            } else {
+                format!("{}={}", name, val)
+            }
+        })
+        .collect();
+    format!("{}({})", name, args.join(", "))
+}
+
+impl TestGenerator {
+    pub fn run(&mut self, storage: &mut DBStorage, module: Path) -> Result<String, TError> {
+        // Panics are reported as failures, so don't print them as they happen.
+        quiet_generated_panics();
+        // Generated calls run on a thread with a large stack, so that they reach the depth limit
+        // long before they could overflow it.
+        std::thread::scope(|scope| {
+            std::thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, || {
+                    QUIET_PANICS.with(|quiet| quiet.set(true));
+                    self.run_module(storage, module)
+                })
+                .expect("Failed to start test generation thread")
+                .join()
+                .expect("Test generation thread panicked")
+        })
+    }
+
+    fn run_module(&mut self, storage: &mut DBStorage, module: Path) -> Result<String, TError> {
+        let root = storage.look_up_definitions(module)?;
+        let mut defs = vec![];
+        definitions(&root.ast, &mut defs);
+        let mut report = vec![];
+        for def in defs.iter() {
+            if def.args.as_ref().map_or(true, |args| args.is_empty()) {
+                continue;
+            }
+            let types = match argument_types(storage, &defs, def) {
+                Ok(types) => types,
+                Err(err) => {
+                    report.push(format!("{}: skipped, {}", def.name, err));
+                    continue;
+                }
+            };
+            match self.find_counterexample(storage, &root, &defs, def, &types)? {
+                None => report.push(format!("{}: passed {} generated calls", def.name, CASES)),
+                Some((args, failure)) => report.push(format!(
+                    "{}: failed with {}\n  minimal call: {}",
+                    def.name,
+                    failure,
+                    format_call(&def.name, &args)
+                )),
+            }
+        }
+        Ok(report.join("\n"))
+    }
+
+    fn generate(&mut self, ty: &Val, size: i32) -> Val {
+        if *ty == bit_type() {
+            boolean(self.rng.gen_bool())
+        } else if *ty == string_type() {
+            let len = self.rng.gen_range(0, size + 1);
+            let s: String = (0..len)
+                .map(|_| (b'a' + self.rng.gen_range(0, 26) as u8) as char)
+                .collect();
+            string(&s)
+        } else if *ty == i64_type() {
+            PrimVal(I64(self.rng.gen_range(-size, size + 1) as i64))
+        } else if *ty == u8_type() {
+            PrimVal(U8(self.rng.gen_range(0, size.min(255) + 1) as u8))
+        } else if *ty == u32_type() {
+            PrimVal(U32(self.rng.gen_range(0, size + 1) as u32))
+        } else if *ty == u64_type() {
+            PrimVal(U64(self.rng.gen_range(0, size + 1) as u64))
+        } else if *ty == f64_type() {
+            // Halves are exact, so that counterexamples print the value that was used.
+            float64(self.rng.gen_range(-2 * size, 2 * size + 1) as f64 / 2.0)
+        } else if let App { arguments, .. } = ty {
+            let element = match &**arguments {
+                Struct(fields) => fields[0].1.clone(),
+                _ => i32_type(),
+            };
+            let len = self.rng.gen_range(0, size / 2 + 1);
+            List((0..len).map(|_| self.generate(&element, size)).collect())
+        } else {
+            int32(self.rng.gen_range(-size, size + 1))
+        }
+    }
+
+    fn find_counterexample(
+        &mut self,
+        storage: &mut DBStorage,
+        root: &Root,
+        defs: &[Let],
+        def: &Let,
+        types: &[(String, Val)],
+    ) -> Result<Option<(Args, Failure)>, TError> {
+        for case in 0..CASES {
+            // Start with small inputs and grow them, so most failures are found small.
+            let size = case / 4;
+            let args: Args = types
+                .iter()
+                .map(|(name, ty)| (name.clone(), self.generate(ty, size)))
+                .collect();
+            if let Some(failure) = call(storage, root, defs, def, &args) {
+                return Ok(Some(shrink_failure(
+                    storage, root, defs, def, args, failure,
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn shrink_failure(
+    storage: &mut DBStorage,
+    root: &Root,
+    defs: &[Let],
+    def: &Let,
+    mut args: Args,
+    mut failure: Failure,
+) -> (Args, Failure) {
+    'shrinking: loop {
+        for (index, (_, val)) in args.iter().enumerate() {
+            for smaller in shrink(val) {
+                let mut candidate = args.clone();
+                candidate[index].1 = smaller;
+                match call(storage, root, defs, def, &candidate) {
+                    Some(next) if next.same_kind(&failure) => {
+                        args = candidate;
+                        failure = next;
+                        continue 'shrinking;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        return (args, failure);
+    }
+}
+
+// Calls a function after its module's definitions, reporting contract failures, runtime errors and
+// panics.
+fn call(
+    storage: &mut DBStorage,
+    root: &Root,
+    defs: &[Let],
+    def: &Let,
+    args: &[(String, Val)],
+) -> Option<Failure> {
+    let info = def.get_info();
+    let apply = Apply {
+        inner: Box::new(
+            Sym {
+                name: def.name.clone(),
+                info: info.clone(),
+            }
+            .into_node(),
+        ),
+        args: args
+            .iter()
+            .map(|(name, val)| Let {
+                name: name.clone(),
+                args: None,
+                value: Box::new(val.clone().into_node()),
+                info: info.clone(),
+            })
+            .collect(),
+        info: info.clone(),
+    }
+    .into_node();
+    let program = with_definitions(defs, apply, &info);
+    let root = Root {
+        ast: program,
+        table: root.table.clone(),
+    };
+    let mut silent_print = |_: &mut DBStorage, _, _| Ok(int32(0));
+    let mut silent_eprint = |_: &mut DBStorage, _, _| Ok(int32(0));
+    let mut interp = Interpreter::default();
+    interp.depth_limit = Some(DEPTH_LIMIT);
+    interp.impls.insert("print".to_string(), &mut silent_print);
+    interp
+        .impls
+        .insert("eprint".to_string(), &mut silent_eprint);
+    let result = catch_unwind(AssertUnwindSafe(|| interp.visit_root(storage, &root)));
+    match result {
+        Ok(Ok(_)) => None,
+        Ok(Err(err @ TError::RequirementFailure(_)))
+        | Ok(Err(err @ TError::PostconditionFailure(_)))
+        | Ok(Err(err @ TError::RecursionLimit(_, _)))
+        | Ok(Err(err @ TError::DivisionByZero(_)))
+        | Ok(Err(err @ TError::IndexOutOfBounds(_, _, _, _)))
+        | Ok(Err(err @ TError::MissingKey(_, _))) => Some(Failure::Error(err)),
+        // Other errors (e.g. effects that aren't handled) can't be blamed on the inputs.
+        Ok(Err(_)) => None,
+        Err(panic) => Some(Failure::Panic(
+            if let Some(msg) = panic.downcast_ref::<&str>() {
+                msg.to_string()
+            } else if let Some(msg) = panic.downcast_ref::<String>() {
+                msg.clone()
+            } else {
+                "unknown panic".to_string()
+            },
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink_i32_towards_zero() {
+        assert_eq!(shrink(&int32(0)), vec![]);
+        assert_eq!(shrink(&int32(10)), vec![int32(0), int32(5), int32(9)]);
+        assert_eq!(
+            shrink(&int32(-3)),
+            vec![int32(0), int32(-1), int32(-2), int32(3)]
+        );
+    }
+
+    #[test]
+    fn shrink_str_towards_empty() {
+        assert_eq!(shrink(&string("")), vec![]);
+        assert_eq!(shrink(&string("a")), vec![string("")]);
+        assert_eq!(
+            shrink(&string("abc")),
+            vec![string(""), string("a"), string("bc"), string("ab")]
+        );
+    }
+
+    #[test]
+    fn shrink_list_towards_empty() {
+        assert_eq!(shrink(&List(vec![])), vec![]);
+        assert_eq!(
+            shrink(&List(vec![int32(2)])),
+            vec![List(vec![]), List(vec![int32(0)]), List(vec![int32(1)])]
+        );
+    }
+
+    #[test]
+    fn generate_inferred_types() {
+        let info = Info::default();
+        let intros = Pack::new();
+        assert_eq!(generated_type(&intros, f64_type(), &info), Ok(f64_type()));
+        assert_eq!(
+            generated_type(&intros, list_type(Variable("_1".to_string())), &info),
+            Ok(list_type(i32_type()))
+        );
+        assert!(generated_type(&intros, Variable("Char".to_string()), &info).is_err());
+    }
+
+    #[test]
+    fn format_calls_as_tako() {
+        assert_eq!(
+            format_call(
+                "f",
+                &[
+                    ("x".to_string(), int32(-3)),
+                    ("y".to_string(), string("hi")),
+                    ("z".to_string(), boolean(true)),
+                ]
+            ),
+            "f(x=(-3), y=\"hi\", z=true)"
+        );
+        assert_eq!(
+            format_call(
+                "g",
+                &[
+                    ("x".to_string(), PrimVal(I64(-3))),
+                    ("y".to_string(), List(vec![PrimVal(U8(1))])),
+                ]
+            ),
+            "g(x=(-3i64), y=[1u8])"
+        );
+    }
+}
@@ -1,8 +1,152 @@
 use crate::ast::*;
-use crate::primitives::{Prim, Val};
+use crate::cli_options::ContractMode;
+use crate::externs::{get_interface, CONTAINERS, FIXED_NUMBERS, OPTIONALS, PRODUCTS};
+use crate::passes::interpreter::Interpreter;
+use crate::passes::pretty_print::PrettyPrint;
+use crate::passes::type_checker::eval_type;
+use crate::primitives::{num_bits, unit_type, variable, Prim, Val};
 use crate::symbol_table::*;
+use crate::type_graph::TypeGraph;
 use crate::{database::DBStorage, errors::TError};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+// Reports a failed contract and exits with a status reserved for contract failures.
+const CONTRACT_FAILURE: &str = "#include <cstdlib>
+#include <iostream>
+[[noreturn]] void tako_contract_failure(const char* kind, const char* at, const char* expr) {
+  std::cerr << \"runtime \" << kind << \" failed at \" << at << \": \" << expr << std::endl;
+  std::exit(101);
+}";
+
+// Operations that are handled somewhere in a program are called through `operation::perform`,
+// which runs the innermost clause handling them, or the operation itself. Clauses run without
+// their own handler (or the handlers installed inside it), and exit from the handled expression by
+// throwing a `handler_exit`, unless they resume.
+const HANDLERS: &str = "#include <functional>
+#include <iostream>
+#include <string>
+#include <type_traits>
+#include <utility>
+#include <vector>
+namespace tako {
+// The value of operations that are only run for their effects, e.g. `print`.
+struct unit {};
+std::ostream& operator<<(std::ostream& out, const unit&) {
+  return out << \"()\";
+}
+// Handlers are numbered in the order that they are installed.
+int& handlers_installed() {
+  static int installed = 0;
+  return installed;
+}
+std::vector<std::pair<int, int>>& handlers_hidden() {
+  static std::vector<std::pair<int, int>> hidden;
+  return hidden;
+}
+bool handler_visible(int handler) {
+  for (const auto& hidden : handlers_hidden()) {
+    if (hidden.first <= handler && handler <= hidden.second) {
+      return false;
+    }
+  }
+  return true;
+}
+struct hide_handlers {
+  explicit hide_handlers(int handler) {
+    handlers_hidden().emplace_back(handler, handlers_installed());
+  }
+  ~hide_handlers() {
+    handlers_hidden().pop_back();
+  }
+};
+template <typename R>
+struct fallback {
+  template <typename F, typename... Args>
+  static R call(const F& op, const Args&... args) {
+    return op(args...);
+  }
+};
+template <>
+struct fallback<unit> {
+  template <typename F, typename... Args>
+  static unit call(const F& op, const Args&... args) {
+    op(args...);
+    return unit{};
+  }
+};
+// The clauses handling the operation numbered `Op`, with their handlers, innermost last.
+template <int Op, typename F>
+std::vector<std::pair<int, std::function<F>>>& clauses() {
+  static std::vector<std::pair<int, std::function<F>>> clauses;
+  return clauses;
+}
+template <int Op, typename F>
+struct operation;
+template <int Op, typename R, typename... Args>
+struct operation<Op, R(Args...)> {
+  template <typename F>
+  static R perform(const F& op, Args... args) {
+    auto& handling = clauses<Op, R(Args...)>();
+    for (auto clause = handling.rbegin(); clause != handling.rend(); ++clause) {
+      if (handler_visible(clause->first)) {
+        const hide_handlers hide(clause->first);
+        return clause->second(args...);
+      }
+    }
+    return fallback<R>::call(op, args...);
+  }
+  struct install {
+    install(int handler, std::function<R(Args...)> clause) {
+      clauses<Op, R(Args...)>().emplace_back(handler, clause);
+    }
+    ~install() {
+      clauses<Op, R(Args...)>().pop_back();
+    }
+  };
+};
+template <typename T>
+struct handler_exit {
+  int handler;
+  T value;
+};
+template <typename T>
+struct handled_value {
+  using type = T;
+};
+template <>
+struct handled_value<const char*> {
+  using type = std::string;
+};
+// An expression with handlers, and the clauses that are waiting for its value, innermost last.
+template <typename T>
+struct handler {
+  using value = typename handled_value<T>::type;
+  const int id = ++handlers_installed();
+  std::vector<std::function<value(value)>> resumptions;
+  template <typename Body>
+  value run(const Body& body) const {
+    try {
+      return body();
+    } catch (const handler_exit<value>& exit) {
+      if (exit.handler != id) {
+        throw;
+      }
+      return exit.value;
+    }
+  }
+  // Leaves the expression with a value, in place of the `R` that a clause would resume with.
+  template <typename R>
+  R exit(const value& val) const {
+    throw handler_exit<value>{id, val};
+  }
+  value resume(value val) const {
+    for (auto waiting = resumptions.rbegin(); waiting != resumptions.rend(); ++waiting) {
+      val = (*waiting)(val);
+    }
+    return val;
+  }
+};
+}";
 
 // Walks the AST compiling it to wasm.
 #[derive(Default)]
@@ -10,6 +154,48 @@ pub struct CodeGenerator {
     functions: Vec<Code>,
     includes: HashSet<String>,
     pub flags: HashSet<String>,
+    // Data types, by name, with their struct and the names of their constructors.
+    types: HashMap<String, (String, Vec<String>)>,
+    constructors: HashMap<String, CppConstructor>,
+    // The operations of the interfaces declared in the program.
+    interfaces: HashMap<String, Vec<(String, Val)>>,
+    // The interfaces that each data type implements, by the name of the type.
+    implemented: HashMap<String, Vec<String>>,
+    // How many function bodies are being generated, main's body is at depth 1.
+    // Functions defined in main can use (and change) its variables, which outlive them.
+    function_depth: usize,
+    // The operations that handlers in the program handle, by name, and the handlers' clauses.
+    handled: HashMap<String, HandledOperation>,
+    clauses: HashSet<Path>,
+    // The arguments of the functions in the program, which can't be assigned to.
+    arguments: HashSet<Path>,
+    // The types of the program's top level definitions, found when they are first needed.
+    graph: Option<TypeGraph>,
+    // The requirements on arguments that hold in the code being generated.
+    facts: Vec<Bound>,
+    // The recursive functions whose bodies are being generated, which are passed themselves as
+    // their first argument (see `visit_let`).
+    recursive: HashSet<String>,
+}
+
+// An operation that can be handled, numbered for `tako::operation`, with the code that calls it
+// when it isn't handled and the C++ types of its arguments (by name) and result.
+#[derive(Clone, Debug)]
+struct HandledOperation {
+    id: usize,
+    code: String,
+    arguments: Vec<(String, String)>,
+    result: String,
+}
+
+// Where a constructor's tag and fields are stored in its data type's struct.
+#[derive(Clone, Debug)]
+struct CppConstructor {
+    ty: String,
+    tag: usize,
+    members: Vec<(String, String)>,
+    // The members holding values of the data type itself, which are stored behind pointers.
+    boxed: HashSet<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -19,13 +205,16 @@ pub enum Code {
     Struct(Vec<Code>),
     Expr(String),
     Statement(String),
-    Template(String, Box<Code>),
     Assignment(String, Box<Code>),
     If {
         condition: Box<Code>,
         then: Box<Code>,
         then_else: Box<Code>,
     },
+    While {
+        condition: Box<Code>,
+        body: Box<Code>,
+    },
     Func {
         name: String,
         args: Vec<String>,
@@ -33,6 +222,8 @@ pub enum Code {
         body: Box<Code>,
         lambda: bool,
         call: bool,
+        // Lambdas for function values that can outlive their frame capture by value.
+        by_value: bool,
     },
 }
 
@@ -48,7 +239,6 @@ impl Code {
                 Code::Block(statements)
             }
             Code::Statement(line) => Code::Statement(line),
-            Code::Template(name, body) => Code::Template(name, Box::new(body.with_expr(f))),
             Code::Assignment(name, value) => Code::Assignment(name, Box::new(value.with_expr(f))),
             Code::If {
                 condition,
@@ -59,12 +249,14 @@ impl Code {
                 then,
                 then_else,
             },
+            Code::While { condition, body } => Code::While { condition, body },
             Code::Func {
                 name,
                 args,
                 mut body,
                 lambda,
                 call,
+                by_value,
                 return_type,
             } => {
                 body = Box::new(body.with_expr(f));
@@ -74,12 +266,22 @@ impl Code {
                     body,
                     lambda,
                     call,
+                    by_value,
                     return_type,
                 }
             }
         }
     }
 
+    // Runs code for its effects, dropping the value of its last expression.
+    fn into_statements(self: Code) -> Code {
+        let block = match self {
+            Code::Block(statements) => Code::Block(statements),
+            code => Code::Block(vec![code]),
+        };
+        block.with_expr(&|exp| Code::Statement(exp))
+    }
+
     fn merge(self: Code, other: Code) -> Code {
         match (self, other) {
             (Code::Empty, right) => right,
@@ -115,10 +317,34 @@ impl Code {
 }
 
 pub fn make_name(def: Vec<Symbol>) -> String {
-    let def_n: Vec<String> = def.iter().map(|n| n.clone().to_name()).collect();
+    // Members of imported modules are named with their module, e.g. `maths.square`, and the hidden
+    // names of loops are made into identifiers, e.g. `#for`.
+    let def_n: Vec<String> = def
+        .iter()
+        .map(|n| n.clone().to_name().replace('.', "_").replace('#', "tako_"))
+        .collect();
     def_n.join("_")
 }
 
+// Quotes a string for C++, leaving non-ascii characters as UTF-8.
+fn quote_string(s: &str) -> String {
+    let mut out = "\"".to_string();
+    for chr in s.chars() {
+        match chr {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\0' => out.push_str("\\0"),
+            chr if chr.is_ascii_control() => out.push_str(&format!("\\{:03o}", chr as u8)),
+            chr => out.push(chr),
+        }
+    }
+    out.push('"');
+    out
+}
+
 fn pretty_print_block(src: Code, indent: &str) -> String {
     let new_indent = indent.to_string() + "  ";
     // Calculate the expression as well...
@@ -142,12 +368,6 @@ fn pretty_print_block(src: Code, indent: &str) -> String {
         }
         Code::Expr(line) => line,
         Code::Statement(line) => format!("{}{};", indent, line),
-        Code::Template(name, body) => format!(
-            "template <{} {}>\n{}",
-            "typename",
-            name,
-            pretty_print_block(*body, indent)
-        ),
         Code::Assignment(name, value) => format!(
             "{}const auto {} = {};",
             indent,
@@ -162,6 +382,9 @@ fn pretty_print_block(src: Code, indent: &str) -> String {
         } => {
             let cond = pretty_print_block(*condition, indent);
             let body = pretty_print_block(*then, indent);
+            if let Code::Empty = *then_else {
+                return format!("{indent}if({}) {}", cond, body, indent = indent);
+            }
             let then_else = pretty_print_block(*then_else, indent);
             format!(
                 "{indent}if({}) {} else {}",
@@ -171,6 +394,11 @@ fn pretty_print_block(src: Code, indent: &str) -> String {
                 indent = indent,
             )
         }
+        Code::While { condition, body } => {
+            let cond = pretty_print_block(*condition, indent);
+            let body = pretty_print_block(*body, indent);
+            format!("{indent}while({}) {}", cond, body, indent = indent)
+        }
         Code::Func {
             name,
             args,
@@ -178,6 +406,7 @@ fn pretty_print_block(src: Code, indent: &str) -> String {
             body: inner,
             lambda,
             call,
+            by_value,
         } => {
             let inner = if let Code::Block(_) = *inner {
                 *inner
@@ -197,7 +426,8 @@ fn pretty_print_block(src: Code, indent: &str) -> String {
                         new_indent = new_indent
                     )
                 };
-                let out = format!("[&]({}) {}", arg_str, body);
+                let capture = if by_value { "=" } else { "&" };
+                let out = format!("[{}]({}) {}", capture, arg_str, body);
                 if call {
                     format!("({})()", out)
                 } else {
@@ -221,6 +451,90 @@ type Res = Result<Code, TError>;
 type State = Table;
 type Out = (String, HashSet<String>);
 
+// Finds the expressions with handlers, i.e. `body ~ clauses`, in a program.
+fn handlers_in<'a>(expr: &'a Node, handlers: &mut Vec<&'a BinOp>) {
+    match expr {
+        Node::SymNode(_) | Node::ValNode(..) => {}
+        Node::ApplyNode(apply) => {
+            handlers_in(&apply.inner, handlers);
+            for arg in apply.args.iter() {
+                handlers_in(&arg.value, handlers);
+            }
+        }
+        Node::AbsNode(abs) => handlers_in(&abs.value, handlers),
+        Node::LetNode(def) => handlers_in(&def.value, handlers),
+        Node::UnOpNode(op) => handlers_in(&op.inner, handlers),
+        Node::BinOpNode(op) => {
+            if op.name == "~" {
+                handlers.push(op);
+            }
+            handlers_in(&op.left, handlers);
+            handlers_in(&op.right, handlers);
+        }
+    }
+}
+
+// The number and signature of a handled operation, e.g. `0, tako::unit(std::string)`.
+fn operation_type(op: &HandledOperation) -> String {
+    let arguments: Vec<&str> = op.arguments.iter().map(|(_, ty)| ty.as_str()).collect();
+    format!("{}, {}({})", op.id, op.result, arguments.join(", "))
+}
+
+fn is_closed(node: &Node) -> bool {
+    match node {
+        Node::SymNode(_) | Node::ApplyNode(_) | Node::AbsNode(_) | Node::LetNode(_) => false,
+        Node::ValNode(_, _) => true,
+        Node::UnOpNode(op) => is_closed(&op.inner),
+        Node::BinOpNode(op) => is_closed(&op.left) && is_closed(&op.right),
+    }
+}
+
+// Evaluates an expression that mentions no symbols.
+fn constant(storage: &mut DBStorage, node: &Node) -> Option<Val> {
+    if !is_closed(node) {
+        return None;
+    }
+    Interpreter::default()
+        .visit(storage, &mut vec![], node)
+        .ok()
+}
+
+// A comparison of a function's argument with a constant, e.g. `n > 0`.
+#[derive(Clone, Debug)]
+struct Bound {
+    argument: Path,
+    op: &'static str,
+    value: Val,
+}
+
+impl Bound {
+    // Whether every value of the argument that satisfies this bound also satisfies `other`.
+    fn implies(&self, other: &Bound) -> bool {
+        use std::cmp::Ordering::*;
+        let order = match (&self.value, &other.value) {
+            (Val::PrimVal(known), Val::PrimVal(wanted))
+                if std::mem::discriminant(known) == std::mem::discriminant(wanted) =>
+            {
+                known.cmp(wanted)
+            }
+            _ => return false,
+        };
+        self.argument == other.argument
+            && match (self.op, other.op) {
+                (">", ">" | ">=") | (">=", ">=") => order != Less,
+                (">=", ">") => order == Greater,
+                ("<", "<" | "<=") | ("<=", "<=") => order != Greater,
+                ("<=", "<") => order == Less,
+                ("==", ">") => order == Greater,
+                ("==", ">=") => order != Less,
+                ("==", "<") => order == Less,
+                ("==", "<=") => order != Greater,
+                ("==", "==") => order == Equal,
+                _ => false,
+            }
+    }
+}
+
 impl CodeGenerator {
     fn build_call1(&mut self, before: &str, inner: Code) -> Code {
         inner.with_expr(&|exp| Code::Expr(format!("{}({})", before, exp)))
@@ -232,6 +546,1382 @@ impl CodeGenerator {
             })
         })
     }
+    // Fixed width integers are brace initialised, so that literals keep their width.
+    fn build_number(&mut self, ty: &str, literal: String) -> Code {
+        self.includes.insert("#include <cstdint>".to_string());
+        Code::Expr(format!("{}{{{}}}", ty, literal))
+    }
+    fn build_conditional(&mut self, condition: Code, then: Code, then_else: Code) -> Code {
+        condition.with_expr(&|cond| {
+            then.clone().with_expr(&|then| {
+                then_else.clone().with_expr(&|then_else| {
+                    Code::Expr(format!("({} ? {} : {})", cond, then, then_else))
+                })
+            })
+        })
+    }
+
+    // Compiles the body of an `if` or a loop, which is only run for its effects.
+    fn visit_body(&mut self, storage: &mut DBStorage, state: &mut State, body: &Node) -> Res {
+        let body = match body {
+            // The body's scope is already a block in C++.
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" => inner,
+            body => body,
+        };
+        Ok(self.visit(storage, state, body)?.into_statements())
+    }
+
+    // Blocks with definitions get their own scope.
+    fn build_scope(&mut self, inner: Code) -> Code {
+        match inner {
+            Code::Block(statements) => {
+                let func = Code::Func {
+                    name: "".to_string(),
+                    args: vec![],
+                    return_type: "int".to_string(), // TODO
+                    body: Box::new(Code::Block(statements)),
+                    lambda: true,
+                    call: true,
+                    by_value: false,
+                };
+                Code::Expr(pretty_print_block(func, ""))
+            }
+            code => code,
+        }
+    }
+
+    fn build_requirement(
+        &mut self,
+        storage: &mut DBStorage,
+        expr: &BinOp,
+        condition: Code,
+        value: Code,
+    ) -> Res {
+        if storage.options.contracts != ContractMode::Unchecked {
+            return self.build_contract(
+                storage,
+                "requirement",
+                &expr.left,
+                condition,
+                value,
+                expr.get_info(),
+            );
+        }
+        // TODO: handle 'error' values more widly.
+        Ok(condition.with_expr(&|cond| {
+            value
+                .clone()
+                .with_expr(&|value| Code::Expr(format!("({} ? {} : throw 101)", cond, value)))
+        }))
+    }
+
+    // Finds the bound that a condition places on an argument, e.g. `0 < n` is `n > 0`.
+    fn bound(&self, storage: &mut DBStorage, condition: &Node) -> Option<Bound> {
+        let op = match condition {
+            Node::BinOpNode(op) => op,
+            _ => return None,
+        };
+        let (sym, value, flipped) = match (&*op.left, &*op.right) {
+            (Node::SymNode(sym), value) => (sym, value, false),
+            (value, Node::SymNode(sym)) => (sym, value, true),
+            _ => return None,
+        };
+        let op = match (op.name.as_str(), flipped) {
+            (">", false) | ("<", true) => ">",
+            (">=", false) | ("<=", true) => ">=",
+            ("<", false) | (">", true) => "<",
+            ("<=", false) | (">=", true) => "<=",
+            ("==", _) => "==",
+            _ => return None,
+        };
+        let argument = sym.get_info().defined_at?;
+        if !self.arguments.contains(&argument) {
+            return None;
+        }
+        Some(Bound {
+            argument,
+            op,
+            value: constant(storage, value)?,
+        })
+    }
+
+    // A contract is proven if it mentions no symbols and holds, or if it is implied by a
+    // requirement on an argument that has already been checked (e.g. `n > 0` after `n > 2`).
+    // Other contracts aren't proven, even if they always hold, so they are still checked.
+    fn is_proven(&self, storage: &mut DBStorage, condition: &Node) -> bool {
+        if constant(storage, condition) == Some(Val::PrimVal(Prim::Bool(true))) {
+            return true;
+        }
+        match self.bound(storage, condition) {
+            Some(bound) => self.facts.iter().any(|fact| fact.implies(&bound)),
+            None => false,
+        }
+    }
+
+    // Generates the code guarded by a requirement, where the requirement is known to hold.
+    fn with_requirement(
+        &mut self,
+        storage: &mut DBStorage,
+        condition: &Node,
+        f: impl FnOnce(&mut Self, &mut DBStorage) -> Res,
+    ) -> Res {
+        let fact = self.bound(storage, condition);
+        let known = fact.is_some();
+        self.facts.extend(fact);
+        let res = f(self, storage);
+        if known {
+            self.facts.pop();
+        }
+        res
+    }
+
+    // Guards a value with a condition that must hold, reporting where it failed.
+    fn build_contract(
+        &mut self,
+        storage: &mut DBStorage,
+        kind: &str,
+        condition_node: &Node,
+        condition: Code,
+        value: Code,
+        info: Info,
+    ) -> Result<Code, TError> {
+        if storage.options.contracts == ContractMode::Release
+            && self.is_proven(storage, condition_node)
+        {
+            return Ok(value);
+        }
+        let text = PrettyPrint { names_only: true }.visit_root(storage, condition_node)?;
+        let at = match info.loc {
+            Some(loc) => format!("{:?}", loc),
+            None => "an unknown location".to_string(),
+        };
+        self.includes.insert(CONTRACT_FAILURE.to_string());
+        let failure = format!("tako_contract_failure({:?}, {:?}, {:?})", kind, at, text);
+        Ok(condition.with_expr(&|cond| {
+            value.clone().with_expr(&|value| {
+                Code::Expr(format!("({} ? {} : ({}, {}))", cond, value, failure, value))
+            })
+        }))
+    }
+
+    fn visit_postcondition(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        expr: &BinOp,
+    ) -> Res {
+        let value = self.visit(storage, state, &expr.left)?;
+        if storage.options.contracts == ContractMode::Unchecked {
+            return Ok(value);
+        }
+        let abs = match &*expr.right {
+            Node::AbsNode(abs) => abs,
+            right => {
+                return Err(TError::InternalError(
+                    format!("Expected a postcondition over `it`, found {}", right),
+                    expr.get_info(),
+                ))
+            }
+        };
+        let name = make_name(
+            abs.get_info()
+                .defined_at
+                .expect("Could not find definition for postcondition"),
+        );
+        let condition = self.visit(storage, state, &abs.value)?;
+        let checked = self.build_contract(
+            storage,
+            "postcondition",
+            &abs.value,
+            condition,
+            Code::Expr(name.clone()),
+            expr.get_info(),
+        )?;
+        let check = pretty_print_block(checked, "");
+        Ok(value.with_expr(&|value| {
+            Code::Expr(format!(
+                "[&](const auto {}) {{ return {}; }}({})",
+                name, check, value
+            ))
+        }))
+    }
+
+    fn named_type(&mut self, name: &str) -> Option<String> {
+        match name {
+            "I32" => Some("int".to_string()),
+            "I64" | "U32" | "U64" => {
+                self.includes.insert("#include <cstdint>".to_string());
+                let ty = match name {
+                    "I64" => "int64_t",
+                    "U32" => "uint32_t",
+                    _ => "uint64_t",
+                };
+                Some(ty.to_string())
+            }
+            "U8" | "F64" => {
+                self.includes.insert(FIXED_NUMBERS.to_string());
+                let ty = if name == "U8" {
+                    "tako::u8"
+                } else {
+                    "tako::f64"
+                };
+                Some(ty.to_string())
+            }
+            "Bit" => Some("bool".to_string()),
+            "String" => {
+                self.includes.insert("#include <string>".to_string());
+                Some("std::string".to_string())
+            }
+            name => self.types.get(name).map(|(ty, _)| ty.clone()),
+        }
+    }
+
+    fn field_type(&mut self, ty: &Option<Box<Node>>, info: Info) -> Result<String, TError> {
+        if let Some(Node::SymNode(sym)) = ty.as_deref() {
+            if let Some(ty) = self.named_type(&sym.name) {
+                return Ok(ty);
+            }
+        }
+        let ty = match ty {
+            Some(ty) => format!("fields of type {}", ty),
+            None => "fields without types".to_string(),
+        };
+        Err(TError::UnsupportedInCpp(ty, info))
+    }
+
+    // Finds the C++ type of an argument or result of an interface operation.
+    fn member_type(&mut self, ty: &Val, self_ty: &str, info: &Info) -> Result<String, TError> {
+        match ty {
+            Val::Variable(name) if name == "Self" => Ok(self_ty.to_string()),
+            ty => self.named_type(&ty.to_string()).ok_or_else(|| {
+                TError::UnsupportedInCpp(format!("operations on {}", ty), info.clone())
+            }),
+        }
+    }
+
+    // Finds the interfaces declared and implemented by a program, before the data types that
+    // implement them are lowered.
+    fn find_interfaces(&mut self, expr: &Node) -> Result<(), TError> {
+        match expr {
+            Node::BinOpNode(BinOp {
+                name, left, right, ..
+            }) if name == ";" || name == "," => {
+                self.find_interfaces(left)?;
+                self.find_interfaces(right)
+            }
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "interface" => {
+                let decl = inner.as_let()?;
+                let annotation = |ty: &Option<Box<Node>>| match ty.as_deref() {
+                    Some(Node::SymNode(sym)) => variable(&sym.name),
+                    _ => unit_type(),
+                };
+                let mut operations = vec![];
+                for sig in signatures(&decl.value)? {
+                    let arguments = sig
+                        .args
+                        .iter()
+                        .map(|arg| (arg.name.clone(), annotation(&arg.info.ty)))
+                        .collect();
+                    let ty = Val::Function {
+                        intros: dict!(),
+                        arguments: Box::new(Val::Struct(arguments)),
+                        results: Box::new(annotation(&sig.info.ty)),
+                    };
+                    operations.push((sig.name, ty));
+                }
+                self.interfaces.insert(decl.name, operations);
+                Ok(())
+            }
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "impl" => {
+                let decl = inner.as_let()?;
+                let imp = implementation(&decl)?;
+                let interfaces = self.implemented.entry(imp.ty.to_string()).or_default();
+                interfaces.push(imp.interface.to_string());
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Declares the operations of the interfaces that a data type implements, as members of its
+    // struct. Local structs can't use the lambdas in `main`, so each operation is stored in a
+    // static `std::function` that the implementation sets.
+    fn interface_members(
+        &mut self,
+        name: &str,
+        ty: &str,
+        info: &Info,
+    ) -> Result<Vec<String>, TError> {
+        let mut members = vec![];
+        for interface in self.implemented.get(name).cloned().unwrap_or_default() {
+            let operations = match self.interfaces.get(&interface).cloned() {
+                Some(operations) => operations,
+                None => get_interface(&interface).ok_or_else(|| {
+                    TError::UnknownSymbol(interface.clone(), info.clone(), "interfaces".to_string())
+                })?,
+            };
+            self.includes.insert("#include <functional>".to_string());
+            for (op, op_ty) in operations.iter() {
+                let (arguments, results) = match op_ty {
+                    Val::Function {
+                        arguments, results, ..
+                    } => (arguments.clone().into_struct(), &**results),
+                    _ => (vec![], op_ty),
+                };
+                let mut args = vec![];
+                for (_, arg) in arguments.iter() {
+                    args.push(self.member_type(arg, ty, info)?);
+                }
+                let func = format!(
+                    "std::function<{}({})>",
+                    self.member_type(results, ty, info)?,
+                    args.join(", ")
+                );
+                members.push(format!(
+                    "static {}& tako_{}() {{ static {} impl; return impl; }}",
+                    func, op, func
+                ));
+            }
+            match interface.as_str() {
+                "Number" => {
+                    for (op, method) in [("+", "add"), ("-", "sub"), ("*", "mul"), ("/", "div")] {
+                        members.push(format!(
+                            "{} operator{}(const {}& right) const {{ return tako_{}()(*this, right); }}",
+                            ty, op, ty, method
+                        ));
+                    }
+                }
+                // Converting to a string prefers the `to_string` member, see `TO_STRING`.
+                "Display" => members.push(
+                    "std::string to_string() const { return tako_to_string()(*this); }".to_string(),
+                ),
+                _ => {}
+            }
+        }
+        Ok(members)
+    }
+
+    // Lowers the operations of an interface to lambdas that call the implementation for the
+    // type of their first `Self` argument.
+    fn visit_interface(&mut self, storage: &mut DBStorage, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let mut path = decl
+            .get_info()
+            .defined_at
+            .expect("Could not find definition for interface");
+        path.pop();
+        let mut code = vec![];
+        for sig in signatures(&decl.value)? {
+            let mut op_path = path.clone();
+            op_path.push(Symbol::new(&sig.name));
+            if storage.find_symbol_uses(op_path.clone())?.is_empty() {
+                continue;
+            }
+            let receiver = sig.args.iter().find(|arg| {
+                matches!(arg.info.ty.as_deref(), Some(Node::SymNode(sym)) if sym.name == "Self")
+            });
+            let receiver = match receiver {
+                Some(receiver) => format!("tako_{}", receiver.name),
+                None => {
+                    return Err(TError::UnsupportedInCpp(
+                        format!("operations without a `Self` argument, like `{}`", sig.name),
+                        sig.info,
+                    ))
+                }
+            };
+            let names: Vec<String> = sig
+                .args
+                .iter()
+                .map(|arg| format!("tako_{}", arg.name))
+                .collect();
+            self.includes.insert("#include <type_traits>".to_string());
+            let name = make_name(op_path);
+            let body = Code::Expr(format!(
+                "std::decay_t<decltype({})>::tako_{}()({})",
+                receiver,
+                sig.name,
+                names.join(", ")
+            ));
+            code.push(Code::Assignment(
+                name.clone(),
+                Box::new(Code::Func {
+                    name,
+                    args: names
+                        .iter()
+                        .map(|arg| format!("const auto {}", arg))
+                        .collect(),
+                    return_type: "int".to_string(), // TODO
+                    body: Box::new(body),
+                    lambda: true,
+                    call: false,
+                    by_value: false,
+                }),
+            ));
+        }
+        if code.is_empty() {
+            return Ok(Code::Empty);
+        }
+        Ok(Code::Block(code))
+    }
+
+    // Build the 'struct' of args
+    fn visit_args(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        exprs: &[Let],
+    ) -> Result<Vec<String>, TError> {
+        let mut args = vec![];
+        for arg in exprs.iter() {
+            // TODO: Include lambda head in values
+            let val = self.visit_let(storage, state, arg)?;
+            match val {
+                Code::Assignment(_, val) => args.push(pretty_print_block(*val, "")),
+                val => args.push(pretty_print_block(val, "")),
+            };
+        }
+        Ok(args)
+    }
+
+    // Lowers `value.method(args)`, which calls the method with the value as its `it` argument.
+    fn visit_method_call(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        value: &Node,
+        call: &Apply,
+    ) -> Res {
+        let value = self.visit(storage, state, value)?;
+        let args = self.visit_args(storage, state, &call.args)?;
+        let method = self.visit(storage, state, &call.inner)?;
+        Ok(value.with_expr(&|value| {
+            method.clone().with_expr(&|method| {
+                let mut with_value = vec![value.clone()];
+                with_value.extend(args.iter().cloned());
+                Code::Expr(format!("{}({})", method, with_value.join(", ")))
+            })
+        }))
+    }
+
+    // Lowers `value?.method()`, which only calls the method if the value isn't `()`.
+    fn visit_optional_access(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        expr: &BinOp,
+    ) -> Res {
+        let call = match &*expr.right {
+            Node::ApplyNode(call) => call,
+            _ => {
+                return Err(TError::UnsupportedInCpp(
+                    "field access".to_string(),
+                    expr.get_info(),
+                ))
+            }
+        };
+        let left = self.visit(storage, state, &expr.left)?;
+        // The value is passed as the method's `it` argument.
+        let mut args = vec!["value".to_string()];
+        args.extend(self.visit_args(storage, state, &call.args)?);
+        let call = self
+            .visit(storage, state, &call.inner)?
+            .with_expr(&|method| Code::Expr(format!("{}({})", method, args.join(", "))));
+        let and_then = storage
+            .get_extern("?.".to_string())?
+            .expect("Missing ?. operator");
+        self.includes.insert(and_then.cpp.includes.clone());
+        Ok(left.with_expr(&|left| {
+            call.clone().with_expr(&|call| {
+                Code::Expr(format!(
+                    "{}({}, [&](const auto value) {{ return {}; }})",
+                    and_then.cpp.code, left, call
+                ))
+            })
+        }))
+    }
+
+    // Lowers the methods of an implementation, storing them in the members of the type's struct.
+    fn visit_impl(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let imp = implementation(&decl)?;
+        let ty = match self.types.get(imp.ty) {
+            Some((ty, _)) => ty.clone(),
+            None => {
+                return Err(TError::UnsupportedInCpp(
+                    format!("implementations for {}", imp.ty),
+                    decl.get_info(),
+                ))
+            }
+        };
+        let mut code = vec![];
+        for method in imp.methods.iter() {
+            let name = make_name(
+                method
+                    .get_info()
+                    .defined_at
+                    .expect("Could not find definition for method"),
+            );
+            let mut args = vec![];
+            for arg in method.args.iter().flatten() {
+                let path = arg
+                    .get_info()
+                    .defined_at
+                    .expect("Could not find definition for method arg");
+                args.push(format!("const auto {}", make_name(path)));
+            }
+            let body = self.visit(storage, state, &method.value)?;
+            code.push(Code::Assignment(
+                name.clone(),
+                Box::new(Code::Func {
+                    name: name.clone(),
+                    args,
+                    return_type: "int".to_string(), // TODO
+                    body: Box::new(body),
+                    lambda: true,
+                    call: false,
+                    by_value: false,
+                }),
+            ));
+            code.push(Code::Statement(format!(
+                "{}::tako_{}() = {}",
+                ty, method.name, name
+            )));
+        }
+        if code.is_empty() {
+            return Ok(Code::Empty);
+        }
+        Ok(Code::Block(code))
+    }
+
+    // C++14 can't deduce a vector's element type from a braced list, so defer to a helper.
+    fn visit_list(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        if elements(&expr.inner).is_empty() {
+            return Err(TError::UnsupportedInCpp(
+                "empty list literals".to_string(),
+                expr.get_info(),
+            ));
+        }
+        self.includes.insert(CONTAINERS.to_string());
+        let mut code: Option<Code> = None;
+        for element in elements(&expr.inner) {
+            let element = self.visit(storage, state, element)?;
+            code = Some(match code {
+                None => element,
+                Some(code) => code.with_expr(&|left| {
+                    element
+                        .clone()
+                        .with_expr(&|right| Code::Expr(format!("{}, {}", left, right)))
+                }),
+            });
+        }
+        Ok(self.build_call1("tako::list", code.unwrap_or(Code::Empty)))
+    }
+
+    // Lowers a tuple to a `std::tuple`, or a tuple of named fields to an (unnamed) struct.
+    fn visit_tuple(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let fields = tuple_fields(&expr.inner)?;
+        let named = fields
+            .iter()
+            .filter(|(_, field)| matches!(field, Node::LetNode(_)))
+            .count();
+        if named != 0 && named != fields.len() {
+            return Err(TError::UnsupportedInCpp(
+                "tuples with both positional and named fields".to_string(),
+                expr.get_info(),
+            ));
+        }
+        let mut code: Option<Code> = None;
+        for (name, field) in fields.iter() {
+            let field = match field {
+                Node::LetNode(def) if def.args.is_none() => &*def.value,
+                Node::LetNode(def) => {
+                    return Err(TError::UnsupportedInCpp(
+                        format!("functions as fields, e.g. `{}`", name),
+                        def.get_info(),
+                    ))
+                }
+                field => field,
+            };
+            let field = self.visit(storage, state, field)?;
+            code = Some(match code {
+                None => field,
+                Some(code) => code.with_expr(&|left| {
+                    field
+                        .clone()
+                        .with_expr(&|right| Code::Expr(format!("{}, {}", left, right)))
+                }),
+            });
+        }
+        let code = code.unwrap_or(Code::Empty);
+        // Tuples and records write their values like containers do.
+        self.includes.insert(CONTAINERS.to_string());
+        self.includes.insert(PRODUCTS.to_string());
+        if named == 0 {
+            return Ok(self.build_call1("std::make_tuple", code));
+        }
+        // The values are passed to a lambda, so that the struct's members can have their types.
+        let params: Vec<String> = fields
+            .iter()
+            .map(|(name, _)| format!("auto {}_", name))
+            .collect();
+        let members: Vec<String> = fields
+            .iter()
+            .map(|(name, _)| format!("decltype({0}_) {0};", name))
+            .collect();
+        let values: Vec<String> = fields
+            .iter()
+            .map(|(name, _)| format!("{}_", name))
+            .collect();
+        let shown: Vec<String> = fields
+            .iter()
+            .map(|(name, _)| format!("\"{0}\", {0}", name))
+            .collect();
+        let func = format!(
+            "[&]({}) {{ struct {{ {} std::string to_string() const {{ return tako::record({}); }} }} fields{{{}}}; return fields; }}",
+            params.join(", "),
+            members.join(" "),
+            shown.join(", "),
+            values.join(", ")
+        );
+        Ok(self.build_call1(&func, code))
+    }
+
+    // Lowers a data type to a struct holding the tag and the fields of every constructor.
+    fn visit_type(&mut self, storage: &mut DBStorage, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let mut path = decl
+            .get_info()
+            .defined_at
+            .expect("Could not find definition for type");
+        let ty = make_name(path.clone());
+        let type_path = path.clone();
+        path.pop();
+        let ctors = constructors(&decl.value)?;
+        let tag_bits = std::cmp::max(1, num_bits(ctors.len()));
+        let mut members = vec![format!("unsigned int tag : {}", tag_bits)];
+        // Values are equal when they have the same constructor and its fields are equal.
+        let mut equalities = vec!["tag == other.tag".to_string()];
+        for (tag, ctor) in ctors.iter().enumerate() {
+            let mut fields = vec![];
+            let mut boxed = HashSet::new();
+            let mut field_equalities = vec![];
+            for field in ctor.fields.iter() {
+                let member = format!("{}_{}", ctor.name, field.name);
+                let field_ty = match field.info.ty.as_deref() {
+                    // The struct can't contain itself, so it holds a pointer to the value instead.
+                    Some(Node::SymNode(sym)) if sym.name == decl.name => {
+                        self.includes.insert("#include <memory>".to_string());
+                        boxed.insert(member.clone());
+                        format!("std::shared_ptr<const {}>", ty)
+                    }
+                    _ => self.field_type(&field.info.ty, field.get_info())?,
+                };
+                field_equalities.push(if boxed.contains(&member) {
+                    format!("*{} == *other.{}", member, member)
+                } else {
+                    format!("{} == other.{}", member, member)
+                });
+                members.push(format!("{} {}", field_ty, member));
+                fields.push((field.name.clone(), member));
+            }
+            if !field_equalities.is_empty() {
+                equalities.push(format!(
+                    "(tag != {} || ({}))",
+                    tag,
+                    field_equalities.join(" && ")
+                ));
+            }
+            self.constructors.insert(
+                ctor.name.clone(),
+                CppConstructor {
+                    ty: decl.name.clone(),
+                    tag,
+                    members: fields,
+                    boxed,
+                },
+            );
+        }
+        members.extend(self.interface_members(&decl.name, &ty, &decl.info)?);
+        let names = ctors.iter().map(|ctor| ctor.name.clone()).collect();
+        self.types.insert(decl.name.clone(), (ty.clone(), names));
+        let operators = format!(
+            "bool operator==(const {ty}& other) const {{ return {}; }} \
+             bool operator!=(const {ty}& other) const {{ return !(*this == other); }}",
+            equalities.join(" && "),
+            ty = ty
+        );
+        let mut code = vec![Code::Statement(format!(
+            "struct {} {{ {}; {} }}",
+            ty,
+            members.join("; "),
+            operators
+        ))];
+        for ctor in ctors.iter() {
+            let mut ctor_path = path.clone();
+            ctor_path.push(Symbol::new(&ctor.name));
+            // Matches only use the tags, so constructors that are only named by their declaration
+            // aren't needed.
+            let uses = storage.find_symbol_uses(ctor_path.clone())?;
+            if uses.iter().all(|used| used.starts_with(&type_path)) {
+                continue;
+            }
+            let name = make_name(ctor_path);
+            let layout = &self.constructors[&ctor.name];
+            let mut values = vec![layout.tag.to_string()];
+            let mut args = vec![];
+            for other in ctors.iter() {
+                for field in other.fields.iter() {
+                    if other.name == ctor.name {
+                        let arg = format!("{}_{}", name, field.name);
+                        args.push(format!("const auto {}", arg));
+                        let member = format!("{}_{}", ctor.name, field.name);
+                        if layout.boxed.contains(&member) {
+                            values.push(format!("std::make_shared<const {}>({})", ty, arg));
+                        } else {
+                            values.push(arg);
+                        }
+                    } else {
+                        values.push("{}".to_string());
+                    }
+                }
+            }
+            let value = Code::Expr(format!("{}{{{}}}", ty, values.join(", ")));
+            let value = if ctor.fields.is_empty() {
+                value
+            } else {
+                Code::Func {
+                    name: name.clone(),
+                    args,
+                    return_type: ty.clone(),
+                    body: Box::new(value),
+                    lambda: true,
+                    call: false,
+                    by_value: false,
+                }
+            };
+            code.push(Code::Assignment(name, Box::new(value)));
+        }
+        Ok(Code::Block(code))
+    }
+
+    // Modules are found at compile time, so the result is a constant.
+    fn visit_lib_exists(&mut self, storage: &mut DBStorage, expr: &Apply) -> Res {
+        let name = expr.args.iter().find(|arg| arg.name == "it");
+        match name.map(|arg| &*arg.value) {
+            Some(Node::ValNode(Val::PrimVal(Prim::Str(name)), _)) => {
+                Ok(Code::Expr(storage.find_module(name).is_some().to_string()))
+            }
+            _ => Err(TError::UnsupportedInCpp(
+                "`lib::exists` of a module name that is not a literal".to_string(),
+                expr.get_info(),
+            )),
+        }
+    }
+
+    // Lowers a match to a chain of conditionals on the tag of the value.
+    fn visit_match(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
+        let info = expr.get_info();
+        let (values, clauses): (Vec<&Let>, Vec<&Let>) =
+            expr.args.iter().partition(|arg| arg.name == "it");
+        let value = match values.as_slice() {
+            [value] => self.visit(storage, state, &value.value)?,
+            _ => {
+                return Err(TError::InternalError(
+                    "Expected a value to match on".to_string(),
+                    info,
+                ))
+            }
+        };
+        let mut branches: Vec<(CppConstructor, String)> = vec![];
+        // A `_` clause matches the constructors that don't have clauses, so it is the last case.
+        let mut wildcard = None;
+        for clause in clauses.iter() {
+            let ctor = match self.constructors.get(&clause.name) {
+                Some(ctor) => Some(ctor.clone()),
+                None if clause.name == "_" => None,
+                None => {
+                    return Err(TError::UnknownSymbol(
+                        clause.name.clone(),
+                        clause.get_info(),
+                        "constructors".to_string(),
+                    ))
+                }
+            };
+            if let (Some((first, _)), Some(ctor)) = (branches.first(), &ctor) {
+                if first.ty != ctor.ty {
+                    return Err(TError::TypeMismatch(
+                        format!("`{}` is not a constructor of {}", clause.name, first.ty),
+                        Box::new(unit_type()),
+                        clause.get_info(),
+                    ));
+                }
+            }
+            let (fields, boxed) = ctor
+                .as_ref()
+                .map(|ctor| (ctor.members.clone(), ctor.boxed.clone()))
+                .unwrap_or_default();
+            let mut args = vec![];
+            let mut members = vec![];
+            // Fields are bound by position, and `_` skips one.
+            let clause_args: Vec<&Let> = clause.args.iter().flatten().collect();
+            if !clause_args.is_empty() && clause_args.len() != fields.len() {
+                return Err(TError::TypeMismatch(
+                    format!(
+                        "`{}` has {} fields, but its case binds {}",
+                        clause.name,
+                        fields.len(),
+                        clause_args.len()
+                    ),
+                    Box::new(unit_type()),
+                    clause.get_info(),
+                ));
+            }
+            for (arg, (_, member)) in clause_args.into_iter().zip(fields.iter()) {
+                if arg.name == "_" {
+                    continue;
+                }
+                let path = arg
+                    .get_info()
+                    .defined_at
+                    .expect("Could not find definition for match arg");
+                args.push(format!("const auto {}", make_name(path)));
+                if boxed.contains(member) {
+                    members.push(format!("*tako_match.{}", member));
+                } else {
+                    members.push(format!("tako_match.{}", member));
+                }
+            }
+            let body = self.visit(storage, state, &clause.value)?;
+            let branch = match body {
+                Code::Expr(body) if args.is_empty() => body,
+                Code::Expr(body) => format!(
+                    "[&]({}) {{ return {}; }}({})",
+                    args.join(", "),
+                    body,
+                    members.join(", ")
+                ),
+                body => {
+                    let func = Code::Func {
+                        name: "".to_string(),
+                        args,
+                        return_type: "int".to_string(), // TODO
+                        body: Box::new(body),
+                        lambda: true,
+                        call: false,
+                        by_value: false,
+                    };
+                    format!("({})({})", pretty_print_block(func, ""), members.join(", "))
+                }
+            };
+            match ctor {
+                Some(ctor) => branches.push((ctor, branch)),
+                None => wildcard = Some(branch),
+            }
+        }
+        let ty = match (branches.first(), &wildcard) {
+            (Some((ctor, _)), _) => ctor.ty.clone(),
+            (None, Some(wildcard)) => {
+                return Ok(value
+                    .with_expr(&|value| Code::Expr(format!("((void){}, {})", value, wildcard))))
+            }
+            (None, None) => return Err(TError::NonExhaustiveMatch("every case".to_string(), info)),
+        };
+        let (_, names) = self.types[&ty].clone();
+        let missing: Vec<String> = names
+            .iter()
+            .filter(|name| !clauses.iter().any(|clause| clause.name == **name))
+            .map(|name| format!("`{}`", name))
+            .collect();
+        let mut cases = match wildcard {
+            Some(wildcard) => wildcard,
+            None if missing.is_empty() => branches.pop().expect("Match must have a clause").1,
+            None => return Err(TError::NonExhaustiveMatch(missing.join(", "), info)),
+        };
+        while let Some((ctor, branch)) = branches.pop() {
+            cases = format!("(tako_match.tag == {} ? {} : {})", ctor.tag, branch, cases);
+        }
+        Ok(value.with_expr(&|value| {
+            Code::Expr(format!(
+                "[&](const auto tako_match) {{ return {}; }}({})",
+                cases, value
+            ))
+        }))
+    }
+
+    // Finds the operations that the handlers in a program handle, so that calls to them can run
+    // the handlers' clauses instead.
+    fn find_handlers(
+        &mut self,
+        storage: &mut DBStorage,
+        module: &Path,
+        table: &Table,
+        expr: &Node,
+    ) -> Result<(), TError> {
+        let mut handlers = vec![];
+        handlers_in(expr, &mut handlers);
+        let mut definitions = vec![];
+        definitions_in(expr, &mut definitions);
+        for handler in handlers {
+            let mut clauses = vec![];
+            definitions_in(&handler.right, &mut clauses);
+            for clause in clauses {
+                let path = clause
+                    .get_info()
+                    .defined_at
+                    .expect("Could not find definition for clause");
+                if !self.handled.contains_key(&clause.name) {
+                    let op = self.handled_operation(
+                        storage,
+                        module,
+                        table,
+                        &definitions,
+                        clause,
+                    )?;
+                    self.handled.insert(clause.name.clone(), op);
+                }
+                self.clauses.insert(path);
+            }
+        }
+        Ok(())
+    }
+
+    // Finds the operation that a clause handles, i.e. the definition of its name outside of it.
+    // Clauses of handlers at the top level share their paths with the program's definitions, so
+    // those are found amongst the program's `definitions`.
+    fn handled_operation(
+        &mut self,
+        storage: &mut DBStorage,
+        module: &Path,
+        table: &Table,
+        definitions: &[&Let],
+        clause: &Let,
+    ) -> Result<HandledOperation, TError> {
+        let info = clause.get_info();
+        let path = info
+            .defined_at
+            .clone()
+            .expect("Could not find definition for clause");
+        let mut scope = path[..path.len() - 1].to_vec();
+        let found = loop {
+            let mut search = scope.clone();
+            search.push(Symbol::new(&clause.name));
+            let defined = if search == path {
+                definitions
+                    .iter()
+                    .any(|def| def.get_info().defined_at.as_ref() == Some(&search))
+            } else {
+                table.find(&search).is_some()
+            };
+            if defined {
+                break Some(search);
+            }
+            // Anything outside of the module is an extern.
+            if scope.len() <= module.len() {
+                break None;
+            }
+            scope.pop();
+        };
+        let (code, ty) = match found {
+            // Only the types of the program's top level definitions are kept.
+            Some(found) if found.len() == module.len() + 1 => {
+                let ty = self.definition_type(storage, &found)?;
+                (make_name(found), ty)
+            }
+            Some(_) => {
+                return Err(TError::UnsupportedInCpp(
+                    format!(
+                        "handlers for operations defined inside functions, like `{}`",
+                        clause.name
+                    ),
+                    info,
+                ))
+            }
+            None => {
+                let ext = storage.get_extern(clause.name.clone())?.ok_or_else(|| {
+                    TError::UnknownSymbol(clause.name.clone(), info.clone(), "handlers".to_string())
+                })?;
+                self.includes.insert(ext.cpp.includes);
+                self.flags.extend(ext.cpp.flags);
+                let ty = match ext.ty {
+                    Node::ValNode(ty, _) => ty,
+                    ty => eval_type(storage, &ty)?,
+                };
+                (ext.cpp.code, ty)
+            }
+        };
+        let (arguments, result) = match ty {
+            Val::Function {
+                arguments, results, ..
+            } => (*arguments, *results),
+            ty => {
+                return Err(TError::UnsupportedInCpp(
+                    format!(
+                        "handlers for `{}`, which is a {} and not a function",
+                        clause.name, ty
+                    ),
+                    info,
+                ))
+            }
+        };
+        let result = match result {
+            Val::WithRequirement(result, _) => *result,
+            result => result,
+        };
+        let fields = match arguments {
+            Val::Struct(fields) => fields,
+            _ => vec![],
+        };
+        let mut cpp_arguments = vec![];
+        for (name, ty) in fields.iter() {
+            cpp_arguments.push((name.clone(), self.handled_type(ty, clause, &info)?));
+        }
+        Ok(HandledOperation {
+            id: self.handled.len(),
+            code,
+            arguments: cpp_arguments,
+            result: self.handled_type(&result, clause, &info)?,
+        })
+    }
+
+    // The type of one of the program's top level definitions.
+    fn definition_type(&mut self, storage: &mut DBStorage, path: PathRef) -> Result<Val, TError> {
+        if self.graph.is_none() {
+            let module = path[..path.len() - 1].to_vec();
+            self.graph = Some(storage.build_type_graph(module)?);
+        }
+        self.graph
+            .as_ref()
+            .expect("Type graph missing")
+            .get_type(path)
+    }
+
+    // The C++ type of a recursive function's result, which has to be given rather than deduced.
+    fn recursive_result_type(
+        &mut self,
+        storage: &mut DBStorage,
+        path: PathRef,
+        info: &Info,
+    ) -> Result<String, TError> {
+        let unsupported = || {
+            TError::UnsupportedInCpp(
+                "recursive functions without a named result type".to_string(),
+                info.clone(),
+            )
+        };
+        if self.function_depth > 1 {
+            return Err(TError::UnsupportedInCpp(
+                "recursive functions inside other functions".to_string(),
+                info.clone(),
+            ));
+        }
+        match self.definition_type(storage, path)? {
+            Val::Function { results, .. } => self
+                .named_type(&results.to_string())
+                .ok_or_else(unsupported),
+            _ => Err(unsupported()),
+        }
+    }
+
+    // Lowers an empty list that is a top level definition, e.g. `xs = []`, to a vector of the
+    // element type that was inferred for it.
+    fn empty_list(&mut self, storage: &mut DBStorage, path: PathRef) -> Option<Code> {
+        let element = match self.definition_type(storage, path).ok()? {
+            Val::App { inner, arguments } if matches!(&*inner, Val::Variable(name) if name == "List") => {
+                match *arguments {
+                    Val::Struct(mut fields) if fields.len() == 1 => fields.remove(0).1,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        let element = match element {
+            // Nothing is put in the list, so any element type will do.
+            Val::Variable(_) => "int".to_string(),
+            element => self.named_type(&element.to_string())?,
+        };
+        self.includes.insert(CONTAINERS.to_string());
+        Some(Code::Expr(format!("std::vector<{}>{{}}", element)))
+    }
+
+    // The C++ type of an argument or result of a handled operation.
+    fn handled_type(&mut self, ty: &Val, clause: &Let, info: &Info) -> Result<String, TError> {
+        if *ty == unit_type() {
+            return Ok("tako::unit".to_string());
+        }
+        self.named_type(&ty.to_string()).ok_or_else(|| {
+            TError::UnsupportedInCpp(
+                format!("handlers for `{}`, as it uses {}s", clause.name, ty),
+                info.clone(),
+            )
+        })
+    }
+
+    // Calls an operation that handlers can replace, see `HANDLERS`.
+    fn visit_operation(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        expr: &Apply,
+        op: &HandledOperation,
+    ) -> Res {
+        let args = self.visit_args(storage, state, &expr.args)?;
+        // Inside its clause, an operation refers to the operation that the clause handles.
+        let inner = match &*expr.inner {
+            Node::SymNode(sym)
+                if self
+                    .clauses
+                    .contains(&sym.get_info().defined_at.unwrap_or_default()) =>
+            {
+                op.code.clone()
+            }
+            inner => match self.visit(storage, state, inner)? {
+                Code::Expr(inner) => inner,
+                _ => panic!("Don't know how to apply arguments to a block"),
+            },
+        };
+        let mut params = vec![];
+        let mut values = vec![];
+        for (name, ty) in op.arguments.iter() {
+            params.push(format!("const {} tako_{}", ty, name));
+            match expr.args.iter().position(|arg| arg.name == *name) {
+                Some(at) => values.push(args[at].clone()),
+                None => {
+                    return Err(TError::UnsupportedInCpp(
+                        format!("calls to handled operations without `{}`", name),
+                        expr.get_info(),
+                    ))
+                }
+            }
+        }
+        let passed: Vec<String> = expr
+            .args
+            .iter()
+            .map(|arg| format!("tako_{}", arg.name))
+            .collect();
+        values.insert(
+            0,
+            format!(
+                "[&]({}) -> decltype(auto) {{ return {}({}); }}",
+                params.join(", "),
+                inner,
+                passed.join(", ")
+            ),
+        );
+        Ok(Code::Expr(format!(
+            "tako::operation<{}>::perform({})",
+            operation_type(op),
+            values.join(", ")
+        )))
+    }
+
+    // Lowers `body ~ (op(args) = clause, ...)`, which installs a clause for each operation while
+    // the body runs. The clauses that resumed then continue with the body's value.
+    fn visit_handle(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        self.includes.insert(HANDLERS.to_string());
+        let body = self.visit(storage, state, &expr.left)?;
+        let mut clauses = vec![];
+        definitions_in(&expr.right, &mut clauses);
+        let mut installs = vec![];
+        for (index, clause) in clauses.into_iter().enumerate() {
+            let op = self.handled[&clause.name].clone();
+            let code = self.visit_clause(storage, state, clause, &op)?;
+            installs.push(Code::Statement(format!(
+                "const tako::operation<{}>::install tako_clause_{}(tako_handler.id, {})",
+                operation_type(&op),
+                index,
+                pretty_print_block(code, "")
+            )));
+        }
+        installs.push(Code::Expr("tako_handler.run(tako_handled)".to_string()));
+        let lambda = |body: Code, call: bool| Code::Func {
+            name: "".to_string(),
+            args: vec![],
+            return_type: "int".to_string(), // TODO
+            body: Box::new(body),
+            lambda: true,
+            call,
+            by_value: false,
+        };
+        let handle = Code::Block(vec![
+            Code::Assignment("tako_handled".to_string(), Box::new(lambda(body, false))),
+            Code::Statement(
+                "tako::handler<std::decay_t<decltype(tako_handled())>> tako_handler".to_string(),
+            ),
+            Code::Assignment(
+                "tako_value".to_string(),
+                Box::new(lambda(Code::Block(installs), true)),
+            ),
+            Code::Expr("tako_handler.resume(tako_value)".to_string()),
+        ]);
+        Ok(Code::Expr(pretty_print_block(lambda(handle, true), "")))
+    }
+
+    // Lowers a clause to a lambda that returns the value that it resumes with. Clauses that don't
+    // resume exit with their value instead, and statements after a `resume` wait for the value of
+    // the handled expression.
+    fn visit_clause(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        clause: &Let,
+        op: &HandledOperation,
+    ) -> Res {
+        let handled_value = "typename decltype(tako_handler)::value";
+        let mut args = vec![];
+        for (name, ty) in op.arguments.iter() {
+            let arg = clause.args.iter().flatten().find(|arg| arg.name == *name);
+            let arg = match arg.and_then(|arg| arg.get_info().defined_at) {
+                Some(path) => make_name(path),
+                None => format!("tako_{}", name),
+            };
+            args.push(format!("const {} {}", ty, arg));
+        }
+        let mut exits = false;
+        let code = match &clause.args {
+            // Handling an operation with a value resumes with that value.
+            None => self
+                .visit(storage, state, &clause.value)?
+                .with_expr(&|value| Code::Expr(format!("tako_resume({})", value))),
+            Some(_) => {
+                check_resumes(&clause.value)?;
+                let stmts = statements(&clause.value);
+                let split = stmts
+                    .iter()
+                    .position(|stmt| resume_statement(stmt).is_some())
+                    .filter(|at| at + 1 < stmts.len());
+                match split {
+                    Some(at) => self.visit_resumption(storage, state, &stmts, at)?,
+                    None => self.visit_clause_tail(storage, state, &clause.value, &mut exits)?,
+                }
+            }
+        };
+        let mut body = vec![];
+        if clause.args.is_none() || uses_resume(&clause.value) {
+            // Bits are written as `0` and `1`, so they are converted to the result rather than
+            // braced (which would reject them as narrowing).
+            body.push(Code::Statement(format!(
+                "const auto tako_resume = [&](const auto&... value) {{ return {}(value...); }}",
+                op.result
+            )));
+        }
+        if exits {
+            body.push(Code::Statement(format!(
+                "const auto tako_exit = [&](const {} value) {{ return tako_handler.template exit<{}>(value); }}",
+                handled_value, op.result
+            )));
+        }
+        Ok(Code::Func {
+            name: "".to_string(),
+            args,
+            return_type: "int".to_string(), // TODO
+            body: Box::new(Code::Block(body).merge(code)),
+            lambda: true,
+            call: false,
+            by_value: false,
+        })
+    }
+
+    // Lowers a clause with statements after its `resume` (at `at`), which are kept to run once the
+    // handled expression has its value.
+    fn visit_resumption(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        stmts: &[&Node],
+        at: usize,
+    ) -> Res {
+        let mut code = Code::Empty;
+        for stmt in stmts[..at].iter() {
+            code = code.merge(self.visit(storage, state, stmt)?);
+        }
+        let (_, call) = resume_statement(stmts[at]).expect("Resume statement missing");
+        let resumed = self.visit(storage, state, call)?;
+        let name = match stmts[at] {
+            Node::LetNode(def) => make_name(
+                def.get_info()
+                    .defined_at
+                    .expect("Could not find definition for let"),
+            ),
+            _ => "tako_value".to_string(),
+        };
+        let mut rest = Code::Empty;
+        for stmt in stmts[at + 1..].iter() {
+            rest = rest.merge(self.visit(storage, state, stmt)?);
+        }
+        let resumption = Code::Func {
+            name: "".to_string(),
+            args: vec![format!(
+                "const typename decltype(tako_handler)::value {}",
+                name
+            )],
+            return_type: "int".to_string(), // TODO
+            body: Box::new(rest),
+            lambda: true,
+            call: false,
+            by_value: true,
+        };
+        Ok(code
+            .merge(Code::Assignment(
+                "tako_resumed".to_string(),
+                Box::new(resumed),
+            ))
+            .merge(Code::Statement(format!(
+                "tako_handler.resumptions.push_back({})",
+                pretty_print_block(resumption, "")
+            )))
+            .merge(Code::Expr("tako_resumed".to_string())))
+    }
+
+    // Lowers the last step of a clause, which either resumes or exits with its value.
+    fn visit_clause_tail(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        expr: &Node,
+        exits: &mut bool,
+    ) -> Res {
+        match expr {
+            expr if is_resume(expr) => self.visit(storage, state, expr),
+            Node::BinOpNode(op) if op.name == ";" => {
+                let left = self.visit(storage, state, &op.left)?;
+                let right = self.visit_clause_tail(storage, state, &op.right, exits)?;
+                Ok(left.merge(right))
+            }
+            Node::BinOpNode(op) if op.name == "-|" => {
+                let condition = self.visit(storage, state, &op.left)?;
+                let value = self.with_requirement(storage, &op.left, |this, storage| {
+                    this.visit_clause_tail(storage, state, &op.right, exits)
+                })?;
+                self.build_requirement(storage, op, condition, value)
+            }
+            Node::BinOpNode(BinOp {
+                name, left, right, ..
+            }) if name == "?" && matches!(&**left, Node::BinOpNode(req) if req.name == "-|") => {
+                let req = match &**left {
+                    Node::BinOpNode(req) => req,
+                    _ => unreachable!("Expected a requirement"),
+                };
+                let condition = self.visit(storage, state, &req.left)?;
+                let then = self.visit_clause_tail(storage, state, &req.right, exits)?;
+                let then_else = self.visit_clause_tail(storage, state, right, exits)?;
+                Ok(self.build_conditional(condition, then, then_else))
+            }
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" => {
+                let inner = self.visit_clause_tail(storage, state, inner, exits)?;
+                Ok(self.build_scope(inner))
+            }
+            expr if uses_resume(expr) => Err(TError::UnsupportedInCpp(
+                format!("resuming from `{}`", expr),
+                expr.get_info(),
+            )),
+            expr => {
+                *exits = true;
+                Ok(self
+                    .visit(storage, state, expr)?
+                    .with_expr(&|value| Code::Expr(format!("tako_exit({})", value))))
+            }
+        }
+    }
 }
 
 impl Visitor<State, Code, Out, Path> for CodeGenerator {
@@ -247,6 +1937,8 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
             value: Box::new(root.ast.clone()),
             args: Some(vec![]),
         };
+        self.find_interfaces(&root.ast)?;
+        self.find_handlers(storage, module, &root.table, &root.ast)?;
         let mut table = root.table; // TODO: Shouldn't be mut
         if storage.debug_level() > 1 {
             eprintln!("table {:?}", table);
@@ -259,6 +1951,7 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                     body,
                     lambda: _,
                     call: _,
+                    by_value: _,
                     return_type: _,
                 } => Code::Func {
                     name: "main".to_string(),
@@ -266,6 +1959,7 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                     body,
                     lambda: false,
                     call: false,
+                    by_value: false,
                     return_type: "int".to_string(),
                 },
                 thing => panic!("main must be a Func {:?}", thing),
@@ -328,7 +2022,9 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
         match expr {
             Product(tys) => {
                 if tys.is_empty() {
-                    return Ok(Code::Expr("void".to_string()));
+                    // `()` is a missing optional value.
+                    self.includes.insert(OPTIONALS.to_string());
+                    return Ok(Code::Expr("tako::none".to_string()));
                 }
                 unimplemented!("unimplemented sum type in compilation to cpp")
             }
@@ -342,9 +2038,38 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                 use Prim::*;
                 match prim {
                     I32(n) => Ok(Code::Expr(n.to_string())),
+                    I64(n) => Ok(self.build_number("int64_t", n.to_string())),
+                    U8(n) => {
+                        self.includes.insert(FIXED_NUMBERS.to_string());
+                        Ok(self.build_number("tako::u8", format!("{}u", n)))
+                    }
+                    U32(n) => Ok(self.build_number("uint32_t", format!("{}u", n))),
+                    U64(n) => Ok(self.build_number("uint64_t", format!("{}u", n))),
+                    F64(x) => {
+                        self.includes.insert(FIXED_NUMBERS.to_string());
+                        Ok(Code::Expr(format!("tako::f64{{{:?}}}", x.0)))
+                    }
+                    // Big literals may not fit in any C++ integer, so GMP parses them from strings.
+                    BigInt(n) => {
+                        let ty = storage
+                            .get_extern("BigInt".to_string())?
+                            .expect("BigInt should be a built in type")
+                            .cpp;
+                        self.includes.insert(ty.includes);
+                        self.flags.extend(ty.flags);
+                        Ok(Code::Expr(format!("{}(\"{}\")", ty.code, n)))
+                    }
                     Bool(true) => Ok(Code::Expr(1.to_string())),
                     Bool(false) => Ok(Code::Expr(0.to_string())),
-                    Str(s) => Ok(Code::Expr(format!("{:?}", s))),
+                    Str(s) => Ok(Code::Expr(quote_string(s))),
+                    // Characters are strings of a single code point, so that they print as text.
+                    Char(c) => {
+                        self.includes.insert("#include <string>".to_string());
+                        Ok(Code::Expr(format!(
+                            "std::string({})",
+                            quote_string(&c.to_string())
+                        )))
+                    }
                     BuiltIn(name) => {
                         unimplemented!("unimplemented BuiltIn to {} in compilation to cpp", &name)
                     }
@@ -368,19 +2093,24 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
 
     fn visit_apply(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
         // eprintln!("apply here: {:?}", expr);
-        // Build the 'struct' of args
-        let mut args = vec![];
-        for arg in expr.args.iter() {
-            // TODO: Include lambda head in values
-            let val = self.visit_let(storage, state, arg)?;
-            match val {
-                Code::Assignment(_, val) => args.push(pretty_print_block(*val, "")),
-                val => args.push(pretty_print_block(val, "")),
-            };
+        if let Node::SymNode(sym) = &*expr.inner {
+            if sym.get_info().defined_at == Some(vec![Symbol::new("match")]) {
+                return self.visit_match(storage, state, expr);
+            }
+            if sym.get_info().defined_at == Some(vec![Symbol::new("lib::exists")]) {
+                return self.visit_lib_exists(storage, expr);
+            }
+            if let Some(op) = self.handled.get(&sym.name).cloned() {
+                return self.visit_operation(storage, state, expr, &op);
+            }
         }
+        let mut args = self.visit_args(storage, state, &expr.args)?;
         let inner = self.visit(storage, state, &expr.inner)?;
         match inner {
             Code::Expr(expr) => {
+                if self.recursive.contains(&expr) {
+                    args.insert(0, expr.clone());
+                }
                 let with_args = format!("{}({})", expr, args.join(", "));
                 Ok(Code::Expr(with_args))
             }
@@ -400,8 +2130,19 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
             .expect("Could not find definition for abs");
 
         let name = make_name(path);
-        let body = self.visit(storage, state, &expr.value)?;
-        Ok(Code::Template(name, Box::new(body)))
+        self.function_depth += 1;
+        let body = self.visit(storage, state, &expr.value);
+        self.function_depth -= 1;
+        let func = Code::Func {
+            name: name.clone(),
+            args: vec![format!("const auto {}", name)],
+            return_type: "int".to_string(), // TODO
+            body: Box::new(body?),
+            lambda: true,
+            call: false,
+            by_value: true,
+        };
+        Ok(Code::Expr(pretty_print_block(func, "")))
     }
 
     fn visit_let(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Let) -> Res {
@@ -415,14 +2156,51 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
             .defined_at
             .expect("Could not find definition for let");
 
+        let name = make_name(path.clone());
+        // Calls inside a handler's body refer to its clauses, so operations may only be used
+        // through their handlers.
         let uses = storage.find_symbol_uses(path.clone())?;
-        if uses.is_empty() {
+        if uses.is_empty() && !self.handled.values().any(|op| op.code == name) {
             return Ok(Code::Empty);
         }
-        let name = make_name(path);
-        let body = self.visit(storage, state, &expr.value)?;
+        if let Node::UnOpNode(UnOp { name: op, inner, .. }) = &*expr.value {
+            if op == "[]" && elements(inner).is_empty() && self.function_depth == 1 {
+                if let Some(list) = self.empty_list(storage, &path) {
+                    return Ok(Code::Assignment(name, Box::new(list)));
+                }
+            }
+        }
+        let depth = self.function_depth;
+        let value = match &*expr.value {
+            // A function's body is already a scope.
+            Node::UnOpNode(UnOp { name, inner, .. }) if name == "{}" && expr.args.is_some() => {
+                inner
+            }
+            value => value,
+        };
+        // A lambda can't call itself, so a recursive function's body is a lambda that is given
+        // itself, which needs its result type to be known.
+        let recursive = uses.iter().any(|used| used.starts_with(&path));
+        let result_type = if expr.args.is_some() && recursive {
+            Some(self.recursive_result_type(storage, &path, &expr.info)?)
+        } else {
+            None
+        };
+        if let Some(args) = &expr.args {
+            self.function_depth += 1;
+            self.arguments
+                .extend(args.iter().filter_map(|arg| arg.get_info().defined_at));
+        }
+        let calls_itself = result_type.is_some() && self.recursive.insert(name.clone());
+        let body = self.visit(storage, state, value);
+        if calls_itself {
+            self.recursive.remove(&name);
+        }
+        self.function_depth = depth;
+        let body = body?;
         if let Some(eargs) = &expr.args {
             let mut args = vec![];
+            let mut arg_names = vec![];
             for arg in eargs.iter() {
                 let path = arg
                     .get_info()
@@ -430,7 +2208,32 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                     .expect("Could not find definition for let arg");
                 let name = make_name(path);
                 args.push(format!("const auto {}", name));
+                arg_names.push(name);
             }
+            let body = match result_type {
+                Some(result_type) => {
+                    let body = match body {
+                        Code::Block(_) => body,
+                        body => Code::Block(vec![body]),
+                    };
+                    let inner = format!("{}_self", name);
+                    let mut inner_args = vec![format!("const auto& {}", name)];
+                    inner_args.extend(args.iter().cloned());
+                    let lambda = format!(
+                        "[&]({}) -> {} {}",
+                        inner_args.join(", "),
+                        result_type,
+                        pretty_print_block(body, "")
+                    );
+                    let mut call_args = vec![inner.clone()];
+                    call_args.extend(arg_names);
+                    Code::Block(vec![
+                        Code::Assignment(inner.clone(), Box::new(Code::Expr(lambda))),
+                        Code::Expr(format!("{}({})", inner, call_args.join(", "))),
+                    ])
+                }
+                None => body,
+            };
 
             return Ok(Code::Assignment(
                 name.clone(),
@@ -441,6 +2244,7 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                     body: Box::new(body),
                     lambda: true,
                     call: false,
+                    by_value: self.function_depth > 1,
                 }),
             ));
         }
@@ -454,12 +2258,46 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
                 body: Box::new(body),
                 lambda: true,
                 call: true,
+                by_value: false,
             },
         };
+        if expr.info.mutable {
+            // Variables declared with `:=` can be assigned to later, so they can't be const.
+            let value = pretty_print_block(body, "");
+            return Ok(Code::Statement(format!("auto {} = {}", name, value)));
+        }
         Ok(Code::Assignment(name, Box::new(body)))
     }
 
     fn visit_un_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        if expr.name == "type" {
+            return self.visit_type(storage, expr);
+        }
+        if expr.name == "interface" {
+            return self.visit_interface(storage, expr);
+        }
+        if expr.name == "impl" {
+            return self.visit_impl(storage, state, expr);
+        }
+        if expr.name == "[]" {
+            return self.visit_list(storage, state, expr);
+        }
+        if expr.name == "()" {
+            return self.visit_tuple(storage, state, expr);
+        }
+        if let (true, Some((condition, body))) = (expr.name == "{}", loop_parts(&expr.inner)) {
+            // Loops are recursive functions, but they can be run in place.
+            let condition = self.visit(storage, state, condition)?;
+            let body = self.visit_body(storage, state, body)?;
+            return Ok(Code::While {
+                condition: Box::new(condition),
+                body: Box::new(body),
+            });
+        }
+        if expr.name == "{}" {
+            let inner = self.visit(storage, state, &expr.inner)?;
+            return Ok(self.build_scope(inner));
+        }
         let code = self.visit(storage, state, &expr.inner)?;
         let info = expr.get_info();
         let op = expr.name.as_str();
@@ -478,21 +2316,82 @@ impl Visitor<State, Code, Out, Path> for CodeGenerator {
 
     fn visit_bin_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
         let info = expr.get_info();
+        let op = expr.name.as_str();
+        if let (".", Node::ApplyNode(call)) = (op, &*expr.right) {
+            return self.visit_method_call(storage, state, &expr.left, call);
+        }
+        match (op, &*expr.left) {
+            ("?", Node::BinOpNode(req)) if req.name == "-|" => {
+                // A requirement with an alternative is control flow, not a contract.
+                let condition = self.visit(storage, state, &req.left)?;
+                if let Some((_, then)) = if_then_parts(expr) {
+                    let then = self.visit_body(storage, state, then)?;
+                    return Ok(Code::If {
+                        condition: Box::new(condition),
+                        then: Box::new(then),
+                        then_else: Box::new(Code::Empty),
+                    });
+                }
+                let mut then = self.visit(storage, state, &req.right)?;
+                let mut then_else = self.visit(storage, state, &expr.right)?;
+                if let Some((checked, has_value)) = null_checked(&req.left) {
+                    // Past the check, the variable is known to have a value.
+                    let name = self.visit_sym(storage, state, checked)?;
+                    let narrowed = if has_value { &mut then } else { &mut then_else };
+                    *narrowed = name.with_expr(&|name| {
+                        narrowed.clone().with_expr(&|branch| {
+                            Code::Expr(format!(
+                                "[&](const auto {name}) {{ return {branch}; }}(tako::value_of({name}))"
+                            ))
+                        })
+                    });
+                }
+                // A branch without a value makes the other branch's value optional.
+                if is_none(&expr.right) && !is_none(&req.right) {
+                    then = self.build_call1("tako::some", then);
+                }
+                if is_none(&req.right) && !is_none(&expr.right) {
+                    then_else = self.build_call1("tako::some", then_else);
+                }
+                return Ok(self.build_conditional(condition, then, then_else));
+            }
+            ("?.", _) => return self.visit_optional_access(storage, state, expr),
+            ("|=", _) => return self.visit_postcondition(storage, state, expr),
+            (".", _) if field_name(expr).is_some() => {
+                let field = field_name(expr).expect("Expected a field");
+                let value = self.visit(storage, state, &expr.left)?;
+                // Positional fields are the elements of a `std::tuple`.
+                return Ok(match field.parse::<usize>() {
+                    Ok(index) => self.build_call1(&format!("std::get<{}>", index), value),
+                    Err(_) => value.with_expr(&|value| Code::Expr(format!("{}.{}", value, field))),
+                });
+            }
+            ("[]", Node::SymNode(sym)) if storage.get_extern(sym.name.clone())?.is_some() => {
+                // Indexing into a function calls it, e.g. `argv[1]`.
+                let func = self.visit(storage, state, &expr.left)?;
+                let arg = self.visit(storage, state, &expr.right)?;
+                return Ok(func.with_expr(&|func| {
+                    arg.clone()
+                        .with_expr(&|arg| Code::Expr(format!("{}({})", func, arg)))
+                }));
+            }
+            // Handlers are dynamically scoped, so the operations that they replace are called
+            // indirectly (see `visit_operation`) and the clauses are installed while the body runs.
+            ("~", _) => return self.visit_handle(storage, state, expr),
+            ("-|", condition) => {
+                let left = self.visit(storage, state, condition)?;
+                let right = self.with_requirement(storage, condition, |this, storage| {
+                    this.visit(storage, state, &expr.right)
+                })?;
+                return self.build_requirement(storage, expr, left, right);
+            }
+            _ => {}
+        }
         let left = self.visit(storage, state, &expr.left.clone())?;
         let right = self.visit(storage, state, &expr.right.clone())?;
         // TODO: require 2 children
         // TODO: Short circuiting of deps.
-        let op = expr.name.as_str();
         match op {
-            "-|" => {
-                // TODO: handle 'error' values more widly.
-                let done = Code::If {
-                    condition: Box::new(left),
-                    then: Box::new(right),
-                    then_else: Box::new(Code::Statement("throw 101".to_string())),
-                };
-                return Ok(done);
-            }
             "," | ";" => {
                 // TODO: handle 'error' values more widly.
                 return Ok(left.merge(right));
@@ -1,201 +1,549 @@
-use crate::ast::{Node, Node::*};
+use crate::ast::*;
 use crate::database::DBStorage;
 use crate::errors::TError;
 use crate::passes::interpreter::Interpreter;
-use std::collections::BTreeSet;
-use std::collections::HashMap;
+use crate::passes::pretty_print::PrettyPrint;
+use crate::passes::type_graph_builder::TypeGraphBuilder;
+use crate::type_graph::{is_product_of_functions, TypeGraph};
+use std::collections::{BTreeSet, HashMap};
+use Node::*;
 
-use crate::primitives::{bit_type, i32_type, record, string_type, Prim::*, Val, Val::*};
+use crate::externs::get_interface;
+use crate::primitives::{
+    data_constructors, is_container, is_number, never_type, optional_inner, string_type, unit_type,
+    Frame, Val, Val::*,
+};
+
+// The kinds that a type variable can be constrained to, other than `Type` and user interfaces.
+const NUMBER: &str = "Number";
+const COLLECTION: &str = "Collection";
+const DISPLAY: &str = "Display";
+
+// Solves type constraints using unification, generalising the types of definitions so that they
+// can be used at several types (i.e. Hindley-Milner with let-polymorphism).
+#[derive(Default, Debug)]
+pub struct TypeChecker {
+    // Type variables that have been solved.
+    substitution: HashMap<String, Val>,
+    // Constraints on unsolved type variables, e.g. that they must be a `Number`.
+    kinds: HashMap<String, BTreeSet<String>>,
+    // The operations of the interfaces declared with `interface`.
+    interfaces: HashMap<String, Vec<(String, Val)>>,
+    // The interfaces that types implement, declared with `impl`.
+    impls: BTreeSet<(String, Val)>,
+    // The data types declared with `type`, which their own fields refer to by name.
+    types: HashMap<String, Val>,
+    next_var: usize,
+}
 
 pub fn infer(storage: &mut DBStorage, expr: &Node, env: &Val) -> Result<Val, TError> {
-    // Infer that expression t has type A, t => A
-    // See https://ncatlab.org/nlab/show/bidirectional+typechecking
-    use crate::ast::*;
-    match expr {
-        ValNode(prim, _) => match prim {
-            Product(vals) => {
-                let mut tys: BTreeSet<Val> = set![];
-                for val in vals.iter() {
-                    tys.insert(infer(storage, &val.clone().into_node(), env)?);
-                }
-                Ok(Product(tys))
+    TypeGraphBuilder::default().infer(storage, expr, env)
+}
+
+// Evaluates a type annotation in a scope containing the built in types.
+pub(crate) fn eval_type(storage: &mut DBStorage, ty: &Node) -> Result<Val, TError> {
+    eval_type_with(storage, ty, &Frame::new())
+}
+
+// Evaluates a type annotation, with some declared types (e.g. from `type` declarations) in scope.
+pub(crate) fn eval_type_with(
+    storage: &mut DBStorage,
+    ty: &Node,
+    types: &Frame,
+) -> Result<Val, TError> {
+    let mut base_frame = Frame::new();
+    for (name, ext) in storage.get_externs()?.iter() {
+        base_frame.insert(name.to_owned(), ext.value.clone());
+    }
+    Interpreter::default().visit(storage, &mut vec![base_frame, types.clone()], ty)
+}
+
+pub(crate) fn describe(storage: &mut DBStorage, expr: &Node) -> String {
+    PrettyPrint { names_only: true }
+        .visit_root(storage, expr)
+        .unwrap_or_else(|_| format!("{}", expr))
+}
+
+fn quantifier_name(index: usize) -> String {
+    let letter = (b'a' + (index % 26) as u8) as char;
+    if index < 26 {
+        letter.to_string()
+    } else {
+        format!("{}{}", letter, index / 26)
+    }
+}
+
+impl TypeChecker {
+    pub(crate) fn fresh(&mut self) -> Val {
+        self.next_var += 1;
+        Variable(format!("_{}", self.next_var))
+    }
+
+    pub(crate) fn is_var(&self, name: &str) -> bool {
+        name.starts_with('_')
+    }
+
+    pub(crate) fn declare_interface(&mut self, name: &str, operations: Vec<(String, Val)>) {
+        self.interfaces.insert(name.to_string(), operations);
+    }
+
+    // Finds the operations of an interface, with `Self` standing for the implementing type.
+    pub(crate) fn interface(&self, name: &str) -> Option<Vec<(String, Val)>> {
+        self.interfaces
+            .get(name)
+            .cloned()
+            .or_else(|| get_interface(name))
+    }
+
+    // Returns false if the type already implemented the interface.
+    pub(crate) fn implement(&mut self, interface: &str, ty: Val) -> bool {
+        self.impls.insert((interface.to_string(), ty))
+    }
+
+    pub(crate) fn declare_type(&mut self, name: &str, ty: Val) {
+        self.types.insert(name.to_string(), ty);
+    }
+
+    // Replaces a reference to a recursive data type (e.g. the type of `t` in
+    // `type L = Nil | Cons(h: I32, t: L)`) with the data type.
+    pub(crate) fn unfold(&self, ty: &Val) -> Val {
+        match ty {
+            Variable(name) => self.types.get(name).unwrap_or(ty).clone(),
+            ty => ty.clone(),
+        }
+    }
+
+    // Kinds that are checked when their variables are solved, rather than allowing any type.
+    fn is_constraint(&self, kind: &str) -> bool {
+        matches!(kind, NUMBER | COLLECTION | DISPLAY) || self.interfaces.contains_key(kind)
+    }
+
+    // Checks whether a type can be used where a type of a kind is expected.
+    fn has_kind(&self, kind: &str, ty: &Val) -> bool {
+        let ty = &self.unfold(ty);
+        let implemented = self.impls.contains(&(kind.to_string(), ty.clone()));
+        match kind {
+            NUMBER => is_number(ty) || implemented,
+            COLLECTION => {
+                *ty == string_type()
+                    || matches!(ty, App { inner, .. } if matches!(&**inner, Variable(name) if is_container(name)))
+            }
+            // Values of data types can only be displayed once they say how.
+            DISPLAY => data_constructors(ty).is_none() || implemented,
+            kind if self.interfaces.contains_key(kind) => implemented,
+            _ => true,
+        }
+    }
+
+    // Applies the substitution, so that solved type variables are replaced by their types.
+    pub(crate) fn resolve(&self, ty: &Val) -> Val {
+        match ty {
+            Variable(name) => match self.substitution.get(name) {
+                Some(ty) => self.resolve(ty),
+                None => ty.clone(),
+            },
+            Function {
+                intros,
+                arguments,
+                results,
+            } => Function {
+                intros: intros.clone(),
+                arguments: Box::new(self.resolve(arguments)),
+                results: Box::new(self.resolve(results)),
+            },
+            Struct(fields) => Struct(
+                fields
+                    .iter()
+                    .map(|(name, ty)| (name.clone(), self.resolve(ty)))
+                    .collect(),
+            ),
+            Union(tys) => Union(tys.iter().map(|ty| self.resolve(ty)).collect()),
+            Product(tys) => Product(tys.iter().map(|ty| self.resolve(ty)).collect()),
+            WithRequirement(ty, effs) => WithRequirement(Box::new(self.resolve(ty)), effs.clone()),
+            App { inner, arguments } => App {
+                inner: Box::new(self.resolve(inner)),
+                arguments: Box::new(self.resolve(arguments)),
+            },
+            ty => ty.clone(),
+        }
+    }
+
+    pub(crate) fn free_vars(&self, ty: &Val, vars: &mut BTreeSet<String>) {
+        match self.resolve(ty) {
+            Variable(name) if self.is_var(&name) => {
+                vars.insert(name);
             }
-            Union(vals) => {
-                let mut tys: BTreeSet<Val> = set![];
-                for val in vals.iter() {
-                    tys.insert(infer(storage, &val.clone().into_node(), env)?);
+            Function {
+                intros,
+                arguments,
+                results,
+            } => {
+                let mut inner = BTreeSet::new();
+                self.free_vars(&arguments, &mut inner);
+                self.free_vars(&results, &mut inner);
+                for var in inner {
+                    if !intros.iter().any(|(name, _)| *name == var) {
+                        vars.insert(var);
+                    }
                 }
-                Ok(Union(tys))
             }
-            PrimVal(I32(_)) => Ok(i32_type()),
-            PrimVal(Bool(_)) => Ok(bit_type()),
-            PrimVal(Str(_)) => Ok(string_type()),
-            Lambda(node) => infer(storage, node.as_ref(), env), // TODO: abstraction
-            Struct(vals) => {
-                let mut tys: Vec<Val> = vec![];
-                for val in vals.iter() {
-                    tys.push(infer(storage, &val.1.clone().into_node(), env)?);
+            Struct(fields) => {
+                for (_, ty) in fields.iter() {
+                    self.free_vars(ty, vars);
                 }
-                Ok(record(tys)?)
             }
-            _ty => Ok(Val::Variable("Type".to_string())),
-        },
-        UnOpNode(UnOp { name, inner, info }) => {
-            let it_ty = infer(storage, inner, env)?;
-            let new_env = env.clone().merge(rec! {"it" => it_ty.clone()});
-            let inner_ty = infer(
-                storage,
-                &Sym {
-                    name: name.to_string(),
-                    info: info.clone(),
+            Union(tys) | Product(tys) => {
+                for ty in tys.iter() {
+                    self.free_vars(ty, vars);
                 }
-                .into_node(),
-                &new_env,
-            )?;
-            let app = Apply {
-                inner: Box::new(inner_ty.into_node()),
-                args: vec![Let {
-                    name: "it".to_string(),
-                    args: None,
-                    value: Box::new(it_ty.into_node()),
-                    info: info.clone(),
-                }],
-                info: info.clone(),
-            };
-            let mut state = vec![];
-            Interpreter::default().visit_apply(storage, &mut state, &app)
+            }
+            WithRequirement(ty, _) => self.free_vars(&ty, vars),
+            App { inner, arguments } => {
+                self.free_vars(&inner, vars);
+                self.free_vars(&arguments, vars);
+            }
+            _ => {}
         }
-        BinOpNode(BinOp {
-            name,
-            left,
-            right,
-            info,
-        }) => {
-            let left_ty = infer(storage, left, env)?;
-            let tmp_env = env.clone().merge(left_ty.clone());
-            let right_ty = infer(storage, right, &tmp_env)?;
-            let new_env = env
-                .clone()
-                .merge(rec! {"left" => left_ty.clone(), "right" => right_ty.clone()});
-            let inner_ty = infer(
-                storage,
-                &Sym {
-                    name: name.to_string(),
-                    info: info.clone(),
+    }
+
+    // Quantifies over the type variables that are not fixed by the environment.
+    pub(crate) fn generalize(&self, env_vars: &BTreeSet<String>, ty: &Val) -> Val {
+        let ty = self.resolve(ty);
+        let (arguments, results) = match &ty {
+            Function {
+                intros,
+                arguments,
+                results,
+            } if intros.is_empty() => (arguments, results),
+            _ => return ty,
+        };
+        let mut ty_vars = BTreeSet::new();
+        self.free_vars(&ty, &mut ty_vars);
+        let mut renaming = HashMap::new();
+        let mut intros = BTreeSet::new();
+        for var in ty_vars.difference(env_vars) {
+            let name = quantifier_name(renaming.len());
+            match self.kinds.get(var) {
+                Some(kinds) if !kinds.is_empty() => {
+                    for kind in kinds.iter() {
+                        intros.insert((name.clone(), Variable(kind.clone())));
+                    }
                 }
-                .into_node(),
-                &new_env,
-            )?;
-            let app = Apply {
-                inner: Box::new(inner_ty.into_node()),
-                args: vec![
-                    Let {
-                        name: "left".to_string(),
-                        args: None,
-                        value: Box::new(left_ty.into_node()),
-                        info: info.clone(),
-                    },
-                    Let {
-                        name: "right".to_string(),
-                        args: None,
-                        value: Box::new(right_ty.into_node()),
-                        info: info.clone(),
-                    },
-                ],
-                info: info.clone(),
-            };
-            let mut state = vec![];
-            Interpreter::default().visit_apply(storage, &mut state, &app)
+                _ => {
+                    intros.insert((name.clone(), Variable("Type".to_string())));
+                }
+            }
+            renaming.insert(var.clone(), Variable(name));
+        }
+        if intros.is_empty() {
+            return ty;
+        }
+        Function {
+            intros,
+            arguments: Box::new(substitute(arguments, &renaming)),
+            results: Box::new(substitute(results, &renaming)),
         }
-        SymNode(Sym { name, info: _ }) => {
-            if let Some(ext) = storage.get_extern(name.to_string())? {
-                // TODO intros
-                let mut frame = HashMap::new();
-                for (name, ty) in env.clone().into_struct().iter() {
-                    frame.insert(name.clone(), ty.clone());
+    }
+
+    // Replaces the quantified variables of a type with fresh type variables.
+    pub(crate) fn instantiate(&mut self, ty: &Val) -> Val {
+        match ty {
+            Function {
+                intros,
+                arguments,
+                results,
+            } if !intros.is_empty() => {
+                let mut renaming: HashMap<String, Val> = HashMap::new();
+                for (name, kind) in intros.iter() {
+                    // A variable with several kinds is introduced once for each of them.
+                    let var = match renaming.get(name) {
+                        Some(var) => var.clone(),
+                        None => self.fresh(),
+                    };
+                    if let (Variable(var), Variable(kind)) = (&var, kind) {
+                        if self.is_constraint(kind) {
+                            let kinds = self.kinds.entry(var.clone()).or_default();
+                            kinds.insert(kind.clone());
+                        }
+                    }
+                    renaming.insert(name.clone(), var);
+                }
+                Function {
+                    intros: BTreeSet::new(),
+                    arguments: Box::new(substitute(arguments, &renaming)),
+                    results: Box::new(substitute(results, &renaming)),
                 }
-                let mut state = vec![frame];
-                return Interpreter::default().visit(storage, &mut state, &ext.ty);
             }
-            eprintln!("access sym: {}", name);
-            eprintln!("env sym: {}", env);
-            Ok(env.access(name))
+            ty => ty.clone(),
         }
-        ApplyNode(Apply { inner, args, info }) => {
-            let mut arg_tys = vec![];
-            let mut let_tys = vec![];
-            for arg in args.iter() {
-                let ty = infer(storage, &arg.value.clone().into_node(), env)?;
-                let_tys.push((arg.name.clone(), ty.clone()));
-                let ty_let = Let {
-                    name: arg.name.clone(),
-                    args: None,
-                    value: Box::new(ty.into_node()),
-                    info: info.clone(),
-                };
-                arg_tys.push(ty_let);
+    }
+
+    pub(crate) fn mismatch(
+        &self,
+        context: &str,
+        expected: &Val,
+        found: &Val,
+        info: &Info,
+    ) -> TError {
+        TError::UnexpectedType(
+            context.to_string(),
+            Box::new(self.resolve(expected)),
+            Box::new(self.resolve(found)),
+            info.clone(),
+        )
+    }
+
+    fn bind(&mut self, var: &str, ty: &Val, context: &str, info: &Info) -> Result<(), TError> {
+        if let Variable(other) = ty {
+            if other == var {
+                return Ok(());
             }
-            let new_env = env.clone().merge(Struct(let_tys));
-            eprintln!("new_env: {}", &new_env);
-            let inner_ty = infer(storage, inner, &new_env)?;
-            let app = Apply {
-                inner: Box::new(inner_ty.into_node()),
-                args: arg_tys,
-                info: info.clone(),
-            };
-            let mut state = vec![HashMap::new()];
-            Interpreter::default().visit_apply(storage, &mut state, &app)
         }
-        AbsNode(Abs {
-            name,
-            value,
-            info: _,
-        }) => {
-            let ty = infer(storage, &value.clone().into_node(), env)?;
+        // Never has no values, so it can be used as any type, rather than deciding the type.
+        if *ty == never_type() {
+            return Ok(());
+        }
+        let mut vars = BTreeSet::new();
+        self.free_vars(ty, &mut vars);
+        if vars.contains(var) {
+            return Err(self.mismatch(context, &Variable(var.to_string()), ty, info));
+        }
+        for kind in self.kinds.get(var).cloned().unwrap_or_default() {
             match ty {
-                Function {
-                    mut intros,
-                    arguments,
-                    results,
-                } => {
-                    intros.insert((name.to_string(), Variable("typename".to_string())));
-                    Ok(Function {
-                        intros,
-                        arguments,
-                        results,
-                    })
+                Variable(other) if self.is_var(other) => {
+                    let kinds = self.kinds.entry(other.clone()).or_default();
+                    kinds.insert(kind);
                 }
-                ty => panic!("abstraction over {} for {}", &name, &ty),
+                ty if !self.has_kind(&kind, ty) => {
+                    return Err(self.mismatch(context, &Variable(kind), ty, info));
+                }
+                _ => {}
             }
         }
-        LetNode(Let {
-            name,
-            value,
-            args,
-            info,
-        }) => {
-            let ty = if let Some(ty) = info.ty.clone() {
-                let mut state = vec![HashMap::new()];
-                Interpreter::default().visit(storage, &mut state, &ty)?
-            } else {
-                infer(storage, &value.clone().into_node(), env)?
-            };
-            let ty = if let Some(args) = args {
-                let mut arg_tys = rec![];
-                for arg in args.iter() {
-                    let ty = infer(storage, &arg.clone().into_node(), env)?;
-                    arg_tys = arg_tys.merge(ty);
-                }
+        self.substitution.insert(var.to_string(), ty.clone());
+        Ok(())
+    }
+
+    pub(crate) fn unify(
+        &mut self,
+        expected: &Val,
+        found: &Val,
+        context: &str,
+        info: &Info,
+    ) -> Result<(), TError> {
+        let expected = self.instantiate(&self.resolve(expected));
+        let found = self.instantiate(&self.resolve(found));
+        match (&expected, &found) {
+            (Variable(var), ty) if self.is_var(var) => self.bind(var, ty, context, info)?,
+            (ty, Variable(var)) if self.is_var(var) => self.bind(var, ty, context, info)?,
+            (WithRequirement(expected, _), found) => self.unify(expected, found, context, info)?,
+            (expected, WithRequirement(found, _)) => self.unify(expected, found, context, info)?,
+            (
+                Function {
+                    arguments: expected_args,
+                    results: expected_results,
+                    ..
+                },
                 Function {
-                    intros: set![],
-                    results: Box::new(ty),
-                    arguments: Box::new(arg_tys),
+                    arguments: found_args,
+                    results: found_results,
+                    ..
+                },
+            ) => {
+                self.unify(expected_args, found_args, context, info)?;
+                self.unify(expected_results, found_results, context, info)?;
+            }
+            (
+                App {
+                    inner: expected_inner,
+                    arguments: expected_args,
+                },
+                App {
+                    inner: found_inner,
+                    arguments: found_args,
+                },
+            ) if expected_inner == found_inner => {
+                self.unify(expected_args, found_args, context, info)?;
+            }
+            (Struct(expected_fields), Struct(found_fields)) => {
+                if expected_fields.len() != found_fields.len() {
+                    return Err(self.mismatch(context, &expected, &found, info));
+                }
+                for (name, expected_ty) in expected_fields.iter() {
+                    match found_fields.iter().find(|(found, _)| found == name) {
+                        Some((_, found_ty)) => self.unify(expected_ty, found_ty, context, info)?,
+                        None => return Err(self.mismatch(context, &expected, &found, info)),
+                    }
                 }
-            } else {
-                ty
+            }
+            // Structs are functions from their field names to their values, so they can be used
+            // where such a function is expected.
+            (Function { .. }, Struct(_)) | (Product(_), Struct(_))
+                if is_function_of_fields(&expected, &found) => {}
+            (expected, found) if expected == found => {}
+            (_, found) if *found == never_type() => {}
+            (Variable(name), found) if self.types.contains_key(name) => {
+                self.unify(&self.unfold(&expected), found, context, info)?
+            }
+            (expected, Variable(name)) if self.types.contains_key(name) => {
+                self.unify(expected, &self.unfold(&found), context, info)?
+            }
+            // A value, or `()`, can be used where an optional is expected.
+            (expected, found) if optional_inner(expected).is_some() => {
+                let inner = optional_inner(expected).expect("expected an optional");
+                match optional_inner(found) {
+                    Some(found) => self.unify(&inner, &found, context, info)?,
+                    None if *found == unit_type() => {}
+                    None => self.unify(&inner, found, context, info)?,
+                }
+            }
+            (expected, found) => return Err(self.mismatch(context, expected, found, info)),
+        }
+        Ok(())
+    }
+
+    // Finds the type of a built in, instantiating it for a new use.
+    pub(crate) fn lookup_extern(
+        &mut self,
+        storage: &mut DBStorage,
+        name: &str,
+        info: &Info,
+    ) -> Res {
+        if let Some(ext) = storage.get_extern(name.to_string())? {
+            let ty = match ext.ty {
+                ValNode(ty, _) => ty,
+                ty => eval_type(storage, &ty)?,
             };
-            Ok(Val::Struct(vec![(name.clone(), ty)]))
+            let ty = self.builtin_type(ty);
+            return Ok(self.instantiate(&ty));
+        }
+        Err(TError::UnknownSymbol(
+            name.to_string(),
+            info.clone(),
+            "type_checker".to_string(),
+        ))
+    }
+
+    // Reads the declared type of a built in, replacing unconstrained `Type`s with variables.
+    fn builtin_type(&mut self, ty: Val) -> Val {
+        match ty {
+            Variable(kind) if kind == "Type" => self.fresh(),
+            Function {
+                intros,
+                arguments,
+                results,
+            } => Function {
+                intros,
+                arguments: Box::new(self.builtin_type(*arguments)),
+                results: Box::new(self.builtin_type(*results)),
+            },
+            Struct(fields) => Struct(
+                fields
+                    .into_iter()
+                    .map(|(name, ty)| (name, self.builtin_type(ty)))
+                    .collect(),
+            ),
+            WithRequirement(ty, effs) => WithRequirement(Box::new(self.builtin_type(*ty)), effs),
+            App { inner, arguments } => App {
+                inner,
+                arguments: Box::new(self.builtin_type(*arguments)),
+            },
+            // Failures are handled at runtime, so e.g. `I32|Never` can be used as an `I32`.
+            Union(tys) if tys.len() == 2 && tys.contains(&never_type()) => {
+                let ty = tys
+                    .into_iter()
+                    .find(|ty| *ty != never_type())
+                    .expect("union should contain a non-never type");
+                self.builtin_type(ty)
+            }
+            ty => ty,
+        }
+    }
+}
+
+pub(crate) type Res = Result<Val, TError>;
+
+// Whether a struct can be used as a function (or a product of functions) from its field names.
+fn is_function_of_fields(function: &Val, fields: &Val) -> bool {
+    if matches!(function, Product(tys) if !is_product_of_functions(tys)) {
+        return false;
+    }
+    let mut graph = TypeGraph::default();
+    matches!(graph.is_assignable_to(fields, function), Ok(assignable) if assignable.is_true())
+}
+
+pub(crate) fn substitute(ty: &Val, renaming: &HashMap<String, Val>) -> Val {
+    match ty {
+        Variable(name) => renaming.get(name).cloned().unwrap_or_else(|| ty.clone()),
+        Function {
+            intros,
+            arguments,
+            results,
+        } => Function {
+            intros: intros.clone(),
+            arguments: Box::new(substitute(arguments, renaming)),
+            results: Box::new(substitute(results, renaming)),
+        },
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|(name, ty)| (name.clone(), substitute(ty, renaming)))
+                .collect(),
+        ),
+        Union(tys) => Union(tys.iter().map(|ty| substitute(ty, renaming)).collect()),
+        Product(tys) => Product(tys.iter().map(|ty| substitute(ty, renaming)).collect()),
+        WithRequirement(ty, effs) => {
+            WithRequirement(Box::new(substitute(ty, renaming)), effs.clone())
+        }
+        App { inner, arguments } => App {
+            inner: inner.clone(),
+            arguments: Box::new(substitute(arguments, renaming)),
+        },
+        ty => ty.clone(),
+    }
+}
+
+// Collects the names defined in a sequence of definitions.
+pub(crate) fn definitions(expr: &Node, defs: &mut Vec<String>) {
+    match expr {
+        LetNode(Let { name, .. }) => defs.push(name.clone()),
+        // Types are defined along with their constructors.
+        UnOpNode(UnOp { name, inner, .. }) if name == "type" => {
+            if let LetNode(decl) = &**inner {
+                defs.push(decl.name.clone());
+                let ctors = constructors(&decl.value).unwrap_or_default();
+                defs.extend(ctors.into_iter().map(|ctor| ctor.name));
+            }
+        }
+        // Interfaces are defined along with their operations.
+        UnOpNode(UnOp { name, inner, .. }) if name == "interface" => {
+            if let LetNode(decl) = &**inner {
+                defs.push(decl.name.clone());
+                let sigs = signatures(&decl.value).unwrap_or_default();
+                defs.extend(sigs.into_iter().map(|sig| sig.name));
+            }
+        }
+        BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == ";" || name == "," => {
+            definitions(left, defs);
+            definitions(right, defs);
+        }
+        _ => {}
+    }
+}
+
+// Finds the `type` declarations in a sequence of definitions.
+pub(crate) fn type_declarations<'a>(expr: &'a Node, decls: &mut Vec<&'a UnOp>) {
+    match expr {
+        UnOpNode(decl) if decl.name == "type" => decls.push(decl),
+        BinOpNode(BinOp {
+            name, left, right, ..
+        }) if name == ";" || name == "," => {
+            type_declarations(left, decls);
+            type_declarations(right, decls);
         }
+        _ => {}
     }
 }
 
@@ -204,6 +552,7 @@ mod tests {
     use super::*;
     use crate::ast::ToNode;
     use crate::database::DBStorage;
+    use crate::primitives::{bit_type, i32_type, string_type};
 
     type Test = Result<(), TError>;
 
@@ -260,7 +609,7 @@ mod tests {
         assert_type("x=12", "(x=I32)")
     }
 
-    // #[test]
+    #[test]
     fn infer_type_of_let_string_to_i32() -> Test {
         assert_type("x(s: String)=12", "(x=(s=String)->I32)")
     }
@@ -275,7 +624,7 @@ mod tests {
         assert_type("x=\"12\";x", "String")
     }
 
-    //#[test]
+    #[test]
     fn infer_type_of_pair_str_i32() -> Test {
         assert_type("(\"12\",23)", "(String, I32)")
     }
@@ -327,6 +676,91 @@ mod tests {
 
     #[test]
     fn infer_type_of_argv() -> Test {
-        assert_type("argv", "(it=I32) -> String")
+        assert_type("argv", "(it=I32) -> (String & Args)")
+    }
+
+    #[test]
+    fn infer_type_of_function_from_operators() -> Test {
+        assert_type("x(n)=n*2;x(n=3)", "I32")
+    }
+
+    #[test]
+    fn infer_type_of_recursive_function() -> Test {
+        assert_type("fac(n)=(n<=1)-|1?n*fac(n=n-1);fac(n=4)", "I32")
+    }
+
+    #[test]
+    fn infer_type_of_polymorphic_if() -> Test {
+        assert_type(
+            "choose(it, then, else)=it-|then?else;a=choose(it=true, then=1, else=2),b=choose(it=false, then=\"a\", else=\"b\")",
+            "(a=I32,b=String)",
+        )
+    }
+
+    #[test]
+    fn infer_type_of_tuple_fields() -> Test {
+        assert_type("(\"12\", 23).1", "I32")?;
+        assert_type("let (a, b) = (\"12\", 23); a", "String")?;
+        assert_type("(3, y=\"4\").y", "String")
+    }
+
+    #[test]
+    fn infer_type_of_default() -> Test {
+        assert_type("f(x)=x ?: 0;f(x=())", "I32")
+    }
+
+    #[test]
+    fn infer_type_of_polymorphic_id() -> Test {
+        assert_type("id(x)=x;id(x=3),id(x=\"a\")", "(left=I32,right=String)")
+    }
+
+    #[test]
+    fn infer_effects_through_calls() -> Test {
+        assert_type(
+            "f(x)=print(it=x);g(y)=f(x=y);g",
+            "(y=String) -> (Unit & Stdout)",
+        )
+    }
+
+    fn assert_type_error(prog_str: &'static str, expected: Val, found: Val) -> Test {
+        let mut storage = DBStorage::default();
+        let prog_module = storage.module_name("test/prog.tk".to_owned());
+        let prog = storage.parse_str(prog_module, prog_str)?;
+        match infer(&mut storage, &prog, &rec![]) {
+            Err(TError::UnexpectedType(_, exp, fnd, _)) => {
+                assert_eq!((*exp, *fnd), (expected, found));
+            }
+            result => panic!("expected a type error, got {:?}", result),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn type_error_names_expected_and_found_types() -> Test {
+        assert_type_error("x(n)=n*2;x(n=\"a\")", i32_type(), string_type())
+    }
+
+    #[test]
+    fn type_error_in_requirement() -> Test {
+        assert_type_error("3-|4", bit_type(), i32_type())
+    }
+
+    #[test]
+    fn infer_type_of_struct_as_function() -> Test {
+        assert_type("f(g: (field='a'|'b') -> I32) = 1; f(g=(a=3, b=4))", "I32")
+    }
+
+    #[test]
+    fn type_error_for_struct_as_unit() -> Test {
+        assert_type_error(
+            "f(x: ()) = 1; f(x=(a=3, b=4))",
+            unit_type(),
+            rec!["a" => i32_type(), "b" => i32_type()],
+        )
+    }
+
+    #[test]
+    fn type_error_in_monomorphic_argument() -> Test {
+        assert_type_error("f(g)=g(x=1)+g(x=\"a\")", i32_type(), string_type())
     }
 }
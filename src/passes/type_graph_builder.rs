@@ -0,0 +1,2060 @@
+use crate::ast::*;
+use crate::database::DBStorage;
+use crate::errors::TError;
+use crate::passes::type_checker::{
+    definitions, describe, eval_type_with, substitute, type_declarations, Res, TypeChecker,
+};
+use crate::primitives::{
+    bit_type, char_type, data_constructors, data_type, i32_type, is_list, list_type, never_type,
+    optional_inner, optional_type, prim_type, string_type, type_type, unit_type, variable, Frame,
+    Prim::*, Val, Val::*,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use Node::*;
+
+use crate::type_graph::*;
+
+// Walks the AST, recording the type of each definition in the type graph.
+#[derive(Default)]
+pub struct TypeGraphBuilder {
+    // Values of unexpected types, which are collected rather than ending the check when they are
+    // deferred, so that the effects and annotations of the rest of the module are still checked.
+    deferred: Option<Vec<TError>>,
+}
+
+#[derive(Debug, Default)]
+pub struct State {
+    pub graph: TypeGraph,
+    pub path: Path,
+    // Types for definitions that are in scope, but that haven't been checked yet.
+    pending: HashMap<Path, Val>,
+    // Effects used by the function that is currently being checked.
+    effects: BTreeSet<String>,
+    // The path of the function that is currently being checked.
+    function: Path,
+    // The path of the module, i.e. the function that isn't in any other function.
+    module: Path,
+    // The innermost closure (i.e. an abstraction or a function in a function) being checked, which
+    // captures the values of the variables around it, so it can't assign to them.
+    closure: Option<Path>,
+    // The variables declared with `:=` so far.
+    mutable: HashSet<Path>,
+    // Types declared with `type`, which can be used in annotations.
+    types: Frame,
+    checker: TypeChecker,
+}
+
+impl TypeGraphBuilder {
+    // Infers the type of an expression, given the types of the symbols in `env`.
+    pub fn infer(&mut self, storage: &mut DBStorage, expr: &Node, env: &Val) -> Res {
+        let mut state = State::default();
+        if let Struct(fields) = env {
+            for (name, ty) in fields.iter() {
+                state.graph.require_assignable(&[Symbol::new(name)], ty)?;
+            }
+        }
+        let ty = self.visit(storage, &mut state, expr)?;
+        Ok(state.checker.resolve(&ty))
+    }
+
+    // Finds the effects that evaluating an expression would use.
+    pub fn effects(
+        &mut self,
+        storage: &mut DBStorage,
+        expr: &Node,
+    ) -> Result<BTreeSet<String>, TError> {
+        let mut state = State::default();
+        self.visit(storage, &mut state, expr)?;
+        Ok(state.effects)
+    }
+
+    // Checks a module's effects and annotations, returning the values of unexpected types that it
+    // found on the way, which are only errors if the code that has them is run.
+    pub fn check_deferring_values(
+        storage: &mut DBStorage,
+        module: &Path,
+    ) -> Result<Vec<TError>, TError> {
+        let mut builder = TypeGraphBuilder {
+            deferred: Some(vec![]),
+        };
+        builder.visit_root(storage, module)?;
+        Ok(builder.deferred.unwrap_or_default())
+    }
+
+    // Records a value of an unexpected type if those are being deferred, giving it an unknown type.
+    fn defer(&mut self, state: &mut State, res: Res) -> Res {
+        match (res, &mut self.deferred) {
+            (Err(err @ TError::UnexpectedType(..)), Some(deferred)) => {
+                deferred.push(err);
+                Ok(state.checker.fresh())
+            }
+            (res, _) => res,
+        }
+    }
+
+    fn bind(&mut self, state: &mut State, path: PathRef, ty: &Val, info: &Info) -> Res {
+        let prev = state.graph.get_type(path).ok();
+        let assigned = state.checker.resolve(ty);
+        state.graph.require_assignable(path, &assigned)?;
+        let ty = state.graph.get_type(path)?;
+        // Functions that never return are unsatisfiable, but are still defined.
+        if ty.is_sat().is_false() && !assigned.is_sat().is_false() {
+            return Err(TError::UnexpectedType(
+                path_to_string(path),
+                Box::new(state.checker.resolve(&prev.unwrap_or_else(unit_type))),
+                Box::new(state.checker.resolve(&ty)),
+                info.clone(),
+            ));
+        }
+        Ok(ty)
+    }
+
+    // Finds the closest definition of a symbol, searching outwards from the current path.
+    fn definition(&self, state: &State, name: &str) -> Option<(Path, Val)> {
+        let mut search = state.path.clone();
+        loop {
+            search.push(Symbol::new(name));
+            let ty = state.graph.get_type(&search).ok();
+            if let Some(ty) = ty.or_else(|| state.pending.get(&search).cloned()) {
+                return Some((search, ty));
+            }
+            search.pop(); // Strip the name off.
+            search.pop()?;
+        }
+    }
+
+    fn lookup(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        name: &str,
+        info: &Info,
+    ) -> Res {
+        if let Some((_, ty)) = self.definition(state, name) {
+            let ty = state.checker.resolve(&ty);
+            return Ok(state.checker.instantiate(&ty));
+        }
+        state.checker.lookup_extern(storage, name, info)
+    }
+
+    // Finds the type variables that are fixed by definitions visible from a path.
+    fn env_vars(&self, state: &State, path: PathRef) -> BTreeSet<String> {
+        let scope = &path[..path.len() - 1];
+        let mut vars = BTreeSet::new();
+        for (other, ty) in state.graph.iter().chain(state.pending.iter()) {
+            if other != path && !other.is_empty() && scope.starts_with(&other[..other.len() - 1]) {
+                state.checker.free_vars(ty, &mut vars);
+            }
+        }
+        vars
+    }
+
+    // Runs a visitor in a new anonymous scope, forgetting the scope's definitions afterwards.
+    fn in_anon<T>(
+        &mut self,
+        state: &mut State,
+        f: impl FnOnce(&mut Self, &mut State) -> Result<T, TError>,
+    ) -> Result<T, TError> {
+        state.path.push(Symbol::Anon(0));
+        let res = f(self, state);
+        state.graph.forget(&state.path);
+        let scope = &state.path;
+        state.pending.retain(|path, _| !path.starts_with(scope));
+        state.path.pop();
+        res
+    }
+
+    // Visits an expression that is only run once a variable is known to have a value.
+    fn visit_narrowed(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        checked: &Sym,
+        expr: &Node,
+    ) -> Res {
+        let ty = self.lookup(storage, state, &checked.name, &checked.info)?;
+        let inner = match optional_inner(&state.checker.resolve(&ty)) {
+            Some(inner) => inner,
+            None => return self.visit(storage, state, expr),
+        };
+        self.in_anon(state, |this, state| {
+            let mut path = state.path.clone();
+            path.push(Symbol::new(&checked.name));
+            this.bind(state, &path, &inner, &checked.info)?;
+            this.visit(storage, state, expr)
+        })
+    }
+
+    // Records the effects of a value, returning the type of the value itself.
+    fn perform(&mut self, state: &mut State, ty: Val) -> Val {
+        match ty {
+            WithRequirement(ty, effs) => {
+                state.effects.extend(effs);
+                self.perform(state, *ty)
+            }
+            ty => ty,
+        }
+    }
+
+    // Records the type of a definition, checking it against any uses from before it was defined.
+    fn define(
+        &mut self,
+        state: &mut State,
+        path: PathRef,
+        ty: &Val,
+        context: &str,
+        info: &Info,
+    ) -> Res {
+        if let Some(def) = state.pending.remove(path) {
+            state.checker.unify(&def, ty, context, info)?;
+        }
+        let env_vars = self.env_vars(state, path);
+        let ty = state.checker.generalize(&env_vars, ty);
+        self.bind(state, path, &ty, info)
+    }
+
+    // Checks `type Name = ...`, defining the type and the types of its constructors.
+    fn visit_type(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let mut path = state.path.clone();
+        path.push(Symbol::new(&decl.name));
+        if state.graph.get_type(&path).is_ok() {
+            // Already declared ahead of the definitions that use it.
+            return Ok(Struct(vec![(decl.name, type_type())]));
+        }
+        let ctors = constructors(&decl.value)?;
+        // Fields can refer to the type itself, e.g. `type L = Nil | Cons(h: I32, t: L)`.
+        state.types.insert(decl.name.clone(), variable(&decl.name));
+        let mut alternatives = vec![];
+        for ctor in ctors.iter() {
+            let mut fields = vec![];
+            for field in ctor.fields.iter() {
+                let ty = match &field.info.ty {
+                    Some(ty) => eval_type_with(storage, ty, &state.types)?,
+                    None => unit_type(),
+                };
+                fields.push((field.name.clone(), ty));
+            }
+            alternatives.push((ctor.name.clone(), fields));
+        }
+        let ty = data_type(alternatives.clone());
+        state.types.insert(decl.name.clone(), ty.clone());
+        state.checker.declare_type(&decl.name, ty.clone());
+        for (ctor, (name, fields)) in ctors.iter().zip(alternatives) {
+            // Constructors without fields are values, rather than functions.
+            let ctor_ty = if ctor.fields.is_empty() {
+                ty.clone()
+            } else {
+                Function {
+                    intros: BTreeSet::new(),
+                    arguments: Box::new(Struct(fields)),
+                    results: Box::new(ty.clone()),
+                }
+            };
+            let mut path = state.path.clone();
+            path.push(Symbol::new(&name));
+            self.define(state, &path, &ctor_ty, &name, &ctor.info)?;
+        }
+        self.define(state, &path, &type_type(), &decl.name, &decl.info)?;
+        Ok(Struct(vec![(decl.name, type_type())]))
+    }
+
+    // Checks `interface Name = op(arg: Self): Type, ...`, defining the interface's operations.
+    // Each operation can be used with any type that implements the interface.
+    fn visit_interface(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let mut types = state.types.clone();
+        types.insert("Self".to_string(), variable("Self"));
+        let mut operations = vec![];
+        for sig in signatures(&decl.value)? {
+            let mut arguments = vec![];
+            for arg in sig.args.iter() {
+                let ty = match &arg.info.ty {
+                    Some(ty) => eval_type_with(storage, ty, &types)?,
+                    None => unit_type(),
+                };
+                arguments.push((arg.name.clone(), ty));
+            }
+            let results = match &sig.info.ty {
+                Some(ty) => eval_type_with(storage, ty, &types)?,
+                None => unit_type(),
+            };
+            let ty = Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(Struct(arguments)),
+                results: Box::new(results),
+            };
+            operations.push((sig.name, ty, sig.info));
+        }
+        state.checker.declare_interface(
+            &decl.name,
+            operations
+                .iter()
+                .map(|(name, ty, _)| (name.clone(), ty.clone()))
+                .collect(),
+        );
+        let renaming = HashMap::from([("Self".to_string(), variable("a"))]);
+        for (name, ty, info) in operations {
+            let ty = match substitute(&ty, &renaming) {
+                Function {
+                    arguments, results, ..
+                } => Function {
+                    intros: BTreeSet::from([("a".to_string(), variable(&decl.name))]),
+                    arguments,
+                    results,
+                },
+                ty => ty,
+            };
+            let mut path = state.path.clone();
+            path.push(Symbol::new(&name));
+            self.define(state, &path, &ty, &name, &info)?;
+        }
+        let mut path = state.path.clone();
+        path.push(Symbol::new(&decl.name));
+        self.define(state, &path, &type_type(), &decl.name, &decl.info)?;
+        Ok(Struct(vec![(decl.name, type_type())]))
+    }
+
+    // Checks `impl Interface(Type) = (op(args) = ..., ...)`, requiring a method for each operation.
+    fn visit_impl(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let decl = expr.inner.as_let()?;
+        let imp = implementation(&decl)?;
+        let info = decl.get_info();
+        let sym = Sym {
+            name: imp.ty.to_string(),
+            info: info.clone(),
+        };
+        let ty = eval_type_with(storage, &sym.into_node(), &state.types)?;
+        let operations = state.checker.interface(imp.interface).ok_or_else(|| {
+            TError::UnknownSymbol(
+                imp.interface.to_string(),
+                info.clone(),
+                "interfaces".to_string(),
+            )
+        })?;
+        let missing: Vec<String> = operations
+            .iter()
+            .filter(|(name, _)| !imp.methods.iter().any(|method| method.name == *name))
+            .map(|(name, _)| format!("`{}`", name))
+            .collect();
+        if !missing.is_empty() {
+            return Err(TError::IncompleteImplementation(missing.join(", "), info));
+        }
+        // Methods can rely on the type implementing the interface, e.g. to recurse.
+        if !state.checker.implement(imp.interface, ty.clone()) {
+            return Err(TError::DuplicateImplementation(
+                imp.interface.to_string(),
+                Box::new(ty),
+                info,
+            ));
+        }
+        let renaming = HashMap::from([("Self".to_string(), ty)]);
+        state.path.push(imp.scope());
+        let res = self.visit_methods(storage, state, &imp, &operations, &renaming);
+        state.path.pop();
+        res?;
+        Ok(unit_type())
+    }
+
+    // Checks each method of an implementation against the type of its operation.
+    fn visit_methods(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        imp: &Implementation,
+        operations: &[(String, Val)],
+        renaming: &HashMap<String, Val>,
+    ) -> Result<(), TError> {
+        for method in imp.methods.iter() {
+            let ty = match operations.iter().find(|(name, _)| *name == method.name) {
+                Some((_, ty)) => substitute(ty, renaming),
+                None => {
+                    return Err(TError::TypeMismatch(
+                        format!(
+                            "`{}` is not an operation of `{}`",
+                            method.name, imp.interface
+                        ),
+                        Box::new(unit_type()),
+                        method.get_info(),
+                    ))
+                }
+            };
+            let mut path = state.path.clone();
+            path.push(Symbol::new(&method.name));
+            state.pending.insert(path, ty);
+            self.visit_let(storage, state, method)?;
+        }
+        Ok(())
+    }
+
+    // Checks `match(value, Ctor(fields) = result, ...)`, requiring a clause for each constructor.
+    fn visit_match(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
+        let info = &expr.info;
+        let context = describe(storage, &expr.clone().into_node());
+        let (values, clauses): (Vec<&Let>, Vec<&Let>) =
+            expr.args.iter().partition(|arg| arg.name == "it");
+        let value_ty = match values.as_slice() {
+            [value] => self.visit(storage, state, &value.value)?,
+            _ => {
+                return Err(TError::TypeMismatch(
+                    "expected a value to match on".to_string(),
+                    Box::new(unit_type()),
+                    info.clone(),
+                ))
+            }
+        };
+        // A `_` clause matches the constructors that don't have clauses, so it has to be last.
+        if let Some(at) = clauses.iter().position(|clause| clause.name == "_") {
+            if let Some(clause) = clauses.get(at + 1) {
+                return Err(TError::UnreachableClause(
+                    clause.name.clone(),
+                    clause.info.clone(),
+                ));
+            }
+            if let Some(arg) = clauses[at].args.iter().flatten().next() {
+                return Err(TError::TypeMismatch(
+                    format!(
+                        "`_` matches any constructor, so it has no field `{}`",
+                        arg.name
+                    ),
+                    Box::new(value_ty),
+                    arg.info.clone(),
+                ));
+            }
+        }
+        // Only the first clause for a constructor could be used.
+        for (index, clause) in clauses.iter().enumerate() {
+            if clauses[..index]
+                .iter()
+                .any(|other| other.name == clause.name)
+            {
+                return Err(TError::UnreachableClause(
+                    clause.name.clone(),
+                    clause.info.clone(),
+                ));
+            }
+        }
+        let wildcard = clauses.last().filter(|clause| clause.name == "_").copied();
+        let clauses: Vec<&Let> = clauses
+            .into_iter()
+            .filter(|clause| clause.name != "_")
+            .collect();
+        // Without a known type, the value has the type of the first clause's constructor.
+        let ty = match (
+            state.checker.unfold(&state.checker.resolve(&value_ty)),
+            clauses.first(),
+        ) {
+            (ty, _) if data_constructors(&ty).is_some() => ty,
+            (_, Some(clause)) => {
+                let ty = match self.lookup(storage, state, &clause.name, &clause.info)? {
+                    Function { results, .. } => *results,
+                    ty => ty,
+                };
+                state.checker.unify(&ty, &value_ty, &context, info)?;
+                state.checker.resolve(&ty)
+            }
+            (ty, None) => ty,
+        };
+        let ctors: Vec<(String, Vec<(String, Val)>)> = data_constructors(&ty)
+            .ok_or_else(|| {
+                TError::TypeMismatch(
+                    "expected a value of a data type to match on".to_string(),
+                    Box::new(ty.clone()),
+                    info.clone(),
+                )
+            })?
+            .into_iter()
+            .map(|(name, fields)| (name.to_string(), fields.to_vec()))
+            .collect();
+        // A clause for another type's constructor is reported before the constructors it
+        // leaves out.
+        for clause in clauses.iter() {
+            if !ctors.iter().any(|(name, _)| *name == clause.name) {
+                return Err(TError::TypeMismatch(
+                    format!("`{}` is not a constructor of {}", clause.name, ty),
+                    Box::new(ty.clone()),
+                    clause.info.clone(),
+                ));
+            }
+        }
+        let missing: Vec<String> = ctors
+            .iter()
+            .filter(|(name, _)| !clauses.iter().any(|clause| clause.name == *name))
+            .map(|(name, _)| format!("`{}`", name))
+            .collect();
+        if !missing.is_empty() && wildcard.is_none() {
+            return Err(TError::NonExhaustiveMatch(missing.join(", "), info.clone()));
+        }
+        let results = state.checker.fresh();
+        for clause in clauses.into_iter().chain(wildcard) {
+            let fields: &[(String, Val)] = ctors
+                .iter()
+                .find(|(name, _)| *name == clause.name)
+                .map_or(&[], |(_, fields)| fields);
+            // Fields are bound by position, like the arguments of clauses, and `_` skips one.
+            let args: Vec<&Let> = clause.args.iter().flatten().collect();
+            if !args.is_empty() && args.len() != fields.len() {
+                return Err(TError::TypeMismatch(
+                    format!(
+                        "`{}` has {} fields, but its case binds {}",
+                        clause.name,
+                        fields.len(),
+                        args.len()
+                    ),
+                    Box::new(ty.clone()),
+                    clause.info.clone(),
+                ));
+            }
+            let clause_ty = self.in_anon(state, |this, state| {
+                for (arg, (_, field_ty)) in args.iter().zip(fields) {
+                    if arg.name == "_" {
+                        continue;
+                    }
+                    let mut path = state.path.clone();
+                    path.push(Symbol::new(&arg.name));
+                    this.bind(state, &path, field_ty, &arg.info)?;
+                }
+                this.visit(storage, state, &clause.value)
+            })?;
+            let clause_context = describe(storage, &clause.clone().into_node());
+            state
+                .checker
+                .unify(&results, &clause_ty, &clause_context, &clause.info)?;
+        }
+        Ok(state.checker.resolve(&results))
+    }
+
+    // Finds the type of `[a, b, c]`, a list of the elements' (shared) type.
+    fn visit_list(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let ty = state.checker.fresh();
+        for element in elements(&expr.inner) {
+            let element_ty = self.visit(storage, state, element)?;
+            let context = describe(storage, element);
+            state
+                .checker
+                .unify(&ty, &element_ty, &context, &element.get_info())?;
+        }
+        Ok(list_type(state.checker.resolve(&ty)))
+    }
+
+    // Finds the type of `(a, b=c)`, a struct of the fields' types.
+    fn visit_tuple(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        // Named fields are definitions, but only in the tuple.
+        self.in_anon(state, |this, state| {
+            let mut fields = vec![];
+            for (name, field) in tuple_fields(&expr.inner)? {
+                let ty = match field {
+                    Node::LetNode(def) => this.visit_let(storage, state, def)?.access(&name),
+                    field => this.visit(storage, state, field)?,
+                };
+                fields.push((name, state.checker.resolve(&ty)));
+            }
+            Ok(Struct(fields))
+        })
+    }
+
+    // Checks that the type of an expression matches its annotation.
+    // Returns the type of the expression, which is the annotated type if it never has a value, and
+    // the effects that the annotation allows.
+    fn check_annotation(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        annotation: &Node,
+        ty: &Val,
+        context: &str,
+    ) -> Result<(Val, BTreeSet<String>), TError> {
+        let (expected, allowed) = match eval_type_with(storage, annotation, &state.types)? {
+            WithRequirement(ty, effs) => (*ty, effs.into_iter().collect()),
+            ty => (ty, BTreeSet::new()),
+        };
+        let info = annotation.get_info();
+        match state.checker.unify(&expected, ty, context, &info) {
+            Err(TError::UnexpectedType(..)) => {
+                let found = state.checker.resolve(ty);
+                Err(TError::TypeMismatch(
+                    format!(
+                        "`{}` is annotated as {} but has type {}",
+                        context, expected, found
+                    ),
+                    Box::new(found),
+                    info,
+                ))
+            }
+            Ok(()) if state.checker.resolve(ty) == never_type() => Ok((expected, allowed)),
+            res => res.map(|()| (ty.clone(), allowed)),
+        }
+    }
+
+    // Checks that a tuple has the same number of values as the pattern that destructures it.
+    fn check_pattern(
+        &mut self,
+        state: &mut State,
+        whole: &Node,
+        pattern: &Node,
+        ty: &Val,
+        info: &Info,
+    ) -> Result<(), TError> {
+        let (inner, fields) = match (pattern, state.checker.resolve(ty)) {
+            (UnOpNode(UnOp { name, inner, .. }), Struct(fields)) if name == "()" => (inner, fields),
+            _ => return Ok(()),
+        };
+        let pattern_fields = tuple_fields(inner)?;
+        let positional = |name: &&String| name.parse::<usize>().is_ok();
+        let expected = pattern_fields
+            .iter()
+            .map(|(name, _)| name)
+            .filter(positional)
+            .count();
+        let found = fields
+            .iter()
+            .map(|(name, _)| name)
+            .filter(positional)
+            .count();
+        if expected != found {
+            return Err(TError::TypeMismatch(
+                format!(
+                    "`let {}` takes {} values, but the tuple has {}",
+                    show_pattern(whole),
+                    expected,
+                    found
+                ),
+                Box::new(Struct(fields)),
+                info.clone(),
+            ));
+        }
+        for (field, elem) in pattern_fields.iter() {
+            if let Some((_, ty)) = fields.iter().find(|(name, _)| name == field) {
+                self.check_pattern(state, whole, field_value(elem), ty, info)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_param(&mut self, storage: &mut DBStorage, state: &mut State, arg: &Let) -> Res {
+        let ty = match (&arg.args, &*arg.value) {
+            (None, ValNode(val, _)) if *val == unit_type() => {
+                let ty = state.checker.fresh();
+                let mut path = state.path.clone();
+                path.push(Symbol::new(&arg.name));
+                self.bind(state, &path, &ty, &arg.info)?;
+                if let Some(annotation) = &arg.info.ty {
+                    self.check_annotation(storage, state, annotation, &ty, &arg.name)?;
+                }
+                ty
+            }
+            _ => self.visit_let(storage, state, arg)?.access(&arg.name),
+        };
+        Ok(state.checker.resolve(&ty))
+    }
+
+    // Checks a call to a value of type `op_ty` with arguments of types `arguments`.
+    fn apply_type(
+        &mut self,
+        state: &mut State,
+        op_ty: &Val,
+        arguments: Val,
+        context: &str,
+        info: &Info,
+    ) -> Res {
+        let results = state.checker.fresh();
+        let call_ty = Function {
+            intros: BTreeSet::new(),
+            arguments: Box::new(arguments),
+            results: Box::new(results.clone()),
+        };
+        state.checker.unify(op_ty, &call_ty, context, info)?;
+        let results = state.checker.resolve(&results);
+        Ok(self.perform(state, results))
+    }
+
+    // Finds the type of an operator applied to values of the given types.
+    fn operator_type(
+        &mut self,
+        storage: &mut DBStorage,
+        state: &mut State,
+        expr: &BinOp,
+        left_ty: Val,
+        right_ty: Val,
+        context: &str,
+    ) -> Res {
+        let BinOp { name, info, .. } = expr;
+        if name == "+"
+            && (state.checker.resolve(&left_ty) == string_type()
+                || state.checker.resolve(&right_ty) == string_type())
+        {
+            // `+` also concatenates strings with any displayable value.
+            return Ok(string_type());
+        }
+        let op_ty = self.lookup(storage, state, name, info)?;
+        let arguments = match state.checker.resolve(&op_ty) {
+            // Operators like `-` are declared as prefix operators, but can also be used
+            // between two values of the same type.
+            Function {
+                arguments: args, ..
+            } if matches!(&*args, Struct(fields) if !fields.iter().any(|(name, _)| name == "left")) =>
+            {
+                state.checker.unify(&left_ty, &right_ty, context, info)?;
+                vec![("it".to_string(), left_ty)]
+            }
+            _ => vec![
+                ("left".to_string(), left_ty),
+                ("right".to_string(), right_ty),
+            ],
+        };
+        self.apply_type(state, &op_ty, Struct(arguments), context, info)
+    }
+}
+
+// Whether a call is to a constructor of the data type that it gives.
+fn is_constructor(state: &State, inner: &Node, results: &Val) -> bool {
+    let name = match inner {
+        SymNode(sym) => &sym.name,
+        _ => return false,
+    };
+    let ty = state.checker.unfold(&state.checker.resolve(results));
+    data_constructors(&ty).is_some_and(|ctors| ctors.iter().any(|(ctor, _)| ctor == name))
+}
+
+impl Visitor<State, Val, TypeGraph, Path> for TypeGraphBuilder {
+    fn visit(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Node) -> Res {
+        let ty = match expr {
+            SymNode(n) => self.visit_sym(storage, state, n),
+            ValNode(n, _) => self.visit_val(storage, state, n),
+            ApplyNode(n) => self.visit_apply(storage, state, n),
+            AbsNode(n) => self.visit_abs(storage, state, n),
+            // Annotations on definitions are checked against the defined value.
+            LetNode(n) => {
+                let res = self.visit_let(storage, state, n);
+                return self.defer(state, res);
+            }
+            UnOpNode(n) => self.visit_un_op(storage, state, n),
+            BinOpNode(n) => self.visit_bin_op(storage, state, n),
+        };
+        let ty = self.defer(state, ty)?;
+        if let Some(annotation) = &expr.get_info().ty {
+            let context = describe(storage, expr);
+            self.check_annotation(storage, state, annotation, &ty, &context)?;
+        }
+        Ok(ty)
+    }
+
+    fn visit_root(&mut self, storage: &mut DBStorage, module: &Path) -> Result<TypeGraph, TError> {
+        let expr = &storage.parse_module(module.clone())?;
+        if storage.debug_level() > 0 {
+            eprintln!(
+                "building symbol table & type graph for file... {}",
+                path_to_string(module)
+            );
+        }
+        let mut state = State {
+            path: module.clone(),
+            function: module.clone(),
+            module: module.clone(),
+            ..State::default()
+        };
+        let ty = self.visit(storage, &mut state, expr)?;
+        let res = state
+            .graph
+            .require_assignable(&state.path, &ty)
+            .map(|()| ty);
+        self.defer(&mut state, res)?;
+        let checker = &state.checker;
+        state.graph.map_types(|ty| checker.resolve(ty));
+        Ok(state.graph)
+    }
+
+    fn visit_sym(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Sym) -> Res {
+        if storage.debug_level() > 1 {
+            eprintln!(
+                "visiting sym {} {}",
+                path_to_string(&state.path),
+                &expr.name
+            );
+        }
+        // Clauses define their own `resume`, so any other use has nothing to resume.
+        if expr.name == "resume" && self.definition(state, &expr.name).is_none() {
+            return Err(TError::ResumeOutsideHandler(expr.info.clone()));
+        }
+        // A closure would only see the value that a variable had when it was made, so it can't use
+        // variables that can change.
+        if let Some(closure) = &state.closure {
+            let captured = self.definition(state, &expr.name).is_some_and(|(path, _)| {
+                state.mutable.contains(&path) && !path.starts_with(closure)
+            });
+            if captured {
+                return Err(TError::CapturedVariable(
+                    expr.name.clone(),
+                    expr.info.clone(),
+                ));
+            }
+        }
+        let ty = self.lookup(storage, state, &expr.name, &expr.info)?;
+        Ok(self.perform(state, ty))
+    }
+
+    fn visit_val(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Val) -> Res {
+        Ok(match expr {
+            PrimVal(BuiltIn(name)) => self.lookup(storage, state, name, &Info::default())?,
+            PrimVal(prim) => match prim_type(prim) {
+                Some(ty) => ty,
+                None => state.checker.fresh(),
+            },
+            Product(vals) if vals.is_empty() => unit_type(),
+            Lambda(node) => self.in_anon(state, |this, state| this.visit(storage, state, node))?,
+            Struct(vals) => {
+                let mut tys = vec![];
+                for (name, val) in vals.iter() {
+                    tys.push((name.clone(), self.visit_val(storage, state, val)?));
+                }
+                Struct(tys)
+            }
+            _ => state.checker.fresh(),
+        })
+    }
+
+    fn visit_apply(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Apply) -> Res {
+        let Apply { inner, args, info } = expr;
+        if let SymNode(sym) = &**inner {
+            if sym.name == "match" && self.definition(state, &sym.name).is_none() {
+                return self.visit_match(storage, state, expr);
+            }
+        }
+        let (arg_tys, inner_ty) = self.in_anon(state, |this, state| {
+            let mut arg_tys = vec![];
+            for arg in args.iter() {
+                let ty = this.visit_let(storage, state, arg)?.access(&arg.name);
+                arg_tys.push((arg.name.clone(), ty));
+            }
+            let inner_ty = this.visit(storage, state, inner)?;
+            Ok((arg_tys, inner_ty))
+        })?;
+        let context = describe(storage, &expr.clone().into_node());
+        match state.checker.resolve(&inner_ty) {
+            Function {
+                arguments, results, ..
+            } => {
+                let params = match *arguments {
+                    Struct(params) => params,
+                    ty => vec![("it".to_string(), ty)],
+                };
+                // Constructors need a value for each of their fields, and have no other arguments.
+                if is_constructor(state, inner, &results) {
+                    let (params, args) = (Struct(params), Struct(arg_tys));
+                    // Values can't be built without their fields, so this stops programs from
+                    // being run or built even when other unexpected types are deferred.
+                    if let Err(err) = state.checker.unify(&params, &args, &context, info) {
+                        self.deferred = None;
+                        return Err(err);
+                    }
+                    let results = state.checker.resolve(&results);
+                    return Ok(self.perform(state, results));
+                }
+                for (name, param_ty) in params.iter() {
+                    if let Some((_, arg_ty)) = arg_tys.iter().find(|(arg, _)| arg == name) {
+                        state.checker.unify(param_ty, arg_ty, &context, info)?;
+                    }
+                }
+                let results = state.checker.resolve(&results);
+                Ok(self.perform(state, results))
+            }
+            // Calling an unknown function tells us what arguments it accepts.
+            Variable(var) if state.checker.is_var(&var) => {
+                self.apply_type(state, &inner_ty, Struct(arg_tys), &context, info)
+            }
+            // Applying arguments to a value, evaluates it with those arguments in scope.
+            ty => Ok(ty),
+        }
+    }
+
+    fn visit_abs(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Abs) -> Res {
+        if storage.debug_level() > 1 {
+            eprintln!("visiting {} {}", path_to_string(&state.path), &expr.name);
+        }
+        self.in_anon(state, |this, state| {
+            let mut path = state.path.clone();
+            path.push(Symbol::new(&expr.name));
+            let ty = state.checker.fresh();
+            this.bind(state, &path, &ty, &expr.info)?;
+            // As with functions, the effects of the body happen when it is called.
+            let outer_effects = std::mem::take(&mut state.effects);
+            let outer_closure = state.closure.replace(state.path.clone());
+            let results = this.visit(storage, state, &expr.value);
+            state.closure = outer_closure;
+            let effects = std::mem::replace(&mut state.effects, outer_effects);
+            let results = results?;
+            let results = if effects.is_empty() {
+                results
+            } else {
+                WithRequirement(Box::new(results), effects.into_iter().collect())
+            };
+            Ok(Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(Struct(vec![(expr.name.clone(), ty)])),
+                results: Box::new(results),
+            })
+        })
+    }
+
+    fn visit_let(&mut self, storage: &mut DBStorage, state: &mut State, expr: &Let) -> Res {
+        if storage.debug_level() > 1 {
+            eprintln!("visiting {} {}", path_to_string(&state.path), &expr.name);
+        }
+        let Let {
+            name,
+            value,
+            args,
+            info,
+        } = expr;
+        let context = describe(storage, &expr.clone().into_node());
+        state.path.push(Symbol::new(name));
+        let path = state.path.clone();
+        if info.mutable {
+            state.mutable.insert(path.clone());
+        }
+        let ty = if let Some(args) = args {
+            // Functions can refer to themselves.
+            if !state.pending.contains_key(&path) {
+                let this = state.checker.fresh();
+                state.pending.insert(path.clone(), this);
+            }
+            // The effects of the body happen when the function is called, not when it is defined.
+            let outer_effects = std::mem::take(&mut state.effects);
+            let outer_function = std::mem::replace(&mut state.function, path.clone());
+            // Loops run in place, so they aren't closures.
+            let nested = outer_function != state.module || state.closure.is_some();
+            let outer_closure = if nested && !is_loop_step(expr) {
+                state.closure.replace(path.clone())
+            } else {
+                state.closure.clone()
+            };
+            let mut params = vec![];
+            for arg in args.iter() {
+                match self.visit_param(storage, state, arg) {
+                    Ok(ty) => params.push((arg.name.clone(), ty)),
+                    Err(err) => {
+                        state.path.pop();
+                        state.function = outer_function;
+                        state.closure = outer_closure;
+                        return Err(err);
+                    }
+                }
+            }
+            let results = self.visit(storage, state, value);
+            state.path.pop();
+            state.function = outer_function;
+            state.closure = outer_closure;
+            let mut results = results?;
+            let effects = std::mem::replace(&mut state.effects, outer_effects);
+            if let Some(annotation) = &info.ty {
+                // The annotation is on the result, so it is reported as the call that gives it.
+                let names: Vec<&str> = args.iter().map(|arg| arg.name.as_str()).collect();
+                let call = format!("{}({})", name, names.join(", "));
+                let (annotated, allowed) =
+                    self.check_annotation(storage, state, annotation, &results, &call)?;
+                results = annotated;
+                let disallowed: Vec<String> = effects.difference(&allowed).cloned().collect();
+                if !disallowed.is_empty() {
+                    return Err(TError::UnexpectedEffects(
+                        format!("`{}` uses {}", name, disallowed.join(", ")),
+                        annotation.get_info(),
+                    ));
+                }
+            }
+            let results = if effects.is_empty() {
+                results
+            } else {
+                WithRequirement(Box::new(results), effects.into_iter().collect())
+            };
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(Struct(params)),
+                results: Box::new(results),
+            }
+        } else {
+            state.path.pop();
+            let mut ty = self.visit(storage, state, value)?;
+            if let Some(annotation) = &info.ty {
+                ty = self
+                    .check_annotation(storage, state, annotation, &ty, &context)?
+                    .0;
+            }
+            if let Some(pattern) = &info.pattern {
+                self.check_pattern(state, pattern, pattern, &ty, info)?;
+            }
+            if name == LOOP_ITEMS {
+                // Report the items of a `for` as written, rather than the loop's use of them.
+                let len_ty = self.lookup(storage, state, "len", info)?;
+                let context = describe(storage, value);
+                let arguments = Struct(vec![("it".to_string(), ty.clone())]);
+                self.apply_type(state, &len_ty, arguments, &context, info)?;
+            }
+            ty
+        };
+        // Definitions can be used before their types are known.
+        let ty = self.define(state, &path, &ty, &context, info)?;
+        Ok(Struct(vec![(name.clone(), ty)]))
+    }
+
+    fn visit_un_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &UnOp) -> Res {
+        let UnOp { name, inner, info } = expr;
+        if name == "type" {
+            return self.visit_type(storage, state, expr);
+        }
+        if name == "interface" {
+            return self.visit_interface(storage, state, expr);
+        }
+        if name == "impl" {
+            return self.visit_impl(storage, state, expr);
+        }
+        if name == "[]" {
+            return self.visit_list(storage, state, expr);
+        }
+        if name == "()" {
+            return self.visit_tuple(storage, state, expr);
+        }
+        if name == "{}" {
+            return self.in_anon(state, |this, state| this.visit(storage, state, inner));
+        }
+        let inner_ty = self.visit(storage, state, inner)?;
+        let op_ty = self.lookup(storage, state, name, info)?;
+        let arguments = match state.checker.resolve(&op_ty) {
+            Function {
+                arguments: args, ..
+            } if !matches!(*args, Struct(_)) => inner_ty,
+            _ => Struct(vec![("it".to_string(), inner_ty)]),
+        };
+        let context = describe(storage, &expr.clone().into_node());
+        self.apply_type(state, &op_ty, arguments, &context, info)
+    }
+
+    fn visit_bin_op(&mut self, storage: &mut DBStorage, state: &mut State, expr: &BinOp) -> Res {
+        let BinOp {
+            name,
+            left,
+            right,
+            info,
+        } = expr;
+        let context = describe(storage, &expr.clone().into_node());
+        if name == "?." {
+            let left_ty = self.visit(storage, state, left)?;
+            let left_ty = state.checker.resolve(&left_ty);
+            if left_ty == unit_type() {
+                return Ok(left_ty);
+            }
+            let inner = optional_inner(&left_ty);
+            // The access is checked against the value, once it is known not to be `()`.
+            let ty = self.in_anon(state, |this, state| {
+                let mut path = state.path.clone();
+                path.push(Symbol::new(name));
+                this.bind(state, &path, inner.as_ref().unwrap_or(&left_ty), info)?;
+                let value = Sym {
+                    name: name.clone(),
+                    info: info.clone(),
+                };
+                this.visit_bin_op(storage, state, &optional_access(expr, value.into_node()))
+            })?;
+            let ty = state.checker.resolve(&ty);
+            return Ok(match inner {
+                // Optional results aren't nested, e.g. `half(n)?.half()` is still optional.
+                Some(_) => optional_type(optional_inner(&ty).unwrap_or(ty)),
+                None => ty,
+            });
+        }
+        if let Some(call) = method_call(expr) {
+            return self
+                .visit_apply(storage, state, &call)
+                .map_err(|err| method_error(&call, err));
+        }
+        if let Some(field) = field_name(expr) {
+            let left_ty = self.visit(storage, state, left)?;
+            return match state.checker.resolve(&left_ty) {
+                Struct(fields) => match fields.iter().find(|(name, _)| *name == field) {
+                    Some((_, ty)) => Ok(ty.clone()),
+                    None => Err(TError::UnknownField(
+                        field,
+                        Box::new(Struct(fields)),
+                        info.clone(),
+                    )),
+                },
+                // Values of data types and of types that aren't known yet may turn out to be
+                // structs, but numbers, strings and functions never have fields.
+                Union(_) => Ok(state.checker.fresh()),
+                Variable(name) if state.checker.is_var(&name) => Ok(state.checker.fresh()),
+                ty => Err(TError::UnknownField(field, Box::new(ty), info.clone())),
+            };
+        }
+        if name == "[]" {
+            let left_ty = self.visit(storage, state, left)?;
+            match state.checker.resolve(&left_ty) {
+                Function { .. } => return self.visit(storage, state, &index_call(expr)),
+                // Indexing a string gives one of its characters.
+                ty if ty == string_type() => {
+                    let index_ty = self.visit(storage, state, right)?;
+                    state
+                        .checker
+                        .unify(&i32_type(), &index_ty, &context, info)?;
+                    return Ok(char_type());
+                }
+                _ => {}
+            }
+        }
+        match name.as_str() {
+            ";" | "," => {
+                if let Some(merged) = merge_clauses(expr)? {
+                    return self.visit(storage, state, &merged);
+                }
+                // Definitions in a sequence can refer to each other.
+                let mut defs = vec![];
+                definitions(&expr.clone().into_node(), &mut defs);
+                for def in defs {
+                    let mut path = state.path.clone();
+                    path.push(Symbol::new(&def));
+                    if state.graph.get_type(&path).is_err() && !state.pending.contains_key(&path) {
+                        let ty = state.checker.fresh();
+                        state.pending.insert(path, ty);
+                    }
+                }
+                // Types can be used in annotations before they are declared.
+                let mut decls = vec![];
+                type_declarations(left, &mut decls);
+                type_declarations(right, &mut decls);
+                for decl in decls {
+                    self.visit_type(storage, state, decl)?;
+                }
+                let left_ty = self.visit(storage, state, left)?;
+                let right_ty = self.visit(storage, state, right)?;
+                if name == ";" {
+                    Ok(right_ty)
+                } else {
+                    let left_ty = state.checker.resolve(&left_ty);
+                    Ok(left_ty.merge(state.checker.resolve(&right_ty)))
+                }
+            }
+            "~" => {
+                let outer_effects = std::mem::take(&mut state.effects);
+                let ty = self.visit(storage, state, left);
+                let mut effects = std::mem::replace(&mut state.effects, outer_effects);
+                let ty = ty?;
+                let mut clauses = vec![];
+                definitions_in(right, &mut clauses);
+                for clause in clauses {
+                    let op_ty = self.lookup(storage, state, &clause.name, &clause.info)?;
+                    let op_ty = state.checker.resolve(&op_ty);
+                    let (op_args, op_results) = match op_ty.clone() {
+                        Function {
+                            arguments, results, ..
+                        } => (*arguments, *results),
+                        ty => (Struct(vec![]), ty),
+                    };
+                    // The effects of handled operations don't escape the handler.
+                    let op_results = match op_results {
+                        WithRequirement(results, effs) => {
+                            for eff in effs.iter() {
+                                effects.remove(eff);
+                            }
+                            *results
+                        }
+                        results => results,
+                    };
+                    let clause_ty = self.in_anon(state, |this, state| {
+                        // Resuming continues the handled expression, so it has the same type.
+                        let mut path = state.path.clone();
+                        path.push(Symbol::new("resume"));
+                        let resume = Function {
+                            intros: BTreeSet::new(),
+                            arguments: Box::new(rec!("it" => op_results.clone())),
+                            results: Box::new(ty.clone()),
+                        };
+                        this.bind(state, &path, &resume, &clause.info)?;
+                        // Inside its clause, an operation refers to the operation being handled.
+                        path.pop();
+                        path.push(Symbol::new(&clause.name));
+                        path.push(Symbol::new(&clause.name));
+                        this.bind(state, &path, &op_ty, &clause.info)?;
+                        let clause_ty = this.visit_let(storage, state, clause)?;
+                        Ok(state.checker.instantiate(&clause_ty.access(&clause.name)))
+                    })?;
+                    let context = describe(storage, &clause.clone().into_node());
+                    match state.checker.resolve(&clause_ty) {
+                        Function {
+                            arguments, results, ..
+                        } if clause.args.is_some() => {
+                            state
+                                .checker
+                                .unify(&op_args, &arguments, &context, &clause.info)?;
+                            // Clauses that don't resume replace the value of the handled expression.
+                            let results = self.perform(state, *results);
+                            state.checker.unify(&ty, &results, &context, &clause.info)?;
+                        }
+                        value => {
+                            state
+                                .checker
+                                .unify(&op_results, &value, &context, &clause.info)?;
+                        }
+                    }
+                }
+                state.effects.extend(effects);
+                Ok(state.checker.resolve(&ty))
+            }
+            "<-" | "+=" | "-=" | "*=" | "/=" | "%=" => {
+                if let (Some(closure), SymNode(sym)) = (&state.closure, &**left) {
+                    let captured = self
+                        .definition(state, &sym.name)
+                        .is_some_and(|(path, _)| !path.starts_with(closure));
+                    if captured {
+                        return Err(TError::CapturedAssignment(sym.name.clone(), info.clone()));
+                    }
+                }
+                let left_ty = self.visit(storage, state, left)?;
+                let right_ty = self.visit(storage, state, right)?;
+                let op_ty = self.lookup(storage, state, name, info)?;
+                let arguments = vec![
+                    ("left".to_string(), left_ty),
+                    ("right".to_string(), right_ty),
+                ];
+                let outer_effects = std::mem::take(&mut state.effects);
+                let ty = self.apply_type(state, &op_ty, Struct(arguments), &context, info);
+                let effects = std::mem::replace(&mut state.effects, outer_effects);
+                // Functions can change their own variables without it being visible to callers.
+                let local = match &**left {
+                    SymNode(sym) => self
+                        .definition(state, &sym.name)
+                        .is_some_and(|(path, _)| path.starts_with(&state.function)),
+                    _ => false,
+                };
+                if !local {
+                    state.effects.extend(effects);
+                }
+                ty
+            }
+            "?" => {
+                let left_ty = self.visit(storage, state, left)?;
+                let right_ty = match &**left {
+                    // The alternative of `x == () -| ...` is only taken when `x` has a value.
+                    BinOpNode(req) if req.name == "-|" => match null_checked(&req.left) {
+                        Some((checked, false)) => {
+                            self.visit_narrowed(storage, state, checked, right)?
+                        }
+                        _ => self.visit(storage, state, right)?,
+                    },
+                    _ => self.visit(storage, state, right)?,
+                };
+                // A branch that gives `()` makes the result optional, once the other branch's
+                // type is known (e.g. a loop's result is `()`, not optional).
+                let known = |ty: &Val| !matches!(ty, Variable(_) | WithRequirement(..));
+                match (
+                    state.checker.resolve(&left_ty),
+                    state.checker.resolve(&right_ty),
+                ) {
+                    (none, ty) | (ty, none) if none == unit_type() && ty != none && known(&ty) => {
+                        Ok(optional_type(optional_inner(&ty).unwrap_or(ty)))
+                    }
+                    _ => self.operator_type(storage, state, expr, left_ty, right_ty, &context),
+                }
+            }
+            "++" => {
+                let left_ty = self.visit(storage, state, left)?;
+                let right_ty = self.visit(storage, state, right)?;
+                // Lists are joined, rather than being shown as text.
+                if is_list(&state.checker.resolve(&left_ty))
+                    && is_list(&state.checker.resolve(&right_ty))
+                {
+                    state.checker.unify(&left_ty, &right_ty, &context, info)?;
+                    return Ok(state.checker.resolve(&left_ty));
+                }
+                self.operator_type(storage, state, expr, left_ty, right_ty, &context)
+            }
+            "-|" if matches!(null_checked(left), Some((_, true))) => {
+                let cond_ty = self.visit(storage, state, left)?;
+                state.checker.unify(&bit_type(), &cond_ty, &context, info)?;
+                let (checked, _) = null_checked(left).expect("Missing null check");
+                self.visit_narrowed(storage, state, checked, right)
+            }
+            "-|" => {
+                // Conditions are reported as written, e.g. rather than as the `if` they are part of.
+                let cond_ty = self.visit(storage, state, left)?;
+                let cond_context = describe(storage, left);
+                state
+                    .checker
+                    .unify(&bit_type(), &cond_ty, &cond_context, &left.get_info())?;
+                let right_ty = self.visit(storage, state, right)?;
+                self.operator_type(storage, state, expr, cond_ty, right_ty, &context)
+            }
+            "|=" => {
+                let ty = self.visit(storage, state, left)?;
+                let abs = match &**right {
+                    AbsNode(abs) => abs,
+                    right => return self.visit(storage, state, right),
+                };
+                let cond_ty = self.in_anon(state, |this, state| {
+                    let mut path = state.path.clone();
+                    path.push(Symbol::new(&abs.name));
+                    this.bind(state, &path, &ty, info)?;
+                    this.visit(storage, state, &abs.value)
+                })?;
+                state.checker.unify(&bit_type(), &cond_ty, &context, info)?;
+                Ok(state.checker.resolve(&ty))
+            }
+            _ => {
+                let left_ty = self.visit(storage, state, left)?;
+                let right_ty = self.visit(storage, state, right)?;
+                self.operator_type(storage, state, expr, left_ty, right_ty, &context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Symbol;
+    use crate::errors::TError;
+    use crate::primitives::{
+        f64_type, i32_type, i64_type, list_type, map_type, optional_type, string_type, with_effects,
+    };
+    use crate::type_graph::TypeGraph;
+    use pretty_assertions::assert_eq;
+
+    type Test = Result<(), TError>;
+
+    fn filename() -> String {
+        module_root().to_filename()
+    }
+
+    fn module_root() -> Symbol {
+        Symbol::Named("test".to_owned(), Some("tk".to_owned()))
+    }
+
+    fn get_tg(s: &str) -> Result<TypeGraph, TError> {
+        let mut storage = DBStorage::default();
+        storage.set_file(&filename(), s.to_string());
+        let module = storage.module_name(filename());
+        let mut tgb = TypeGraphBuilder::default();
+        let tg: TypeGraph = tgb.visit_root(&mut storage, &module)?;
+        Ok(tg)
+    }
+
+    #[test]
+    fn type_of_int_literal_is_i32() -> Test {
+        let tg = &mut get_tg("0")?;
+        assert_eq!(tg.get_type(&[module_root()])?, i32_type());
+        Ok(())
+    }
+
+    #[test]
+    fn type_of_variable_of_int_literal_is_i32() -> Test {
+        let tg = &mut get_tg("x=0")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("x")])?,
+            i32_type(),
+            "x has type i32"
+        );
+        assert_eq!(
+            tg.get_type(&[module_root()])?,
+            rec!("x" => i32_type()),
+            "program has type i32"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn type_of_struct_int_and_string() -> Test {
+        let tg = &mut get_tg("x=0, y='hi'")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("x")])?,
+            i32_type(),
+            "x has type i32"
+        );
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("y")])?,
+            string_type(),
+            "y has type str"
+        );
+        assert_eq!(
+            tg.get_type(&[module_root()])?,
+            rec!("x" => i32_type(), "y" => string_type()),
+            "program has struct type {{x: i32, y: string}}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn type_of_symbol_is_type_of_its_definition() -> Test {
+        let tg = &mut get_tg("x=0;y=x")?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, i32_type());
+        Ok(())
+    }
+
+    #[test]
+    fn type_of_argument_is_inferred_from_uses() -> Test {
+        let tg = &mut get_tg("f(n)=argv(it=n)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f"), Symbol::new("n")])?,
+            i32_type()
+        );
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("n" => i32_type())),
+                results: Box::new(with_effects(string_type(), &["Args"])),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_symbols_are_errors() {
+        match get_tg("x=y") {
+            Err(TError::UnknownSymbol(name, _, _)) => assert_eq!(name, "y"),
+            res => panic!("expected an unknown symbol error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn block_definitions_are_local() -> Test {
+        let tg = &mut get_tg("x = {y = 'hi'; 3}")?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("x")])?, i32_type());
+        match get_tg("x = {y = 3; y}; z = y") {
+            Err(TError::UnknownSymbol(name, _, _)) => assert_eq!(name, "y"),
+            res => panic!("expected an unknown symbol error, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fields_are_checked_against_the_struct() -> Test {
+        let tg = &mut get_tg("p = (x=0, y='hi'); z = p.y")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("z")])?,
+            string_type()
+        );
+        match get_tg("p = (x=0); z = p.w") {
+            Err(TError::UnknownField(name, _, _)) => assert_eq!(name, "w"),
+            res => panic!("expected an unknown field error, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn numbers_and_strings_have_no_fields() {
+        for source in ["x = 3; y = x.nope", "s = 'hi'; y = s.nope"] {
+            match get_tg(source) {
+                Err(TError::UnknownField(name, _, _)) => assert_eq!(name, "nope"),
+                res => panic!("expected an unknown field error, got {:?}", res),
+            }
+        }
+    }
+
+    #[test]
+    fn clauses_are_checked_as_one_function() -> Test {
+        let tg = &mut get_tg("fac(0) = 1; fac(n) = n * fac(n=n-1)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("fac")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("n" => i32_type())),
+                results: Box::new(i32_type()),
+            }
+        );
+        assert!(get_tg("f(0) = 1; f(1) = 'a'; f(n) = n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn branches_without_values_are_optional() -> Test {
+        let prog = "half(n: I32) = if n % 2 == 0 { n / 2 } else { () }; x = half(n=3);";
+        let tg = &mut get_tg(&format!("{prog} y = x ?: 0; z = x?.half()"))?;
+        let optional = optional_type(i32_type());
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("x")])?, optional);
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, i32_type());
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("z")])?, optional);
+        assert!(get_tg(&format!("{prog} y = x ?: 'none'")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tuples_are_structs_of_their_fields() -> Test {
+        let tg = &mut get_tg("pair = (1, 2.5); let (x, y) = pair; point = (x=2, y=\"b\")")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("pair")])?,
+            rec!("0" => i32_type(), "1" => f64_type())
+        );
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, f64_type());
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("point")])?,
+            rec!("x" => i32_type(), "y" => string_type())
+        );
+        match get_tg("pair = (1, 2.5); pair.2") {
+            Err(TError::UnknownField(field, _, _)) => assert_eq!(field, "2"),
+            res => panic!("expected an unknown field, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn destructuring_needs_as_many_values_as_names() -> Test {
+        get_tg("f() = (1, 2, 3); let (a, b, c) = f()")?;
+        for (prog, msg) in [
+            (
+                "f() = (1, 2, 3); let (a, b) = f()",
+                "`let (a, b)` takes 2 values, but the tuple has 3",
+            ),
+            (
+                "let (a, b, c) = (1, 2)",
+                "`let (a, b, c)` takes 3 values, but the tuple has 2",
+            ),
+            (
+                "let ((a, b), y=c) = ((1, 2, 3), y=4)",
+                "`let ((a, b), y=c)` takes 2 values, but the tuple has 3",
+            ),
+        ] {
+            match get_tg(prog) {
+                Err(TError::TypeMismatch(found, _, _)) => assert_eq!(found, msg),
+                res => panic!("expected a type error for {}, got {:?}", prog, res),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn optionals_are_narrowed_by_null_checks() -> Test {
+        let prog = "half(n: I32) = if n % 2 == 0 { n / 2 } else { () }; x = half(n=3);";
+        let tg = &mut get_tg(&format!("{prog} y = if x != () {{ x + 1 }} else {{ 0 }}"))?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, i32_type());
+        let tg = &mut get_tg(&format!("{prog} y = if x == () {{ 0 }} else {{ x + 1 }}"))?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, i32_type());
+        match get_tg(&format!("{prog} y = x + 1")) {
+            Err(TError::UnexpectedType(..)) => {}
+            res => panic!("expected a type error, got {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_are_checked() -> Test {
+        let tg = &mut get_tg("x: I32 = 3, y = 'hi' : String")?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("x")])?, i32_type());
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("y")])?,
+            string_type()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_are_used_for_arguments_and_results() -> Test {
+        let tg = &mut get_tg("f(x: String): String = x")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("x" => string_type())),
+                results: Box::new(string_type()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotation_mismatches_are_reported_at_the_annotation() {
+        for (prog, column, msg) in &[
+            (
+                "x: String = 3",
+                3,
+                "`x=3` is annotated as String but has type I32",
+            ),
+            (
+                "f(): I32 = 'a'",
+                5,
+                "`f()` is annotated as I32 but has type String",
+            ),
+            (
+                "f(n: I32 = 'a') = n",
+                5,
+                "`n='a'` is annotated as I32 but has type String",
+            ),
+            (
+                "f(n: I32): String = n * 2",
+                11,
+                "`f(n)` is annotated as String but has type I32",
+            ),
+        ] {
+            match get_tg(prog) {
+                Err(TError::TypeMismatch(found, _, info)) => {
+                    assert_eq!(found, *msg);
+                    assert_eq!(
+                        format!("{}", info),
+                        format!("test.tk at line 1, column {}", column)
+                    )
+                }
+                res => panic!("expected a type mismatch for {}, got {:?}", prog, res),
+            }
+        }
+    }
+
+    #[test]
+    fn annotations_can_use_types_declared_later() -> Test {
+        get_tg("origin(): Point = Pt(x=0, y=0); type Point = Pt(x: I32, y: I32)")?;
+        match get_tg("origin(): Point = 0; type Point = Pt(x: I32, y: I32)") {
+            Err(TError::TypeMismatch(..)) => Ok(()),
+            res => panic!("expected a type mismatch, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn effects_of_calls_are_part_of_the_result_type() -> Test {
+        let tg = &mut get_tg("greet(name) = print(it=name); main() = greet(name='tako')")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("main")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!()),
+                results: Box::new(with_effects(unit_type(), &["Stdout"])),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn abstractions_are_functions() -> Test {
+        let tg = &mut get_tg("speak() = (x |- print(it=x))")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("speak")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!()),
+                results: Box::new(Function {
+                    intros: BTreeSet::new(),
+                    arguments: Box::new(rec!("x" => string_type())),
+                    results: Box::new(with_effects(unit_type(), &["Stdout"])),
+                }),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_allow_effects() -> Test {
+        let tg = &mut get_tg("f(n: I32): I32 & Print = (eprint('f'); n)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("n" => i32_type())),
+                results: Box::new(with_effects(i32_type(), &["Stderr"])),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn never_has_the_annotated_type() -> Test {
+        let tg = &mut get_tg("f(x: I32): I32 & Exit = exit(it=x); die(x: I32) = exit(it=x)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("x" => i32_type())),
+                results: Box::new(with_effects(i32_type(), &["Exit"])),
+            }
+        );
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("die")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("x" => i32_type())),
+                results: Box::new(with_effects(never_type(), &["Exit"])),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pure_functions_cannot_use_effects() {
+        match get_tg("f(n: I32): String = (print('f'); argv(it=n))") {
+            Err(TError::UnexpectedEffects(msg, _)) => assert_eq!(msg, "`f` uses Args, Stdout"),
+            res => panic!("expected an effects error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn assigning_to_outer_variables_uses_mut() -> Test {
+        let tg = &mut get_tg("total := 0; add(n: I32) = (total += n)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("add")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("n" => i32_type())),
+                results: Box::new(with_effects(i32_type(), &["Mut"])),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assigning_to_local_variables_is_pure() -> Test {
+        let tg =
+            &mut get_tg("double(n: I32): I32 = (acc := n; acc *= 2; acc /= 1; acc %= 9; acc)")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("double")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("n" => i32_type())),
+                results: Box::new(i32_type()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn closures_cannot_assign_to_captured_variables() -> Test {
+        for prog in [
+            "mk() = {c := 0; inc() = {c += 1; c}; inc}",
+            "x := 0; mk() = (y |- (x += y))",
+        ] {
+            match get_tg(prog) {
+                Err(TError::CapturedAssignment(..)) => {}
+                res => panic!("expected a captured assignment for {}, got {:?}", prog, res),
+            }
+        }
+        // Loops run in place, so they can still change the variables around them.
+        get_tg("f() = {i := 0; while i < 3 { i += 1 }; i}")?;
+        Ok(())
+    }
+
+    #[test]
+    fn closures_cannot_use_mutable_variables() -> Test {
+        for prog in [
+            "f() = {c := 1; get() = c; c += 5; get()}",
+            "c := 1; mk() = (y |- c + y)",
+        ] {
+            match get_tg(prog) {
+                Err(TError::CapturedVariable(name, _)) => assert_eq!(name, "c"),
+                res => panic!("expected a captured variable for {}, got {:?}", prog, res),
+            }
+        }
+        // Functions at the top level aren't closures, so they use the variable itself.
+        get_tg("c := 1; get() = c; c += 5; get()")?;
+        // Copies of variables can be captured.
+        get_tg("f() = {c := 1; c += 5; n = c; get() = n; get()}")?;
+        Ok(())
+    }
+
+    #[test]
+    fn handled_effects_do_not_escape_the_handler() -> Test {
+        let tg = &mut get_tg("f() = print('hi') ~ (print(it) = resume())")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("f")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!()),
+                results: Box::new(unit_type()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn handler_clauses_replace_the_handled_value() {
+        match get_tg("f() = argv(it=1) ~ (argv(it) = it)") {
+            Err(TError::UnexpectedType(_, expected, found, _)) => {
+                assert_eq!((*expected, *found), (string_type(), i32_type()))
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn resume_is_only_defined_in_handler_clauses() {
+        for prog in [
+            "resume(it=1)",
+            "f() = resume(it=1)",
+            "f() = print('hi') ~ (print(it) = resume()); resume()",
+        ] {
+            match get_tg(prog) {
+                Err(TError::ResumeOutsideHandler(_)) => {}
+                res => panic!(
+                    "expected a resume outside of a handler for {}, got {:?}",
+                    prog, res
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn constructors_build_values_of_their_data_type() -> Test {
+        let tg = &mut get_tg("type Shape = Circle(r: I32) | Empty")?;
+        let shape = data_type(vec![
+            ("Circle".to_string(), vec![("r".to_string(), i32_type())]),
+            ("Empty".to_string(), vec![]),
+        ]);
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("Circle")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("r" => i32_type())),
+                results: Box::new(shape.clone()),
+            }
+        );
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("Empty")])?, shape);
+        Ok(())
+    }
+
+    #[test]
+    fn type_of_match_is_type_of_its_cases() -> Test {
+        let tg = &mut get_tg(
+            "type Shape = Circle(r: I32) | Empty; area(s) = match(it=s, Circle(r) = r, Empty = 0)",
+        )?;
+        let shape = data_type(vec![
+            ("Circle".to_string(), vec![("r".to_string(), i32_type())]),
+            ("Empty".to_string(), vec![]),
+        ]);
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("area")])?,
+            Function {
+                intros: BTreeSet::new(),
+                arguments: Box::new(rec!("s" => shape)),
+                results: Box::new(i32_type()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn match_must_cover_every_constructor() {
+        match get_tg("type T = A | B | C; f(t: T) = match(it=t, B = 1)") {
+            Err(TError::NonExhaustiveMatch(cases, _)) => assert_eq!(cases, "`A`, `C`"),
+            res => panic!("expected a non exhaustive match error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn match_cannot_repeat_a_constructor() {
+        match get_tg("type S = A | B(x: I32); f(s: S) = match(it=s, A = 1, A = 2, B(x) = x)") {
+            Err(TError::UnreachableClause(name, _)) => assert_eq!(name, "A"),
+            res => panic!("expected an unreachable clause error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn match_cases_bind_each_field_of_their_constructor() {
+        match get_tg("type T = A(x: I32) | B; f(t: T) = match(it=t, A(x, y) = y, B = 1)") {
+            Err(TError::TypeMismatch(msg, _, _)) => {
+                assert_eq!(msg, "`A` has 1 fields, but its case binds 2")
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn match_cases_bind_fields_by_position() -> Test {
+        get_tg("type T = A(x: I32, y: String); f(t: T): String = match(it=t, A(_, name) = name)")?;
+        Ok(())
+    }
+
+    #[test]
+    fn match_rejects_constructors_of_other_types_before_missing_cases() {
+        match get_tg("type T = A | B; type U = C; f(t: T) = match(it=t, A = 1, C = 2)") {
+            Err(TError::TypeMismatch(msg, _, _)) => {
+                assert_eq!(msg, "`C` is not a constructor of A | B")
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn type_of_number_depends_on_its_suffix() -> Test {
+        let tg = &mut get_tg("x = 2i64 * 3i64; y = 1.5")?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("x")])?, i64_type());
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, f64_type());
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_needs_numbers() {
+        match get_tg("x = 1.5 * 'a'") {
+            Err(TError::UnexpectedType(_, expected, found, _)) => {
+                assert_eq!((*expected, *found), (f64_type(), string_type()))
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn arithmetic_needs_numbers_of_the_same_type() {
+        for (prog, expected, found) in [
+            ("x = 3i64 + 2", i64_type(), i32_type()),
+            ("f(w, h) = w * h; x = f(w=1.5, h=2)", f64_type(), i32_type()),
+            ("x = 1.5 < 2", f64_type(), i32_type()),
+        ] {
+            match get_tg(prog) {
+                Err(TError::UnexpectedType(_, exp, fnd, _)) => {
+                    assert_eq!((*exp, *fnd), (expected, found), "{}", prog)
+                }
+                res => panic!("expected a type error for {}, got {:?}", prog, res),
+            }
+        }
+    }
+
+    #[test]
+    fn type_of_list_is_list_of_its_elements() -> Test {
+        let tg = &mut get_tg("xs = [1, 2]; y = xs[0]")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("xs")])?,
+            list_type(i32_type())
+        );
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("y")])?, i32_type());
+        Ok(())
+    }
+
+    #[test]
+    fn joining_lists_gives_a_list() -> Test {
+        let tg = &mut get_tg("xs = [1, 2] ++ [3]; s = [1] ++ 'a'")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("xs")])?,
+            list_type(i32_type())
+        );
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("s")])?,
+            string_type()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn list_elements_must_have_the_same_type() {
+        match get_tg("xs = [1, 'a']") {
+            Err(TError::UnexpectedType(msg, _, _, _)) => assert_eq!(msg, "'a'"),
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn control_flow_errors_show_the_source() {
+        for (program, expected) in [
+            ("f(n: Bit) = if n {1} else {'a'}", "if n {1} else {'a'}"),
+            ("if 1 {2} else {3}", "1"),
+            ("while 1 {2}", "1"),
+            ("for i in 5 {i}", "5"),
+        ] {
+            match get_tg(program) {
+                Err(TError::UnexpectedType(msg, _, _, _)) => assert_eq!(msg, expected),
+                res => panic!("expected a type error, got {:?}", res),
+            }
+        }
+    }
+
+    #[test]
+    fn loops_hide_their_steps() {
+        for program in [
+            "for i in [1] {for}",
+            "for i in [1] {in}",
+            "while false {while()}",
+        ] {
+            match get_tg(program) {
+                Err(TError::UnknownSymbol(..)) => {}
+                res => panic!("expected an unknown symbol, got {:?}", res),
+            }
+        }
+    }
+
+    #[test]
+    fn type_of_map_is_inferred_from_keys_and_values() -> Test {
+        let tg = &mut get_tg("m = to_map(keys=['a'], values=[1])")?;
+        assert_eq!(
+            tg.get_type(&[module_root(), Symbol::new("m")])?,
+            map_type(string_type(), i32_type())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn len_requires_a_collection() {
+        match get_tg("n = len(it=1)") {
+            Err(TError::UnexpectedType(msg, _, _, _)) => assert_eq!(msg, "(len)(it=1)"),
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn operations_of_interfaces_need_an_implementation() -> Test {
+        let iface = "interface Sized = size(it: Self): I32";
+        match get_tg(&format!("{}; n = size(it=1)", iface)) {
+            Err(TError::UnexpectedType(_, expected, found, _)) => {
+                assert_eq!((*expected, *found), (variable("Sized"), i32_type()))
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+        let prog = format!("{}; impl Sized(I32) = size(it) = it; n = size(it=1)", iface);
+        let tg = &mut get_tg(&prog)?;
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("n")])?, i32_type());
+        Ok(())
+    }
+
+    #[test]
+    fn operators_use_implementations_of_number() -> Test {
+        let prog = "type Money = Cents(c: I32);
+            cents(m: Money): I32 = match(it=m, Cents(c) = c);
+            impl Number(Money) = (
+                add(left, right) = Cents(c=cents(m=left) + cents(m=right)),
+                sub(left, right) = Cents(c=cents(m=left) - cents(m=right)),
+                mul(left, right) = Cents(c=cents(m=left) * cents(m=right)),
+                div(left, right) = Cents(c=cents(m=left) / cents(m=right))
+            );
+            x = Cents(c=1) + Cents(c=2)";
+        let tg = &mut get_tg(prog)?;
+        let money = data_type(vec![(
+            "Cents".to_string(),
+            vec![("c".to_string(), i32_type())],
+        )]);
+        assert_eq!(tg.get_type(&[module_root(), Symbol::new("x")])?, money);
+        Ok(())
+    }
+
+    #[test]
+    fn implementations_need_every_operation() {
+        match get_tg("type Money = Cents(c: I32); impl Number(Money) = add(left, right) = left") {
+            Err(TError::IncompleteImplementation(missing, _)) => {
+                assert_eq!(missing, "`sub`, `mul`, `div`")
+            }
+            res => panic!("expected an incomplete implementation, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn types_implement_interfaces_once() {
+        match get_tg(
+            "interface Sized = size(it: Self): I32;
+            impl Sized(I32) = size(it) = 1;
+            impl Sized(I32) = size(it) = 2",
+        ) {
+            Err(TError::DuplicateImplementation(interface, ty, _)) => {
+                assert_eq!((interface.as_str(), *ty), ("Sized", i32_type()))
+            }
+            res => panic!("expected a duplicate implementation, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn methods_must_match_their_operation() {
+        match get_tg("interface Sized = size(it: Self): I32; impl Sized(I32) = size(it) = 'a'") {
+            Err(TError::UnexpectedType(_, expected, found, _)) => {
+                assert_eq!((*expected, *found), (i32_type(), string_type()))
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn data_types_need_display_to_be_shown() {
+        match get_tg("type T = A; x = 'a' ++ A") {
+            Err(TError::UnexpectedType(_, expected, _, _)) => {
+                assert_eq!(*expected, variable("Display"))
+            }
+            res => panic!("expected a type error, got {:?}", res),
+        }
+        assert!(get_tg("type T = A; impl Display(T) = to_string(it) = 'A'; x = 'a' ++ A").is_ok());
+    }
+}
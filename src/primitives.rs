@@ -1,10 +1,12 @@
 use crate::ast::{Info, Node};
 use crate::errors::TError;
 use bitvec::prelude::*;
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 use crate::data_structures::tribool::*;
 
@@ -18,10 +20,47 @@ pub type TypeSet = BTreeSet<Val>;
 pub type Pack = BTreeSet<(String, Val)>;
 pub type Frame = HashMap<String, Val>;
 
+// A float that is compared and hashed by its bits, so that it can be stored in sets and maps.
+#[derive(Clone, Copy)]
+pub struct Float(pub f64);
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Float) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Float {}
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Float) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Float {
+    fn cmp(&self, other: &Float) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Float {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
 pub enum Prim {
     Bool(bool),
     I32(i32),
+    I64(i64),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    F64(Float),
+    BigInt(num_bigint::BigInt),
+    Char(char),
     Str(String),
     BuiltIn(String),
     Tag(BitVec), // An identifying bit string (prefix).
@@ -33,6 +72,13 @@ impl std::fmt::Debug for Prim {
         match self {
             Bool(b) => write!(f, "{:?}", b)?,
             I32(i) => write!(f, "{:?}", i)?,
+            I64(i) => write!(f, "{:?}", i)?,
+            U8(i) => write!(f, "{:?}", i)?,
+            U32(i) => write!(f, "{:?}", i)?,
+            U64(i) => write!(f, "{:?}", i)?,
+            F64(x) => write!(f, "{:?}", x.0)?,
+            BigInt(i) => write!(f, "{}", i)?,
+            Char(c) => write!(f, "`{}`", c)?,
             Str(s) => write!(f, "'{}'", s)?,
             BuiltIn(b) => write!(f, "BuiltIn#{}", b)?,
             Tag(bits) => {
@@ -59,6 +105,10 @@ pub enum Val {
     Union(TypeSet),
     Product(TypeSet),
     Padded(Offset, InnerVal),
+    // Containers
+    List(Vec<Val>),
+    Set(TypeSet),
+    Map(BTreeMap<Val, Val>),
     Function {
         intros: Pack,
         arguments: InnerVal,
@@ -100,6 +150,7 @@ impl Val {
             Product(tys) => all_true(tys.iter().map(|ty| ty.is_sat())),
             Union(tys) => any_true(tys.iter().map(|ty| ty.is_sat())),
             Padded(_, ty) => ty.is_sat(),
+            List(_) | Set(_) | Map(_) => True,
             Function {
                 intros: _,
                 arguments: _,
@@ -164,6 +215,7 @@ impl Val {
             Product(_) => never_type(), // TODO
             Padded(_, ty) => ty.access(name),
             Pointer(_, ty) => ty.access(name),
+            List(_) | Set(_) | Map(_) => never_type(),
             Function {
                 intros: _,
                 arguments,
@@ -209,6 +261,23 @@ impl std::fmt::Debug for Val {
             PrimVal(prim) => write!(f, "{:?}", prim),
             BitStr(ptr_size) => write!(f, "Pointer<{}b>Code", ptr_size),
             Lambda(val) => write!(f, "{}", val),
+            Struct(_) if constructor_of(self).is_some() => {
+                let (name, fields) = constructor_of(self).expect("Expected a constructor");
+                write!(f, "{}", name)?;
+                if !fields.is_empty() {
+                    let fields: Vec<String> = fields
+                        .iter()
+                        .map(|(name, val)| format!("{}={:?}", name, val))
+                        .collect();
+                    write!(f, "({})", fields.join(", "))?;
+                }
+                Ok(())
+            }
+            // Tuples only have positional fields, e.g. `(1, 'two')`.
+            Struct(vals) if is_tuple(vals) => {
+                let vals: Vec<String> = vals.iter().map(|(_, val)| format!("{:?}", val)).collect();
+                write!(f, "({})", vals.join(", "))
+            }
             Struct(vals) => {
                 let mut out = f.debug_struct("");
                 for val in vals.iter() {
@@ -216,6 +285,23 @@ impl std::fmt::Debug for Val {
                 }
                 out.finish()
             }
+            Union(_) if data_constructors(self).is_some() => {
+                let alternatives: Vec<String> = data_constructors(self)
+                    .expect("Expected a data type")
+                    .into_iter()
+                    .map(|(name, fields)| {
+                        if fields.is_empty() {
+                            return name.to_string();
+                        }
+                        let fields: Vec<String> = fields
+                            .iter()
+                            .map(|(name, ty)| format!("{}: {:?}", name, ty))
+                            .collect();
+                        format!("{}({})", name, fields.join(", "))
+                    })
+                    .collect();
+                write!(f, "{}", alternatives.join(" | "))
+            }
             Union(s) => {
                 if s.is_empty() {
                     write!(f, "Never")
@@ -238,6 +324,21 @@ impl std::fmt::Debug for Val {
                     out.finish()
                 }
             }
+            List(vals) => {
+                let vals: Vec<String> = vals.iter().map(|val| format!("{:?}", val)).collect();
+                write!(f, "[{}]", vals.join(", "))
+            }
+            Set(vals) => {
+                let vals: Vec<String> = vals.iter().map(|val| format!("{:?}", val)).collect();
+                write!(f, "{{{}}}", vals.join(", "))
+            }
+            Map(vals) => {
+                let vals: Vec<String> = vals
+                    .iter()
+                    .map(|(key, val)| format!("{:?}: {:?}", key, val))
+                    .collect();
+                write!(f, "{{{}}}", vals.join(", "))
+            }
             Pointer(ptr_size, ty) => write!(f, "Pointer<{}b>{:#?}", ptr_size, ty),
             Padded(size, t) => write!(f, "pad_{}#{:#?}", size, t),
             Function {
@@ -254,7 +355,14 @@ impl std::fmt::Debug for Val {
                 }
                 write!(f, "{:#?} -> {:#?}", arguments, results)
             }
-            App { inner, arguments } => write!(f, "({:#?})({:#?})", inner, arguments),
+            App { inner, arguments } => match (&**inner, &**arguments) {
+                (Variable(name), Struct(args)) if is_container(name) => {
+                    let args: Vec<String> =
+                        args.iter().map(|(_, ty)| format!("{:?}", ty)).collect();
+                    write!(f, "{}({})", name, args.join(", "))
+                }
+                _ => write!(f, "({:#?})({:#?})", inner, arguments),
+            },
             WithRequirement(ty, effs) => write!(f, "{:#?}+{}", ty, effs.join("+")),
             Variable(name) => write!(f, "{}", name),
         }
@@ -294,6 +402,10 @@ pub fn int32(i: i32) -> Val {
     Val::PrimVal(Prim::I32(i))
 }
 
+pub fn float64(x: f64) -> Val {
+    Val::PrimVal(Prim::F64(Float(x)))
+}
+
 pub fn builtin(name: &str) -> Val {
     Val::PrimVal(Prim::BuiltIn(name.to_string()))
 }
@@ -369,7 +481,7 @@ pub fn size(ty: &Val) -> Result<Offset, TError> {
     }
 }
 
-fn num_bits(n: Offset) -> Offset {
+pub fn num_bits(n: Offset) -> Offset {
     let mut k = 0;
     let mut p = 1;
     loop {
@@ -418,11 +530,16 @@ pub fn add_to_product(tys: &mut TypeSet, values: &TypeSet) {
     }
 }
 
+// The tag identifying the `index`th of `count` alternatives in a sum.
+pub fn sum_tag(index: Offset, count: Offset) -> Val {
+    tag(bits(index, num_bits(count)))
+}
+
 pub fn sum(values: Vec<Val>) -> Result<Val, TError> {
     let mut layout = set![];
-    let tag_bits = num_bits(values.len() as Offset);
-    for (count, val) in values.into_iter().enumerate() {
-        let mut tagged = tag(bits(count, tag_bits));
+    let count = values.len() as Offset;
+    for (index, val) in values.into_iter().enumerate() {
+        let mut tagged = sum_tag(index, count);
         if val != unit_type() {
             tagged = record(vec![tagged, val])?;
         }
@@ -431,6 +548,93 @@ pub fn sum(values: Vec<Val>) -> Result<Val, TError> {
     Ok(Union(layout))
 }
 
+// A data type with named constructors, tagged in the same way as `sum`.
+// Each alternative is a struct of the constructor's tag followed by its fields.
+pub fn data_type(constructors: Vec<(String, Vec<(String, Val)>)>) -> Val {
+    let count = constructors.len() as Offset;
+    let mut layout = set![];
+    for (index, (name, fields)) in constructors.into_iter().enumerate() {
+        let mut tagged = vec![(name, sum_tag(index, count))];
+        tagged.extend(fields);
+        layout.insert(Struct(tagged));
+    }
+    Union(layout)
+}
+
+// Whether a struct's fields are all positional, i.e. are named `0`, `1`, ... in order.
+pub fn is_tuple(fields: &[(String, Val)]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .enumerate()
+            .all(|(index, (name, _))| *name == index.to_string())
+}
+
+// The name of a constructor of a data type with its fields.
+pub type DataConstructor<'a> = (&'a str, &'a [(String, Val)]);
+
+// Finds the constructor (and fields) of a value of a data type, or of one of its alternatives.
+pub fn constructor_of(val: &Val) -> Option<DataConstructor<'_>> {
+    match val {
+        Struct(vals) => match vals.split_first() {
+            Some(((name, PrimVal(Prim::Tag(_))), fields)) => Some((name, fields)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Lists the constructors of a data type, in the order they were declared (i.e. by tag).
+pub fn data_constructors(ty: &Val) -> Option<Vec<DataConstructor<'_>>> {
+    let alternatives = match ty {
+        Union(tys) if !tys.is_empty() => tys,
+        _ => return None,
+    };
+    let mut ctors = vec![];
+    for alternative in alternatives.iter() {
+        let tag = match alternative {
+            Struct(vals) => &vals.first()?.1,
+            _ => return None,
+        };
+        ctors.push((tag, constructor_of(alternative)?));
+    }
+    ctors.sort_by_key(|(tag, _)| *tag);
+    Some(ctors.into_iter().map(|(_, ctor)| ctor).collect())
+}
+
+// The built in containers, which are types applied to the types of their contents.
+const CONTAINERS: [&str; 3] = ["List", "Set", "Map"];
+
+pub fn is_container(name: &str) -> bool {
+    CONTAINERS.contains(&name)
+}
+
+pub fn is_list(ty: &Val) -> bool {
+    matches!(ty, App { inner, .. } if matches!(&**inner, Variable(name) if name == "List"))
+}
+
+fn container_type(name: &str, arguments: Vec<(String, Val)>) -> Val {
+    App {
+        inner: Box::new(Variable(name.to_string())),
+        arguments: Box::new(Struct(arguments)),
+    }
+}
+
+pub fn list_type(ty: Val) -> Val {
+    container_type("List", vec![("it".to_string(), ty)])
+}
+
+pub fn set_type(ty: Val) -> Val {
+    container_type("Set", vec![("it".to_string(), ty)])
+}
+
+pub fn map_type(key: Val, value: Val) -> Val {
+    container_type(
+        "Map",
+        vec![("key".to_string(), key), ("value".to_string(), value)],
+    )
+}
+
 pub fn never_type() -> Val {
     Union(set![])
 }
@@ -439,6 +643,28 @@ pub fn unit_type() -> Val {
     Product(set![])
 }
 
+// An optional value is either a value of the type or `()`, e.g. `I32|Unit`.
+pub fn optional_type(ty: Val) -> Val {
+    Union(set![ty, unit_type()])
+}
+
+// The type of the value in an optional, e.g. `I32` for `I32|Unit`.
+pub fn optional_inner(ty: &Val) -> Option<Val> {
+    match ty {
+        Union(tys) if tys.len() == 2 && tys.contains(&unit_type()) => {
+            tys.iter().find(|ty| **ty != unit_type()).cloned()
+        }
+        _ => None,
+    }
+}
+
+pub fn with_effects(ty: Val, effects: &[&str]) -> Val {
+    WithRequirement(
+        Box::new(ty),
+        effects.iter().map(|eff| eff.to_string()).collect(),
+    )
+}
+
 pub fn bit_type() -> Val {
     sum(vec![unit_type(), unit_type()]).expect("bit should be safe")
 }
@@ -469,18 +695,75 @@ pub fn byte_size() -> Offset {
     8
 }
 
+// A unicode code point.
 pub fn char_type() -> Val {
-    byte_type()
+    variable("Char")
 }
 
+// Strings are stored as UTF-8.
 pub fn string_type() -> Val {
-    char_type().ptr()
+    byte_type().ptr()
 }
 
 pub fn i32_type() -> Val {
     record(vec![byte_type(), byte_type(), byte_type(), byte_type()]).expect("i32 should be safe")
 }
 
+pub fn i64_type() -> Val {
+    variable("I64")
+}
+
+pub fn u8_type() -> Val {
+    variable("U8")
+}
+
+pub fn u32_type() -> Val {
+    variable("U32")
+}
+
+pub fn u64_type() -> Val {
+    variable("U64")
+}
+
+pub fn f64_type() -> Val {
+    variable("F64")
+}
+
+pub fn bigint_type() -> Val {
+    variable("BigInt")
+}
+
+// Checks whether a type is one of the built in number types.
+pub fn is_number(ty: &Val) -> bool {
+    *ty == i32_type()
+        || [
+            i64_type(),
+            u8_type(),
+            u32_type(),
+            u64_type(),
+            f64_type(),
+            bigint_type(),
+        ]
+        .contains(ty)
+}
+
+// The type of a primitive value, if it is data (rather than a built in or a tag).
+pub fn prim_type(prim: &Prim) -> Option<Val> {
+    Some(match prim {
+        Prim::Bool(_) => bit_type(),
+        Prim::I32(_) => i32_type(),
+        Prim::I64(_) => i64_type(),
+        Prim::U8(_) => u8_type(),
+        Prim::U32(_) => u32_type(),
+        Prim::U64(_) => u64_type(),
+        Prim::F64(_) => f64_type(),
+        Prim::BigInt(_) => bigint_type(),
+        Prim::Char(_) => char_type(),
+        Prim::Str(_) => string_type(),
+        Prim::BuiltIn(_) | Prim::Tag(_) => return None,
+    })
+}
+
 pub fn number_type() -> Val {
     variable("Number")
 }
@@ -601,6 +884,25 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn data_type_tags_constructors_in_order() -> Res {
+        let shape = data_type(vec![
+            ("Circle".to_string(), vec![("r".to_string(), i32_type())]),
+            ("Empty".to_string(), vec![]),
+            ("Any".to_string(), vec![]),
+        ]);
+        let ctors = data_constructors(&shape).expect("Expected constructors");
+        let names: Vec<&str> = ctors.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["Circle", "Empty", "Any"]);
+        assert_eq!(ctors[0].1, &[("r".to_string(), i32_type())]);
+        match &shape {
+            Union(alternatives) => {
+                assert!(alternatives.contains(&Struct(vec![("Any".to_string(), sum_tag(2, 3))])))
+            }
+            ty => panic!("expected a union, got {:?}", ty),
+        }
+        Ok(())
+    }
+    #[test]
     fn nested_quad_type() -> Res {
         let quad = record(vec![bit_type(), bit_type()])?;
         assert_eq!(card(&quad), Ok(4));
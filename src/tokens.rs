@@ -1,3 +1,4 @@
+use super::errors::TError;
 use super::location::*;
 use std::collections::VecDeque;
 use std::fmt;
@@ -9,17 +10,29 @@ pub enum TokenType {
     CloseBracket,
     NumLit,
     StringLit,
+    // A string containing expressions in braces, e.g. `"Hello {name}!"`.
+    InterpolatedLit(Vec<StringPart>),
+    CharLit,
     Sym,
     Unknown,
     Whitespace,
 }
 
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum StringPart {
+    Text(String),
+    // The source of an interpolated expression and where it starts.
+    Code(String, Loc),
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct Token {
     pub tok_type: TokenType,
     // TODO: Use enum types to convert tokens to literals and symbols.
     pub value: String,
     pub pos: Loc,
+    // Whether the token starts a new line, i.e. the token before it is on an earlier line.
+    pub new_line: bool,
 }
 
 impl fmt::Debug for Token {
@@ -34,6 +47,7 @@ const CLOSEBRACKETS: &str = ")]}";
 const NUMBERS: &str = "0123456789";
 const WHITESPACE: &str = "\n\r\t ";
 const QUOTES: &str = "'\"`";
+const CHAR_QUOTE: char = '`';
 const COMMENT: &str = "//";
 const MULTI_COMMENT: &str = "/*";
 
@@ -60,24 +74,68 @@ fn classify_char(ch: char) -> TokenType {
     TokenType::Sym
 }
 
+// Consumes the source of an interpolated expression, up to its closing brace.
+fn lex_interpolation(
+    contents: &mut std::iter::Peekable<std::str::Chars>,
+    pos: &mut Loc,
+    open: &Loc,
+) -> Result<String, TError> {
+    let unclosed = || {
+        TError::ParseError(
+            "Unclosed `{` in string".to_string(),
+            open.clone().get_info(),
+        )
+    };
+    let mut code = String::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    loop {
+        let chr = contents.peek().copied().ok_or_else(unclosed)?;
+        match (quote, chr) {
+            (Some(_), '\\') => {
+                // Keep escapes for when the expression is lexed.
+                code.push(chr);
+                pos.next(contents);
+                code.push(contents.peek().copied().ok_or_else(unclosed)?);
+            }
+            (Some(q), chr) if q == chr => quote = None,
+            (Some(_), _) => {}
+            (None, chr) if QUOTES.contains(chr) => quote = Some(chr),
+            (None, '{') => depth += 1,
+            (None, '}') if depth == 0 && code.trim().is_empty() => {
+                return Err(TError::ParseError(
+                    "Expected an expression between `{` and `}` in string, use `\\{` for a brace"
+                        .to_string(),
+                    open.clone().get_info(),
+                ))
+            }
+            (None, '}') if depth == 0 => return Ok(code),
+            (None, '}') => depth -= 1,
+            (None, _) => {}
+        }
+        code.push(chr);
+        pos.next(contents);
+    }
+}
+
 // Consumes a single token from a Deque of characters.
 pub fn lex_head<'a>(
     mut contents: std::iter::Peekable<std::str::Chars<'a>>,
     pos: &mut Loc,
-) -> (Token, std::iter::Peekable<std::str::Chars<'a>>) {
+) -> Result<(Token, std::iter::Peekable<std::str::Chars<'a>>), TError> {
     let mut head: VecDeque<char> = VecDeque::new();
 
     let mut tok_type: TokenType = TokenType::Unknown;
     let mut quote: Option<char> = None;
-    let start = pos.clone();
+    let mut start = pos.clone();
 
     // TODO: This should be simplified (make tight loops).
-    while let Some(chr) = contents.peek() {
-        let chr_type = classify_char(*chr);
+    while let Some(chr) = contents.peek().copied() {
+        let chr_type = classify_char(chr);
         tok_type = match (&tok_type, &chr_type) {
             (TokenType::Unknown, TokenType::Whitespace) => TokenType::Unknown, // Ignore
             (TokenType::Unknown, TokenType::StringLit) => {
-                quote = Some(*chr);
+                quote = Some(chr);
                 TokenType::StringLit
             }
             (TokenType::Unknown, new_tok_type) => new_tok_type.clone(),
@@ -86,11 +144,32 @@ pub fn lex_head<'a>(
             (TokenType::Op, _) => break, // Token finished.
 
             (TokenType::NumLit, TokenType::NumLit) => TokenType::NumLit,
-            (TokenType::NumLit, TokenType::Sym) => TokenType::Sym, // Promotion
-            (TokenType::NumLit, _) => break,                       // Token finished.
+            // Prefixes, suffixes, hex digits and underscores, e.g. `0xff`, `1_000` or `10u8`.
+            (TokenType::NumLit, TokenType::Sym) => TokenType::NumLit,
+            // A decimal point, e.g. `1.5`, but not a trailing `.`.
+            (TokenType::NumLit, TokenType::Op)
+                if chr == '.'
+                    && head.iter().all(|c| c.is_ascii_digit())
+                    && contents.clone().nth(1).is_some_and(|c| c.is_ascii_digit()) =>
+            {
+                TokenType::NumLit
+            }
+            (TokenType::NumLit, _) => break, // Token finished.
 
             (TokenType::Sym, TokenType::Sym) => TokenType::Sym,
             (TokenType::Sym, TokenType::NumLit) => TokenType::Sym,
+            // Qualified names, e.g. `lib::exists`.
+            (TokenType::Sym, TokenType::Op)
+                if chr == ':'
+                    && (head.back() == Some(&':')
+                        || (contents.clone().nth(1) == Some(':')
+                            && contents
+                                .clone()
+                                .nth(2)
+                                .is_some_and(|c| classify_char(c) == TokenType::Sym))) =>
+            {
+                TokenType::Sym
+            }
             (TokenType::Sym, _) => break, // Token finished.
 
             (TokenType::OpenBracket, _) => break, // Token finished.
@@ -104,13 +183,18 @@ pub fn lex_head<'a>(
         }
         if chr_type != TokenType::Whitespace {
             // Add the character.
-            head.push_back(*chr);
+            head.push_back(chr);
         }
         // Continue past the character.
         pos.next(&mut contents);
+        if tok_type == TokenType::Unknown && pos.pos.line != start.pos.line {
+            // Tokens after a new line start on that line, so that the parser can find line breaks.
+            start = pos.clone();
+        }
     }
     if tok_type == TokenType::StringLit {
         // We hit a quote.
+        let mut parts = vec![];
         loop {
             pos.next(&mut contents);
             // Add the character.
@@ -118,6 +202,21 @@ pub fn lex_head<'a>(
                 if Some(*chr) == quote {
                     break;
                 }
+                if quote != Some(CHAR_QUOTE) && *chr == '{' {
+                    let open = pos.clone();
+                    pos.next(&mut contents);
+                    let code_pos = pos.clone();
+                    let code = lex_interpolation(&mut contents, pos, &open)?;
+                    parts.push(StringPart::Text(head.drain(..).collect()));
+                    parts.push(StringPart::Code(code, code_pos));
+                    continue;
+                }
+                if quote != Some(CHAR_QUOTE) && *chr == '}' {
+                    return Err(TError::ParseError(
+                        "Unmatched `}` in string, use `\\}` for a literal brace".to_string(),
+                        pos.clone().get_info(),
+                    ));
+                }
                 let nxt = if chr == &'\\' {
                     contents.next(); // Escape.
                     let escape = contents.peek().expect("Escaped character");
@@ -136,19 +235,35 @@ pub fn lex_head<'a>(
         }
         // Drop the quote
         pos.next(&mut contents);
+        if quote == Some(CHAR_QUOTE) {
+            if head.len() != 1 {
+                let value: String = head.into_iter().collect();
+                return Err(TError::ParseError(
+                    format!(
+                        "Expected a single character in `{value}`, strings are quoted with ' or \""
+                    ),
+                    start.get_info(),
+                ));
+            }
+            tok_type = TokenType::CharLit;
+        } else if !parts.is_empty() {
+            parts.push(StringPart::Text(head.drain(..).collect()));
+            tok_type = TokenType::InterpolatedLit(parts);
+        }
     }
     let value = head.into_iter().collect();
     let comment = value == COMMENT;
     let multi_comment = value == MULTI_COMMENT;
     if !comment && !multi_comment {
-        return (
+        return Ok((
             Token {
                 value,
                 tok_type,
                 pos: start,
+                new_line: false,
             },
             contents,
-        );
+        ));
     }
     // Track depth of mutli line comments
     let mut depth = 1;
@@ -184,7 +299,9 @@ mod tests {
     use super::super::location::*;
     use super::classify_char;
     use super::lex_head;
+    use super::StringPart;
     use super::TokenType;
+    use crate::errors::TError;
 
     #[test]
     fn classify_whitespace() {
@@ -214,7 +331,7 @@ mod tests {
     fn lex_number() {
         let chars = "123".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::NumLit);
     }
 
@@ -222,28 +339,80 @@ mod tests {
     fn lex_symbol() {
         let chars = "a123".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.tok_type, TokenType::Sym);
+    }
+
+    #[test]
+    fn lex_number_forms() {
+        for lit in ["1_000", "0xff", "0b101", "1.5", "10u8", "2.5f64"].iter() {
+            let chars = lit.chars().peekable();
+            let mut pos = Loc::default();
+            let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+            assert_eq!(tok.tok_type, TokenType::NumLit);
+            assert_eq!(&tok.value, lit);
+        }
+    }
+
+    #[test]
+    fn lex_qualified_symbol() {
+        let chars = "lib::exists(it='x')".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::Sym);
+        assert_eq!(tok.value, "lib::exists");
+    }
+
+    #[test]
+    fn lex_symbol_then_annotation() {
+        let chars = "x::".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, chars) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.value, "x");
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.tok_type, TokenType::Op);
+        assert_eq!(tok.value, "::");
+    }
+
+    #[test]
+    fn lex_number_then_operator() {
+        let chars = "1.x".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, chars) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.value, "1");
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.tok_type, TokenType::Op);
+        assert_eq!(tok.value, ".");
     }
 
     #[test]
     fn lex_operator() {
         let chars = "-a123".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::Op);
     }
 
+    #[test]
+    fn lex_assignment_operators() {
+        for op in ["+=", "-=", "*=", "/=", "%="] {
+            let chars = format!("{}1", op);
+            let mut pos = Loc::default();
+            let (tok, _) = lex_head(chars.chars().peekable(), &mut pos).expect("Valid token");
+            assert_eq!((tok.tok_type, tok.value.as_str()), (TokenType::Op, op));
+        }
+    }
+
     #[test]
     fn lex_num_and_newline_linux() {
         let chars = "\n12".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::NumLit);
         assert_eq!(
             pos,
             Loc {
-                filename: None,
+                filename: "".into(),
                 pos: Pos { line: 2, col: 3 }
             }
         );
@@ -253,12 +422,12 @@ mod tests {
     fn lex_num_and_newline_windows() {
         let chars = "\r\n12".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::NumLit);
         assert_eq!(
             pos,
             Loc {
-                filename: None,
+                filename: "".into(),
                 pos: Pos { line: 2, col: 3 }
             }
         );
@@ -269,37 +438,119 @@ mod tests {
         // For mac systems before OSX
         let chars = "\r12".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::NumLit);
         assert_eq!(
             pos,
             Loc {
-                filename: None,
+                filename: "".into(),
                 pos: Pos { line: 2, col: 3 }
             }
         );
     }
 
+    #[test]
+    fn lex_token_after_newline_is_on_its_line() {
+        let chars = "\n  12".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.pos.pos.line, 2);
+    }
+
     #[test]
     fn lex_escaped_characters_in_string() {
         let chars = "'\\n\\t2\\r\\\'\"'".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, _) = lex_head(chars, &mut pos);
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::StringLit);
         assert_eq!(tok.value, "\n\t2\r\'\"");
     }
 
+    #[test]
+    fn lex_char() {
+        let chars = "`é`".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(tok.tok_type, TokenType::CharLit);
+        assert_eq!(tok.value, "é");
+    }
+
+    #[test]
+    fn lex_multiple_characters_in_char_is_an_error() {
+        for source in ["`abc`", "``"] {
+            let chars = source.chars().peekable();
+            let mut pos = Loc::default();
+            match lex_head(chars, &mut pos) {
+                Err(TError::ParseError(msg, _)) => {
+                    assert!(msg.starts_with("Expected a single character"), "{}", msg)
+                }
+                other => panic!("Expected a parse error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn lex_interpolated_string() {
+        let chars = "\"a {f(\"}\")} \\{b\\}\"".chars().peekable();
+        let mut pos = Loc::default();
+        let (tok, _) = lex_head(chars, &mut pos).expect("Valid token");
+        assert_eq!(
+            tok.tok_type,
+            TokenType::InterpolatedLit(vec![
+                StringPart::Text("a ".to_string()),
+                StringPart::Code(
+                    "f(\"}\")".to_string(),
+                    Loc {
+                        filename: "".into(),
+                        pos: Pos { line: 1, col: 5 }
+                    }
+                ),
+                StringPart::Text(" {b}".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn lex_empty_braces_in_string() {
+        let chars = "\"ab {  } c\"".chars().peekable();
+        let mut pos = Loc::default();
+        match lex_head(chars, &mut pos) {
+            Err(TError::ParseError(msg, info)) => {
+                assert!(msg.starts_with("Expected an expression"), "{}", msg);
+                assert_eq!(info.loc.expect("A location").pos, Pos { line: 1, col: 5 })
+            }
+            res => panic!("expected a parse error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn lex_unmatched_braces_in_string() {
+        for (text, col) in [("\"a {b\"", 4), ("\"a } b\"", 4)].iter() {
+            let chars = text.chars().peekable();
+            let mut pos = Loc::default();
+            match lex_head(chars, &mut pos) {
+                Err(TError::ParseError(_, info)) => {
+                    assert_eq!(
+                        info.loc.expect("A location").pos,
+                        Pos { line: 1, col: *col }
+                    )
+                }
+                res => panic!("expected a parse error, got {:?}", res),
+            }
+        }
+    }
+
     #[test]
     fn lex_call() {
         let chars = "x()".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, chars2) = lex_head(chars, &mut pos);
+        let (tok, chars2) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::Sym);
         assert_eq!(tok.value, "x");
-        let (tok, chars3) = lex_head(chars2, &mut pos);
+        let (tok, chars3) = lex_head(chars2, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::OpenBracket);
         assert_eq!(tok.value, "(");
-        let (tok, _) = lex_head(chars3, &mut pos);
+        let (tok, _) = lex_head(chars3, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::CloseBracket);
         assert_eq!(tok.value, ")");
     }
@@ -308,13 +559,13 @@ mod tests {
     fn lex_strings_with_operators() {
         let chars = "!\"hello world\"\n7".chars().peekable();
         let mut pos = Loc::default();
-        let (tok, chars2) = lex_head(chars, &mut pos);
+        let (tok, chars2) = lex_head(chars, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::Op);
         assert_eq!(tok.value, "!");
-        let (tok, chars3) = lex_head(chars2, &mut pos);
+        let (tok, chars3) = lex_head(chars2, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::StringLit);
         assert_eq!(tok.value, "hello world");
-        let (tok, _) = lex_head(chars3, &mut pos);
+        let (tok, _) = lex_head(chars3, &mut pos).expect("Valid token");
         assert_eq!(tok.tok_type, TokenType::NumLit);
         assert_eq!(tok.value, "7");
     }
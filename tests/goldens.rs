@@ -9,9 +9,10 @@ use takolib::errors::TError;
 #[derive(Debug, PartialEq)]
 pub enum TestResult {
     Error,
-    Success,            // With an unspecified value
-    Output(String),     // With an expected value
-    OutputFile(String), // With an expected value in a file
+    ErrorMessage(String), // With an expected message in the error
+    Success,              // With an unspecified value
+    Output(String),       // With an expected value
+    OutputFile(String),   // With an expected value in a file
     ReturnValue(i32),
 }
 use TestResult::*;
@@ -81,7 +82,16 @@ fn test_expecting(expected: TestResult, options: Vec<&str>) -> Test {
             eprintln!("Received error:\n{:?}", err);
             Ok(())
         }
-        (Ok(result), Error) => {
+        (Err(err), ErrorMessage(msg)) => {
+            assert!(
+                err.to_string().contains(&msg),
+                "expected an error containing {:?}, got {:?}",
+                msg,
+                err.to_string()
+            );
+            Ok(())
+        }
+        (Ok(result), Error | ErrorMessage(_)) => {
             eprintln!("---Got result---\n{:?}", result);
             panic!("Expected error");
         }
@@ -121,11 +131,38 @@ fn plus_123() -> Test {
     )
 }
 
+#[test]
+fn accumulator() -> Test {
+    test_expecting(
+        Output("13".to_string()),
+        vec!["--run", "examples/accumulator.tk"],
+    )
+}
+
 #[test]
 fn alt() -> Test {
     run("examples/alt.tk")
 }
 
+#[test]
+fn ambiguous_operators() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "parse failed, ambiguous use of `&&` and `||`, add parentheses, e.g. `(a && b) || c` or `a && (b || c)` at counter_examples/ambiguous_operators.tk at line 2, column 14"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/ambiguous_operators.tk"],
+    )
+}
+
+#[test]
+fn annotations() -> Test {
+    test_expecting(
+        Output("'Hello, tako'".to_string()),
+        vec!["--run", "examples/annotations.tk"],
+    )
+}
+
 #[test]
 fn arguments() -> Test {
     run("examples/arguments.tk")
@@ -142,35 +179,677 @@ fn bare_words() -> Test {
 }
 
 #[test]
-fn bool_requirement() -> Test {
-    run("examples/bool_requirement.tk")
+fn big_numbers() -> Test {
+    test_expecting(
+        Output(
+            "299999999999999999997 149999999999999999995 1267650600228229401496703205376 true\n0"
+                .to_string(),
+        ),
+        vec!["--run", "examples/big_numbers.tk"],
+    )
+}
+
+#[test]
+fn block_scope() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unknown symbol `z` in type_checker at counter_examples/block_scope.tk at line 2, column 1"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/block_scope.tk"],
+    )
+}
+
+#[test]
+fn blocks() -> Test {
+    test_expecting(
+        Output("2 31 24\n5\n2".to_string()),
+        vec!["--run", "examples/blocks.tk"],
+    )
+}
+
+#[test]
+fn bool_requirement() -> Test {
+    run("examples/bool_requirement.tk")
+}
+
+#[test]
+fn bool_times_bool() -> Test {
+    run_with_error("counter_examples/bool_times_bool.tk")
+}
+
+#[test]
+fn check_accumulator() -> Test {
+    test_expecting(
+        Output(
+            "add:  {\n    n: I32,\n} -> I32+Mut
+double:  {\n    n: I32,\n} -> I32
+halve_parity:  {\n    n: I32,\n} -> I32
+total: I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/accumulator.tk"],
+    )
+}
+
+#[test]
+fn check_annotations() -> Test {
+    test_expecting(
+        Output("greet:  {\n    name: String,\n} -> String".to_string()),
+        vec!["check", "examples/annotations.tk"],
+    )
+}
+
+#[test]
+fn check_blocks() -> Test {
+    test_expecting(
+        Output(
+            "area:  {\n    w: I32,\n    h: I32,\n} -> I32
+x: I32
+y: I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/blocks.tk"],
+    )
+}
+
+#[test]
+fn check_clauses() -> Test {
+    test_expecting(
+        Output(
+            "describe: a: Type|- {\n    it: a,\n} -> String
+fac:  {\n    n: I32,\n} -> I32
+pick: a: Number|- {\n    a: a,\n    b: a,\n} -> a"
+                .to_string(),
+        ),
+        vec!["check", "examples/clauses.tk"],
+    )
+}
+
+#[test]
+fn check_closures() -> Test {
+    test_expecting(
+        Output(
+            "add3:  {\n    x: I32,\n} -> I32
+add6:  {\n    x: I32,\n} -> I32
+adder: a: Number|- {\n    n: a,\n} ->  {\n    x: a,\n} -> a
+compose: a: Type|-b: Type|-c: Type|- {
+    f:  {\n        x: b,\n    } -> c,
+    g:  {\n        x: a,\n    } -> b,
+} ->  {\n    x: a,\n} -> c
+counter: a: Number|- {\n    start: a,\n} ->  {\n    by: a,\n} -> a"
+                .to_string(),
+        ),
+        vec!["check", "examples/closures.tk"],
+    )
+}
+
+#[test]
+fn check_control_flow() -> Test {
+    test_expecting(
+        Output(
+            "i: I32
+sign:  {\n    n: I32,\n} -> I32
+total: I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/control_flow.tk"],
+    )
+}
+
+#[test]
+fn check_containers() -> Test {
+    test_expecting(
+        Output(
+            "ages: Map(String, I32)
+evens: List(I32)
+halves: List(F64)
+names: List(String)
+none: List(String)
+seen: Set(I32)
+tens: List(I32)
+total: I32
+xs: List(I32)"
+                .to_string(),
+        ),
+        vec!["check", "examples/containers.tk"],
+    )
+}
+
+#[test]
+fn check_defaulting() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "type mismatch in `(y*2)`, expected Number but found Bit at examples/defaulting.tk at line 1, column 10"
+                .to_string(),
+        ),
+        vec!["check", "examples/defaulting.tk"],
+    )
+}
+
+#[test]
+fn check_dot_notation() -> Test {
+    test_expecting(
+        Output(
+            "maths.cube: a: Number|- {\n    it: a,\n} -> a
+maths.square: a: Number|- {\n    it: a,\n} -> a
+point:  { x: I32, y: I32 }
+scale: a: Number|- {\n    it: a,\n    by: a,\n} -> a"
+                .to_string(),
+        ),
+        vec!["check", "examples/dot_notation.tk"],
+    )
+}
+
+#[test]
+fn check_effects() -> Test {
+    test_expecting(
+        Output(
+            "double:  {\n    n: I32,\n} -> I32\ngreet:  {\n    name: String,\n} -> Unit+Stdout"
+                .to_string(),
+        ),
+        vec!["check", "examples/effects.tk"],
+    )
+}
+
+#[test]
+fn check_fac() -> Test {
+    test_expecting(
+        Output("fac:  {\n    n: I32,\n} -> I32".to_string()),
+        vec!["check", "examples/fac.tk"],
+    )
+}
+
+#[test]
+fn check_handlers() -> Test {
+    test_expecting(
+        Output(
+            "greet: a: Display|- {\n    name: a,\n} -> Unit+Stdout
+loudly: a: Display|- {\n    name: a,\n} -> Unit+Stdout
+message: a: Display|- {\n    name: a,\n} -> String
+quietly: a: Display|- {\n    name: a,\n} -> Unit"
+                .to_string(),
+        ),
+        vec!["check", "examples/handlers.tk"],
+    )
+}
+
+#[test]
+fn check_imports() -> Test {
+    test_expecting(
+        Output(
+            "maths: String
+maths.cube: a: Number|- {\n    it: a,\n} -> a
+maths.square: a: Number|- {\n    it: a,\n} -> a"
+                .to_string(),
+        ),
+        vec!["check", "examples/imports.tk"],
+    )
+}
+
+#[test]
+fn check_modules() -> Test {
+    test_expecting(
+        Output(
+            "celsius.from_kelvin:  {\n    it: I32,\n} -> I32
+fahrenheit.from_kelvin:  {\n    it: I32,\n} -> I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/modules.tk"],
+    )
+}
+
+#[test]
+fn check_incomplete_clauses() -> Test {
+    test_expecting(
+        Error,
+        vec!["check", "counter_examples/incomplete_clauses.tk"],
+    )
+}
+
+#[test]
+fn check_incomplete_implementation() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "implementation is missing operations `sub`, `mul`, `div` at counter_examples/incomplete_implementation.tk at line 4, column 19"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/incomplete_implementation.tk"],
+    )
+}
+
+#[test]
+fn check_interfaces() -> Test {
+    test_expecting(
+        Output(
+            "Point: Type
+Pt:  {\n    x: I32,\n    y: I32,\n} -> Pt(x: I32, y: I32)
+Sized: Type
+describe: a: Display|-a: Sized|- {\n    it: a,\n} -> String
+size: a: Sized|- {\n    it: a,\n} -> I32
+total: Pt(x: I32, y: I32)
+x_of:  {\n    p: Pt(x: I32, y: I32),\n} -> I32
+y_of:  {\n    p: Pt(x: I32, y: I32),\n} -> I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/interfaces.tk"],
+    )
+}
+
+#[test]
+fn check_interpolation() -> Test {
+    test_expecting(
+        Output("greeting: String\nname: String\nsummary: String\nxs: List(I32)".to_string()),
+        vec!["check", "examples/interpolation.tk"],
+    )
+}
+
+#[test]
+fn check_linked_lists() -> Test {
+    test_expecting(
+        Output(
+            "End: End | Link(head: I32, tail: Ints)
+Ints: Type
+Link:  {\n    head: I32,\n    tail: Ints,\n} -> End | Link(head: I32, tail: Ints)
+second:  {\n    it: End | Link(head: I32, tail: Ints),\n} -> I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/linked_lists.tk"],
+    )
+}
+
+#[test]
+fn check_mutual_recursion() -> Test {
+    test_expecting(
+        Output("is_even:  {\n    x: I32,\n} -> Bit\nis_odd:  {\n    y: I32,\n} -> Bit".to_string()),
+        vec!["check", "examples/mutual_recursion.tk"],
+    )
+}
+
+#[test]
+fn check_missing_implementation() -> Test {
+    test_expecting(
+        Error,
+        vec!["check", "counter_examples/missing_implementation.tk"],
+    )
+}
+
+#[test]
+fn check_non_exhaustive_match() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "match is missing cases for `Rect`, `Empty` at counter_examples/non_exhaustive_match.tk at line 3, column 28"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/non_exhaustive_match.tk"],
+    )
+}
+
+#[test]
+fn check_numbers() -> Test {
+    test_expecting(
+        Output("big: I64\nbyte: U8\nhalf: F64\nmask: I32\nmillion: I32".to_string()),
+        vec!["check", "examples/numbers.tk"],
+    )
+}
+
+#[test]
+fn check_optionals() -> Test {
+    test_expecting(
+        Output(
+            "double:  {\n    it: I32,\n} -> I32
+half:  {\n    n: I32,\n} -> |(\n    Unit,\n    I32,\n)
+whole: |(Unit, I32)"
+                .to_string(),
+        ),
+        vec!["check", "examples/optionals.tk"],
+    )
+}
+
+#[test]
+fn check_paren_free_calls() -> Test {
+    test_expecting(
+        Output("double:  {\n    it: I32,\n} -> I32".to_string()),
+        vec!["check", "examples/paren_free_calls.tk"],
+    )
+}
+
+#[test]
+fn check_pure_function_prints() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unexpected effects, `double` uses Stdout at counter_examples/pure_function_prints.tk at line 2, column 16"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/pure_function_prints.tk"],
+    )
+}
+
+#[test]
+fn compile_pure_function_prints() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unexpected effects, `double` uses Stdout at counter_examples/pure_function_prints.tk at line 2, column 16"
+                .to_string(),
+        ),
+        vec!["counter_examples/pure_function_prints.tk"],
+    )
+}
+
+#[test]
+fn impure_after_type_error() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unexpected effects, `double` uses Stdout at counter_examples/impure_after_type_error.tk at line 3, column 16"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/impure_after_type_error.tk"],
+    )
+}
+
+#[test]
+fn compile_impure_after_type_error() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unexpected effects, `double` uses Stdout at counter_examples/impure_after_type_error.tk at line 3, column 16"
+                .to_string(),
+        ),
+        vec!["counter_examples/impure_after_type_error.tk"],
+    )
+}
+
+#[test]
+fn pure_function_prints() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unexpected effects, `double` uses Stdout at counter_examples/pure_function_prints.tk at line 2, column 16"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/pure_function_prints.tk"],
+    )
+}
+
+#[test]
+fn compile_wrong_result_type() -> Test {
+    test_expecting(
+        ErrorMessage("impossible type, `f(n)` is annotated as String but has type I32".to_string()),
+        vec!["counter_examples/wrong_result_type.tk"],
+    )
+}
+
+#[test]
+fn wrong_result_type() -> Test {
+    test_expecting(
+        ErrorMessage("impossible type, `f(n)` is annotated as String but has type I32".to_string()),
+        vec!["--run", "counter_examples/wrong_result_type.tk"],
+    )
+}
+
+#[test]
+fn check_shapes() -> Test {
+    test_expecting(
+        Output(
+            "Circle:  {\n    r: I32,\n} -> Circle(r: I32) | Rect(w: I32, h: I32) | Empty
+Empty: Circle(r: I32) | Rect(w: I32, h: I32) | Empty
+Rect:  {\n    w: I32,\n    h: I32,\n} -> Circle(r: I32) | Rect(w: I32, h: I32) | Empty
+Shape: Type
+area:  {\n    s: Circle(r: I32) | Rect(w: I32, h: I32) | Empty,\n} -> I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/shapes.tk"],
+    )
+}
+
+#[test]
+fn check_strings() -> Test {
+    test_expecting(
+        Output(
+            "at: I32
+count: I32
+first: Char
+greeting: String
+joined: String
+shout: String
+word: String
+words: List(String)"
+                .to_string(),
+        ),
+        vec!["check", "examples/strings.tk"],
+    )
+}
+
+#[test]
+fn check_structs_as_functions() -> Test {
+    test_expecting(
+        Output(
+            "keep:  {
+    table:  {
+        field: |(
+            'a',
+            'b',
+        ),
+    } -> I32,
+} ->  {
+    field: |(
+        'a',
+        'b',
+    ),
+} -> I32
+scores:  { a: I32, b: I32 }"
+                .to_string(),
+        ),
+        vec!["check", "examples/structs_as_functions.tk"],
+    )
+}
+
+#[test]
+fn check_struct_missing_field_as_function() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "impossible type, `scores=((a=3,c=4))` is annotated as  {\n    field: |(\n        'a',\n        'b',\n    ),\n} -> I32 but has type  {\n    a: I32,\n    c: I32,\n}"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/struct_missing_field_as_function.tk"],
+    )
+}
+
+#[test]
+fn check_tuples() -> Test {
+    test_expecting(
+        Output(
+            "divide:  {\n    n: I32,\n    by: I32,\n} -> (I32, I32)
+let_name_number: (String, I32)
+let_px_py:  { x: I32, y: I32 }
+let_quotient_remainder: (I32, I32)
+name: String
+number: I32
+pair: (I32, String)
+point:  { x: I32, y: I32 }
+px: I32
+py: I32
+quotient: I32
+remainder: I32
+swap:  {\n    p: (I32, String),\n} -> (String, I32)"
+                .to_string(),
+        ),
+        vec!["check", "examples/tuples.tk"],
+    )
+}
+
+#[test]
+fn check_constructor_missing_field() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "type mismatch in `(Rect)(w=2, d=5)`, expected  {\n    w: I32,\n    h: I32,\n} but found  {\n    w: I32,\n    d: I32,\n} at counter_examples/constructor_missing_field.tk at line 5, column 12"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/constructor_missing_field.tk"],
+    )
+}
+
+#[test]
+fn check_foreign_constructor_in_match() -> Test {
+    test_expecting(
+        ErrorMessage("impossible type, `Red` is not a constructor of Circle(r: I32) | Empty".to_string()),
+        vec!["check", "counter_examples/foreign_constructor_in_match.tk"],
+    )
+}
+
+#[test]
+fn check_data_equality() -> Test {
+    test_expecting(
+        Output(
+            "End: End | Link(head: I32, tail: Ints)
+Ints: Type
+Link:  {\n    head: I32,\n    tail: Ints,\n} -> End | Link(head: I32, tail: Ints)"
+                .to_string(),
+        ),
+        vec!["check", "examples/data_equality.tk"],
+    )
+}
+
+#[test]
+fn check_unknown_constructor() -> Test {
+    test_expecting(
+        Error,
+        vec!["check", "counter_examples/unknown_constructor.tk"],
+    )
+}
+
+#[test]
+fn check_wildcard_match() -> Test {
+    test_expecting(
+        Output(
+            "Clubs: Hearts | Diamonds | Clubs | Spades
+Diamonds: Hearts | Diamonds | Clubs | Spades
+Hearts: Hearts | Diamonds | Clubs | Spades
+Spades: Hearts | Diamonds | Clubs | Spades
+Suit: Type
+score:  {\n    s: Hearts | Diamonds | Clubs | Spades,\n} -> I32"
+                .to_string(),
+        ),
+        vec!["check", "examples/wildcard_match.tk"],
+    )
+}
+
+#[test]
+fn check_wildcard_not_last() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "clause of `A` is never used, as earlier clauses match the same arguments at counter_examples/wildcard_not_last.tk at line 2, column 36"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/wildcard_not_last.tk"],
+    )
+}
+
+#[test]
+fn captured_variable() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "cannot use `count` in a closure, as it was declared with `:=` and closures only capture its value, copy it with `=` first at counter_examples/captured_variable.tk at line 5, column 14"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/captured_variable.tk"],
+    )
+}
+
+#[test]
+fn clauses() -> Test {
+    test_expecting(
+        Output("120 yes\n17".to_string()),
+        vec!["--run", "examples/clauses.tk"],
+    )
+}
+
+#[test]
+fn closures() -> Test {
+    test_expecting(
+        Output("7 7 15\n0".to_string()),
+        vec!["--run", "examples/closures.tk"],
+    )
+}
+
+#[test]
+fn control_flow() -> Test {
+    test_expecting(
+        Output("-1 0 1 60\n60".to_string()),
+        vec!["--run", "examples/control_flow.tk"],
+    )
+}
+
+#[test]
+fn code_reuse() -> Test {
+    run("examples/code_reuse.tk")
+}
+
+#[test]
+fn comment() -> Test {
+    run("examples/comment.tk")
+}
+
+#[test]
+fn compile_1_plus_2() -> Test {
+    compile_matching_golden("tests/goldens/examples_1_plus_2.cc", "examples/1_plus_2.tk")
+}
+
+#[test]
+fn compile_accumulator() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_accumulator.cc",
+        "examples/accumulator.tk",
+    )
+}
+
+#[test]
+fn compile_arguments() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_arguments.cc",
+        "examples/arguments.tk",
+    )
+}
+
+// Arbitrary precision integers are compiled with GMP, which is only installed for Linux builds.
+#[cfg(not(windows))]
+#[test]
+fn compile_big_numbers() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_big_numbers.cc",
+        "examples/big_numbers.tk",
+    )
 }
 
 #[test]
-fn bool_times_bool() -> Test {
-    run_with_error("counter_examples/bool_times_bool.tk")
+fn compile_blocks() -> Test {
+    compile_matching_golden("tests/goldens/examples_blocks.cc", "examples/blocks.tk")
 }
 
 #[test]
-fn code_reuse() -> Test {
-    run("examples/code_reuse.tk")
+fn compile_captured_variable() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "cannot use `count` in a closure, as it was declared with `:=` and closures only capture its value, copy it with `=` first at counter_examples/captured_variable.tk at line 5, column 14"
+                .to_string(),
+        ),
+        vec!["counter_examples/captured_variable.tk"],
+    )
 }
 
 #[test]
-fn comment() -> Test {
-    run("examples/comment.tk")
+fn compile_clauses() -> Test {
+    compile_matching_golden("tests/goldens/examples_clauses.cc", "examples/clauses.tk")
 }
 
 #[test]
-fn compile_1_plus_2() -> Test {
-    compile_matching_golden("tests/goldens/examples_1_plus_2.cc", "examples/1_plus_2.tk")
+fn compile_closures() -> Test {
+    compile_matching_golden("tests/goldens/examples_closures.cc", "examples/closures.tk")
 }
 
 #[test]
-fn compile_arguments() -> Test {
+fn compile_control_flow() -> Test {
     compile_matching_golden(
-        "tests/goldens/examples_arguments.cc",
-        "examples/arguments.tk",
+        "tests/goldens/examples_control_flow.cc",
+        "examples/control_flow.tk",
     )
 }
 
@@ -187,6 +866,48 @@ fn compile_comment() -> Test {
     compile_matching_golden("tests/goldens/examples_comment.cc", "examples/comment.tk")
 }
 
+#[test]
+fn compile_containers() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_containers.cc",
+        "examples/containers.tk",
+    )
+}
+
+#[test]
+fn compile_contracts() -> Test {
+    // Both modes write the same binary, so they are built one after the other.
+    test_expecting(
+        OutputFile("tests/goldens/examples_contracts_unchecked.cc".to_string()),
+        vec!["examples/contracts.tk"],
+    )?;
+    let output = std::process::Command::new("build/examples_contracts")
+        .output()
+        .expect("could not run compiled contracts example");
+    assert!(!output.status.success());
+    test_expecting(
+        OutputFile("tests/goldens/examples_contracts.cc".to_string()),
+        vec!["--checked", "examples/contracts.tk"],
+    )?;
+    let output = std::process::Command::new("build/examples_contracts")
+        .output()
+        .expect("could not run compiled contracts example");
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "runtime requirement failed at examples/contracts.tk at line 2, column 15: ((n%2)==0)\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn compile_dot_notation() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_dot_notation.cc",
+        "examples/dot_notation.tk",
+    )
+}
+
 #[test]
 fn compile_div() -> Test {
     compile_matching_golden("tests/goldens/examples_div.cc", "examples/div.tk")
@@ -200,6 +921,27 @@ fn compile_empty_def_args() -> Test {
     )
 }
 
+#[test]
+fn compile_handled_at_top_level() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_handled_at_top_level.cc",
+        "examples/handled_at_top_level.tk",
+    )
+}
+
+#[test]
+fn compile_handled_operations() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_handled_operations.cc",
+        "examples/handled_operations.tk",
+    )
+}
+
+#[test]
+fn compile_handlers() -> Test {
+    compile_matching_golden("tests/goldens/examples_handlers.cc", "examples/handlers.tk")
+}
+
 #[test]
 fn compile_hello_name() -> Test {
     compile_matching_golden(
@@ -224,11 +966,61 @@ fn compile_ignored_let() -> Test {
     )
 }
 
+#[test]
+fn compile_imports() -> Test {
+    compile_matching_golden("tests/goldens/examples_imports.cc", "examples/imports.tk")
+}
+
+#[test]
+fn compile_interfaces() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_interfaces.cc",
+        "examples/interfaces.tk",
+    )
+}
+
+#[test]
+fn compile_interpolation() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_interpolation.cc",
+        "examples/interpolation.tk",
+    )
+}
+
+#[test]
+fn compile_lexical_scope() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_lexical_scope.cc",
+        "examples/lexical_scope.tk",
+    )
+}
+
+#[test]
+fn compile_linked_lists() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_linked_lists.cc",
+        "examples/linked_lists.tk",
+    )
+}
+
 #[test]
 fn compile_lambda() -> Test {
     compile_matching_golden("tests/goldens/examples_lambda.cc", "examples/lambda.tk")
 }
 
+#[test]
+fn compile_matched_constructors() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_matched_constructors.cc",
+        "examples/matched_constructors.tk",
+    )
+}
+
+#[test]
+fn compile_modules() -> Test {
+    compile_matching_golden("tests/goldens/examples_modules.cc", "examples/modules.tk")
+}
+
 #[test]
 fn compile_multi_comment() -> Test {
     compile_matching_golden(
@@ -271,6 +1063,14 @@ fn compile_nested_explicit() -> Test {
     )
 }
 
+#[test]
+fn compile_nested_loops() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_nested_loops.cc",
+        "examples/nested_loops.tk",
+    )
+}
+
 #[test]
 fn compile_non_overlapping_anons() -> Test {
     compile_matching_golden(
@@ -284,6 +1084,46 @@ fn compile_not() -> Test {
     compile_matching_golden("tests/goldens/examples_not.cc", "examples/not.tk")
 }
 
+#[test]
+fn compile_fac() -> Test {
+    compile_matching_golden("tests/goldens/examples_fac.cc", "examples/fac.tk")
+}
+
+#[test]
+fn compile_fib_acc() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "recursive functions inside other functions cannot be compiled to C++ yet at examples/fibAcc.tk at line 2, column 17"
+                .to_string(),
+        ),
+        vec!["examples/fibAcc.tk"],
+    )
+}
+
+#[test]
+fn compile_field_of_number() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "no field `nope` in I32 at counter_examples/field_of_number.tk at line 3, column 2"
+                .to_string(),
+        ),
+        vec!["counter_examples/field_of_number.tk"],
+    )
+}
+
+#[test]
+fn compile_fixed_width() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_fixed_width.cc",
+        "examples/fixed_width.tk",
+    )
+}
+
+#[test]
+fn compile_numbers() -> Test {
+    compile_matching_golden("tests/goldens/examples_numbers.cc", "examples/numbers.tk")
+}
+
 #[test]
 fn compile_optional_semis() -> Test {
     compile_matching_golden(
@@ -292,6 +1132,14 @@ fn compile_optional_semis() -> Test {
     )
 }
 
+#[test]
+fn compile_optionals() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_optionals.cc",
+        "examples/optionals.tk",
+    )
+}
+
 #[test]
 fn compile_order_of_ops() -> Test {
     compile_matching_golden(
@@ -305,6 +1153,27 @@ fn compile_paren() -> Test {
     compile_matching_golden("tests/goldens/examples_paren.cc", "examples/paren.tk")
 }
 
+#[test]
+fn compile_paren_free_calls() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_paren_free_calls.cc",
+        "examples/paren_free_calls.tk",
+    )
+}
+
+#[test]
+fn compile_proven_contracts() -> Test {
+    // Both modes write the same binary, so they are built one after the other.
+    test_expecting(
+        OutputFile("tests/goldens/examples_proven_contracts_unchecked.cc".to_string()),
+        vec!["examples/proven_contracts.tk"],
+    )?;
+    test_expecting(
+        OutputFile("tests/goldens/examples_proven_contracts.cc".to_string()),
+        vec!["--release", "examples/proven_contracts.tk"],
+    )
+}
+
 #[test]
 fn compile_pow() -> Test {
     compile_matching_golden("tests/goldens/examples_pow.cc", "examples/pow.tk")
@@ -324,74 +1193,330 @@ fn compile_shadowing() -> Test {
 }
 
 #[test]
-fn compile_simple() -> Test {
-    compile_matching_golden("tests/goldens/examples_simple.cc", "examples/simple.tk")
+fn compile_shapes() -> Test {
+    compile_matching_golden("tests/goldens/examples_shapes.cc", "examples/shapes.tk")
+}
+
+#[test]
+fn compile_shared_variables() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_shared_variables.cc",
+        "examples/shared_variables.tk",
+    )
+}
+
+#[test]
+fn compile_simple() -> Test {
+    compile_matching_golden("tests/goldens/examples_simple.cc", "examples/simple.tk")
+}
+
+#[test]
+fn compile_simple_call() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_simple_call.cc",
+        "examples/simple_call.tk",
+    )
+}
+
+#[test]
+fn compile_strings() -> Test {
+    compile_matching_golden("tests/goldens/examples_strings.cc", "examples/strings.tk")
+}
+
+#[test]
+fn compile_structs_as_functions() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_structs_as_functions.cc",
+        "examples/structs_as_functions.tk",
+    )
+}
+
+#[test]
+fn compile_sub() -> Test {
+    compile_matching_golden("tests/goldens/examples_sub.cc", "examples/sub.tk")
+}
+
+#[test]
+fn compile_three_vars() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_three_vars.cc",
+        "examples/three_vars.tk",
+    )
+}
+
+#[test]
+fn compile_tmp() -> Test {
+    compile_matching_golden("tests/goldens/examples_tmp.cc", "examples/tmp.tk")
+}
+
+#[test]
+fn compile_tuples() -> Test {
+    compile_matching_golden("tests/goldens/examples_tuples.cc", "examples/tuples.tk")
+}
+
+#[test]
+fn compile_constructor_missing_field() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "type mismatch in `(Rect)(w=2, d=5)`, expected  {\n    w: I32,\n    h: I32,\n} but found  {\n    w: I32,\n    d: I32,\n} at counter_examples/constructor_missing_field.tk at line 5, column 12"
+                .to_string(),
+        ),
+        vec!["counter_examples/constructor_missing_field.tk"],
+    )
+}
+
+#[test]
+fn compile_foreign_constructor_in_match() -> Test {
+    test_expecting(
+        ErrorMessage("impossible type, `Red` is not a constructor of Circle(r: I32) | Empty".to_string()),
+        vec!["counter_examples/foreign_constructor_in_match.tk"],
+    )
+}
+
+#[test]
+fn compile_data_equality() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_data_equality.cc",
+        "examples/data_equality.tk",
+    )
+}
+
+#[test]
+fn compile_unknown_constructor() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "impossible type, `Red` is not a constructor of Circle(r: I32) | Empty".to_string(),
+        ),
+        vec!["counter_examples/unknown_constructor.tk"],
+    )
+}
+
+#[test]
+fn compile_wildcard_match() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_wildcard_match.cc",
+        "examples/wildcard_match.tk",
+    )
+}
+
+#[test]
+fn compile_x_plus_1() -> Test {
+    compile_matching_golden("tests/goldens/examples_x_plus_1.cc", "examples/x_plus_1.tk")
+}
+
+#[test]
+fn defaulting() -> Test {
+    run("examples/defaulting.tk")
+}
+
+#[test]
+fn containers() -> Test {
+    test_expecting(
+        Output(
+            "[20, 30, 40] [2, 4] {1, 2, 3} [2, 3, 4, 5]
+['dan'] {'ann': 31, 'bob': 42, 'cat': 7} [1.5, 2.0]
+25"
+            .to_string(),
+        ),
+        vec!["--run", "examples/containers.tk"],
+    )
+}
+
+#[test]
+fn contracts() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "runtime requirement failed at examples/contracts.tk at line 2, column 15".to_string(),
+        ),
+        vec!["--run", "examples/contracts.tk"],
+    )
+}
+
+#[test]
+fn div() -> Test {
+    run("examples/div.tk")
+}
+
+#[test]
+fn dot_notation() -> Test {
+    test_expecting(
+        Output("9 8\n8".to_string()),
+        vec!["--run", "examples/dot_notation.tk"],
+    )
+}
+
+#[test]
+fn dupe_alt() -> Test {
+    test_expecting(Error, vec!["--run", "counter_examples/dupe_alt.tk"])
+}
+
+#[test]
+fn effects() -> Test {
+    test_expecting(
+        Output("Hello, tako!\n42".to_string()),
+        vec!["--run", "examples/effects.tk"],
+    )
+}
+
+#[test]
+fn empty_args() -> Test {
+    run("examples/empty_args.tk")
+}
+
+#[test]
+fn empty_def_args() -> Test {
+    run("examples/empty_def_args.tk")
+}
+
+#[test]
+fn empty_interpolation() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "parse failed, Expected an expression between `{` and `}` in string, use `\\{` for a brace at counter_examples/empty_interpolation.tk at line 3, column 17"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/empty_interpolation.tk"],
+    )
+}
+
+#[test]
+fn extension() -> Test {
+    run("examples/extension.weird")
+}
+
+#[test]
+fn fac() -> Test {
+    run("examples/fac.tk")
+}
+
+#[test]
+fn generate_tests_contracts() -> Test {
+    test_expecting(
+        Output(
+            "half: failed with runtime requirement failed at examples/contracts.tk at line 2, column 15
+  minimal call: half(n=1)"
+                .to_string(),
+        ),
+        vec!["test", "--generate", "examples/contracts.tk"],
+    )
 }
 
 #[test]
-fn compile_simple_call() -> Test {
-    compile_matching_golden(
-        "tests/goldens/examples_simple_call.cc",
-        "examples/simple_call.tk",
+fn generate_tests_fac() -> Test {
+    test_expecting(
+        Output("fac: passed 100 generated calls".to_string()),
+        vec!["test", "--generate", "examples/fac.tk"],
     )
 }
 
 #[test]
-fn compile_sub() -> Test {
-    compile_matching_golden("tests/goldens/examples_sub.cc", "examples/sub.tk")
+fn generate_tests_divide_by_input() -> Test {
+    test_expecting(
+        Output(
+            "boom: failed with division by zero at examples/divide_by_input.tk at line 2, column 12
+  minimal call: boom(n=0)"
+                .to_string(),
+        ),
+        vec!["test", "--generate", "examples/divide_by_input.tk"],
+    )
 }
 
 #[test]
-fn compile_three_vars() -> Test {
-    compile_matching_golden(
-        "tests/goldens/examples_three_vars.cc",
-        "examples/three_vars.tk",
+fn generate_tests_first_element() -> Test {
+    test_expecting(
+        Output(
+            "first: failed with index 0 is out of bounds for a list of length 0 at examples/first_element.tk at line 2, column 13
+  minimal call: first(xs=[])"
+                .to_string(),
+        ),
+        vec!["test", "--generate", "examples/first_element.tk"],
     )
 }
 
 #[test]
-fn compile_tmp() -> Test {
-    compile_matching_golden("tests/goldens/examples_tmp.cc", "examples/tmp.tk")
+fn generate_tests_lookup() -> Test {
+    test_expecting(
+        Output(
+            "lookup: failed with key '' is missing from map at examples/lookup.tk at line 2, column 16
+  minimal call: lookup(key=\"\")"
+                .to_string(),
+        ),
+        vec!["test", "--generate", "examples/lookup.tk"],
+    )
 }
 
 #[test]
-fn compile_x_plus_1() -> Test {
-    compile_matching_golden("tests/goldens/examples_x_plus_1.cc", "examples/x_plus_1.tk")
+fn generate_tests_fib_acc() -> Test {
+    test_expecting(
+        Output(
+            "fib: failed with recursion limit of 1000 nested calls reached at examples/fibAcc.tk at line 4, column 20
+  minimal call: fib(it=(-1))"
+                .to_string(),
+        ),
+        vec!["test", "--generate", "examples/fibAcc.tk"],
+    )
 }
 
 #[test]
-fn defaulting() -> Test {
-    run("examples/defaulting.tk")
+fn compile_resumptions() -> Test {
+    compile_matching_golden(
+        "tests/goldens/examples_resumptions.cc",
+        "examples/resumptions.tk",
+    )
 }
 
 #[test]
-fn div() -> Test {
-    run("examples/div.tk")
+fn resumptions() -> Test {
+    test_expecting(
+        Output("after two\nafter one\ndone!!\n0".to_string()),
+        vec!["--run", "examples/resumptions.tk"],
+    )
 }
 
 #[test]
-fn dupe_alt() -> Test {
-    test_expecting(Error, vec!["--run", "counter_examples/dupe_alt.tk"])
+fn resume_outside_handler() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`resume` can only be used in the clauses of a handler at counter_examples/resume_outside_handler.tk at line 2, column 12"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/resume_outside_handler.tk"],
+    )
 }
 
 #[test]
-fn empty_args() -> Test {
-    run("examples/empty_args.tk")
+fn compile_resume_outside_handler() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`resume` can only be used in the clauses of a handler at counter_examples/resume_outside_handler.tk at line 2, column 12"
+                .to_string(),
+        ),
+        vec!["counter_examples/resume_outside_handler.tk"],
+    )
 }
 
 #[test]
-fn empty_def_args() -> Test {
-    run("examples/empty_def_args.tk")
+fn handled_at_top_level() -> Test {
+    test_expecting(
+        Output("1\n6".to_string()),
+        vec!["--run", "examples/handled_at_top_level.tk"],
+    )
 }
 
 #[test]
-fn extension() -> Test {
-    run("examples/extension.weird")
+fn handled_operations() -> Test {
+    test_expecting(
+        Output("6\nready\n12".to_string()),
+        vec!["--run", "examples/handled_operations.tk"],
+    )
 }
 
 #[test]
-fn fac() -> Test {
-    run("examples/fac.tk")
+fn handlers() -> Test {
+    test_expecting(
+        Output("Well... Hello, tako!\nHello, tako!\n\n0".to_string()),
+        vec!["--run", "examples/handlers.tk"],
+    )
 }
 
 #[test]
@@ -422,11 +1547,73 @@ fn ignored_let() -> Test {
     run("examples/ignored_let.tk")
 }
 
+#[test]
+fn incomplete_clauses() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "clauses of `sign` don't cover every argument, add a clause without patterns at counter_examples/incomplete_clauses.tk at line 1, column 8"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/incomplete_clauses.tk"],
+    )
+}
+
+#[test]
+fn immutable_assignment() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "cannot assign to `total` as it was not declared with `:=` at counter_examples/immutable_assignment.tk at line 2, column 6"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/immutable_assignment.tk"],
+    )
+}
+
+#[test]
+fn imports() -> Test {
+    test_expecting(
+        Output("49 8 true false\n0".to_string()),
+        vec!["--run", "examples/imports.tk"],
+    )
+}
+
+#[test]
+fn interfaces() -> Test {
+    test_expecting(
+        Output("(7, 10) has size 70\n0".to_string()),
+        vec!["--run", "examples/interfaces.tk"],
+    )
+}
+
+#[test]
+fn interpolation() -> Test {
+    test_expecting(
+        Output("Hello wörld! {3 items: [1, 2, 3], total 6}\n0".to_string()),
+        vec!["--run", "examples/interpolation.tk"],
+    )
+}
+
 #[test]
 fn lambda() -> Test {
     run("examples/lambda.tk")
 }
 
+#[test]
+fn lexical_scope() -> Test {
+    test_expecting(
+        Output("12".to_string()),
+        vec!["--run", "examples/lexical_scope.tk"],
+    )
+}
+
+#[test]
+fn linked_lists() -> Test {
+    test_expecting(
+        Output("2".to_string()),
+        vec!["--run", "examples/linked_lists.tk"],
+    )
+}
+
 #[test]
 fn r#loop() -> Test {
     run("examples/loop.tk")
@@ -440,6 +1627,44 @@ fn missing_arguments() -> Test {
     )
 }
 
+#[test]
+fn missing_implementation() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`add` is not implemented for Pt(x=1, y=2) at counter_examples/missing_implementation.tk at line 4, column 13"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/missing_implementation.tk"],
+    )
+}
+
+#[test]
+fn modules() -> Test {
+    test_expecting(
+        Output("100 212\n107".to_string()),
+        vec!["--run", "examples/modules.tk"],
+    )
+}
+
+#[test]
+fn matched_constructors() -> Test {
+    test_expecting(
+        Output("3".to_string()),
+        vec!["--run", "examples/matched_constructors.tk"],
+    )
+}
+
+#[test]
+fn missing_module() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "could not find module `no_such_module` at counter_examples/missing_module.tk at line 1, column 7"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/missing_module.tk"],
+    )
+}
+
 #[test]
 fn multi_comment() -> Test {
     run("examples/multi_comment.tk")
@@ -488,6 +1713,14 @@ fn nested_explicit_side_effect() -> Test {
     )
 }
 
+#[test]
+fn nested_loops() -> Test {
+    test_expecting(
+        Output("4 100000\n4".to_string()),
+        vec!["--run", "examples/nested_loops.tk"],
+    )
+}
+
 #[test]
 fn non_lambda() -> Test {
     test_expecting(Error, vec!["--run", "counter_examples/non_lambda.tk"])
@@ -513,11 +1746,157 @@ fn not_string() -> Test {
     test_expecting(Error, vec!["--run", "counter_examples/not_string.tk"])
 }
 
+#[test]
+fn number_out_of_range() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`3_000_000_000` is out of range for I32 at counter_examples/number_out_of_range.tk at line 2, column 1"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/number_out_of_range.tk"],
+    )
+}
+
+#[test]
+fn number_out_of_range_later() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`256u8` is out of range for U8 at counter_examples/number_out_of_range_later.tk at line 2, column 4"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/number_out_of_range_later.tk"],
+    )
+}
+
+#[test]
+fn unreachable_clause() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "clause of `fac` is never used, as earlier clauses match the same arguments at counter_examples/unreachable_clause.tk at line 2, column 7"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/unreachable_clause.tk"],
+    )
+}
+
+#[test]
+fn constructor_missing_field() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "type mismatch in `(Rect)(w=2, d=5)`, expected  {\n    w: I32,\n    h: I32,\n} but found  {\n    w: I32,\n    d: I32,\n} at counter_examples/constructor_missing_field.tk at line 5, column 12"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/constructor_missing_field.tk"],
+    )
+}
+
+#[test]
+fn foreign_constructor_in_match() -> Test {
+    test_expecting(
+        ErrorMessage("impossible type, `Red` is not a constructor of Circle(r: I32) | Empty".to_string()),
+        vec!["--run", "counter_examples/foreign_constructor_in_match.tk"],
+    )
+}
+
+#[test]
+fn data_equality() -> Test {
+    test_expecting(
+        Output("true false false false\n0".to_string()),
+        vec!["--run", "examples/data_equality.tk"],
+    )
+}
+
+#[test]
+fn unknown_constructor() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "impossible type, `Red` is not a constructor of Circle(r: I32) | Empty".to_string(),
+        ),
+        vec!["--run", "counter_examples/unknown_constructor.tk"],
+    )
+}
+
+#[test]
+fn wildcard_match() -> Test {
+    test_expecting(
+        Output("1 0 0 13\n0".to_string()),
+        vec!["--run", "examples/wildcard_match.tk"],
+    )
+}
+
+#[test]
+fn unknown_field() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "no field `z` in  { x: I32, y: I32 } at counter_examples/unknown_field.tk at line 2, column 6"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/unknown_field.tk"],
+    )
+}
+
+#[test]
+fn unknown_method() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "unknown method `stretch` at counter_examples/unknown_method.tk at line 2, column 3"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/unknown_method.tk"],
+    )
+}
+
+#[test]
+fn unclosed_interpolation() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "parse failed, Unclosed `{` in string at counter_examples/unclosed_interpolation.tk at line 3, column 17"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/unclosed_interpolation.tk"],
+    )
+}
+
+#[test]
+fn field_of_number() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "no field `nope` in I32 at counter_examples/field_of_number.tk at line 3, column 2"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/field_of_number.tk"],
+    )
+}
+
+#[test]
+fn fixed_width() -> Test {
+    test_expecting(
+        Output("0 255 6000000000 1500.0 375.0 [1.5, 2.0]\n0".to_string()),
+        vec!["--run", "examples/fixed_width.tk"],
+    )
+}
+
+#[test]
+fn numbers() -> Test {
+    test_expecting(
+        Output("6000000000\n1256".to_string()),
+        vec!["--run", "examples/numbers.tk"],
+    )
+}
+
 #[test]
 fn optional_semis() -> Test {
     run("examples/optional_semis.tk")
 }
 
+#[test]
+fn optionals() -> Test {
+    test_expecting(
+        Output("2 0\n8 -1\n13 11\ntrue".to_string()),
+        vec!["--run", "examples/optionals.tk"],
+    )
+}
+
 #[test]
 fn order_of_ops() -> Test {
     run("examples/order_of_ops.tk")
@@ -536,6 +1915,41 @@ fn paren() -> Test {
     run("examples/paren.tk")
 }
 
+#[test]
+fn paren_free_calls() -> Test {
+    test_expecting(
+        Output("7 -10\n8".to_string()),
+        vec!["--run", "examples/paren_free_calls.tk"],
+    )
+}
+
+#[test]
+fn positional_after_named_field() -> Test {
+    test_expecting(
+        ErrorMessage("Expected a name for 2, as it follows a named field".to_string()),
+        vec!["--run", "counter_examples/positional_after_named_field.tk"],
+    )
+}
+
+#[test]
+fn print_tuple() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "expected String but found (I32, String) at counter_examples/print_tuple.tk at line 3, column 6"
+                .to_string(),
+        ),
+        vec!["check", "counter_examples/print_tuple.tk"],
+    )
+}
+
+#[test]
+fn proven_contracts() -> Test {
+    test_expecting(
+        ReturnValue(7),
+        vec!["--run", "examples/proven_contracts.tk"],
+    )
+}
+
 #[test]
 fn pow() -> Test {
     run("examples/pow.tk")
@@ -566,6 +1980,17 @@ fn compile_parse_i32() -> Test {
     compile("examples/parse_i32.tk")
 }
 
+#[test]
+fn error_in_taken_branch() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "division by zero at counter_examples/error_in_taken_branch.tk at line 2, column 22"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/error_in_taken_branch.tk"],
+    )
+}
+
 #[test]
 fn requirement() -> Test {
     run_with_error("counter_examples/requirement.tk")
@@ -576,6 +2001,33 @@ fn shadowing() -> Test {
     run("counter_examples/shadowing.tk")
 }
 
+#[test]
+fn shared_constructor() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "`Off` is already a constructor of `Light` at counter_examples/shared_constructor.tk at line 3, column 12"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/shared_constructor.tk"],
+    )
+}
+
+#[test]
+fn shared_variables() -> Test {
+    test_expecting(
+        Output("6".to_string()),
+        vec!["--run", "examples/shared_variables.tk"],
+    )
+}
+
+#[test]
+fn shapes() -> Test {
+    test_expecting(
+        Output("22".to_string()),
+        vec!["--run", "examples/shapes.tk"],
+    )
+}
+
 #[test]
 fn simple() -> Test {
     run("examples/simple.tk")
@@ -586,6 +2038,38 @@ fn simple_call() -> Test {
     run("examples/simple_call.tk")
 }
 
+#[test]
+fn strings() -> Test {
+    test_expecting(
+        Output("é! wörld a & b & c HÉLLO WÖRLD\n6 11 abc İß\n5".to_string()),
+        vec!["--run", "examples/strings.tk"],
+    )
+}
+
+#[test]
+fn string_index() -> Test {
+    test_expecting(
+        ErrorMessage(
+            "index 5 is out of bounds for a string of length 5 at counter_examples/string_index.tk at line 2, column 5"
+                .to_string(),
+        ),
+        vec!["--run", "counter_examples/string_index.tk"],
+    )
+}
+
+#[test]
+fn structs_as_functions() -> Test {
+    test_expecting(
+        Output("7".to_string()),
+        vec!["--run", "examples/structs_as_functions.tk"],
+    )
+}
+
+#[test]
+fn struct_missing_field_as_function() -> Test {
+    run_with_error("counter_examples/struct_missing_field_as_function.tk")
+}
+
 #[test]
 fn sub() -> Test {
     run("examples/sub.tk")
@@ -606,6 +2090,14 @@ fn tmp() -> Test {
     run("examples/tmp.tk")
 }
 
+#[test]
+fn tuples() -> Test {
+    test_expecting(
+        Output("three 3 3 2\n(3, 'three') (x=1, y=2)\n21".to_string()),
+        vec!["--run", "examples/tuples.tk"],
+    )
+}
+
 #[test]
 fn type_bool() -> Test {
     run("examples/type_bool.tk")
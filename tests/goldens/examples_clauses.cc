@@ -0,0 +1,60 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_clauses_fac = [&](
+    const auto examples_clauses_fac_n
+  ) {
+    const auto examples_clauses_fac_self = [&](const auto& examples_clauses_fac, const auto examples_clauses_fac_n) -> int {  return ((examples_clauses_fac_n==0) ? 1 : (examples_clauses_fac_n*examples_clauses_fac(examples_clauses_fac, (examples_clauses_fac_n-1))));};
+    return examples_clauses_fac_self(examples_clauses_fac_self, examples_clauses_fac_n);
+  };
+  const auto examples_clauses_describe = [&](
+    const auto examples_clauses_describe_it
+  ) {
+    return ((examples_clauses_describe_it==1) ? "yes" : "no");
+  };
+  const auto examples_clauses_pick = [&](
+    const auto examples_clauses_pick_a,
+    const auto examples_clauses_pick_b
+  ) {
+    const auto examples_clauses_pick_y = examples_clauses_pick_b;
+    const auto examples_clauses_pick_x = examples_clauses_pick_a;
+    return ((examples_clauses_pick_a==0) ? examples_clauses_pick_b : ((examples_clauses_pick_b==0) ? examples_clauses_pick_a : (examples_clauses_pick_x*examples_clauses_pick_y)));
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", examples_clauses_fac(5)), " "), examples_clauses_describe((examples_clauses_fac(0)==1))), "\n"));
+  return ((examples_clauses_pick(0, 4)+examples_clauses_pick(3, 0))+examples_clauses_pick(2, 5));
+}
@@ -0,0 +1,9 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_contracts_half = [&](
+    const auto examples_contracts_half_n
+  ) {
+    return (((examples_contracts_half_n%2)==0) ? (examples_contracts_half_n/2) : throw 101);
+  };
+  return (examples_contracts_half(4)+examples_contracts_half(3));
+}
@@ -0,0 +1,50 @@
+#include <iostream>
+#include <memory>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  struct examples_data_equality_Ints { unsigned int tag : 1; int Link_head; std::shared_ptr<const examples_data_equality_Ints> Link_tail; bool operator==(const examples_data_equality_Ints& other) const { return tag == other.tag && (tag != 1 || (Link_head == other.Link_head && *Link_tail == *other.Link_tail)); } bool operator!=(const examples_data_equality_Ints& other) const { return !(*this == other); } };
+  const auto examples_data_equality_End = examples_data_equality_Ints{0, {}, {}};
+  const auto examples_data_equality_Link = [&](
+    const auto examples_data_equality_Link_head,
+    const auto examples_data_equality_Link_tail
+  ) {
+    return examples_data_equality_Ints{1, examples_data_equality_Link_head, std::make_shared<const examples_data_equality_Ints>(examples_data_equality_Link_tail)};
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", (examples_data_equality_Link(1, examples_data_equality_End)==examples_data_equality_Link(1, examples_data_equality_End))), " "), (examples_data_equality_Link(1, examples_data_equality_End)==examples_data_equality_Link(2, examples_data_equality_End))), " "), (examples_data_equality_End==examples_data_equality_Link(1, examples_data_equality_End))), " "), (examples_data_equality_End!=examples_data_equality_End)), "\n"));
+  return 0;
+}
@@ -0,0 +1,10 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_fac_fac = [&](
+    const auto examples_fac_fac_n
+  ) {
+    const auto examples_fac_fac_self = [&](const auto& examples_fac_fac, const auto examples_fac_fac_n) -> int {  return ((examples_fac_fac_n>1) ? (examples_fac_fac_n*examples_fac_fac(examples_fac_fac, (examples_fac_fac_n-1))) : 1);};
+    return examples_fac_fac_self(examples_fac_fac_self, examples_fac_fac_n);
+  };
+  return examples_fac_fac(5);
+}
@@ -1,17 +1,39 @@
 #include <iostream>
 #include <string>
 #include <sstream>
+#include <vector>
 namespace std{
 template <typename T>
-string to_string(const T& t){
+string to_string_of(const T& t, long){
   stringstream out;
   out << t;
   return out.str();
 }
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
 string to_string(const bool& t){
   return t ? "true" : "false";
 }
 }
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
 
 int main(int argc, char* argv[]) {
   const auto examples_higher_order_apply = [&](
@@ -20,6 +42,6 @@ int main(int argc, char* argv[]) {
   ) {
     return examples_higher_order_apply_f(examples_higher_order_apply_f(examples_higher_order_apply_x));
   };
-  std::cout << ((std::to_string((std::to_string((std::to_string(examples_higher_order_apply(3, [&](  const auto examples_higher_order___it___f_y) {  return (examples_higher_order___it___f_y*2);}))+std::to_string("  ")))+std::to_string(examples_higher_order_apply(1, [&](  const auto examples_higher_order___it___f_y) {  return !(examples_higher_order___it___f_y);}))))+std::to_string("\n")));
+  std::cout << (tako::concat(tako::concat(tako::concat(examples_higher_order_apply(3, [&](  const auto examples_higher_order___it___f_y) {  return (examples_higher_order___it___f_y*2);}), "  "), examples_higher_order_apply(1, [&](  const auto examples_higher_order___it___f_y) {  return !(examples_higher_order___it___f_y);})), "\n"));
   return 0;
 }
@@ -0,0 +1,51 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_modules_celsius_from_kelvin = [&](
+    const auto examples_modules_celsius_from_kelvin_it
+  ) {
+    return (examples_modules_celsius_from_kelvin_it-273);
+  };
+  const auto examples_modules_fahrenheit_from_kelvin = [&](
+    const auto examples_modules_fahrenheit_from_kelvin_it
+  ) {
+    return ((((examples_modules_fahrenheit_from_kelvin_it-273)*9)/5)+32);
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", examples_modules_celsius_from_kelvin(373)), " "), examples_modules_fahrenheit_from_kelvin(373)), "\n"));
+  return (examples_modules_celsius_from_kelvin(300)+examples_modules_fahrenheit_from_kelvin(300));
+}
@@ -0,0 +1,122 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+namespace tako {
+struct none_t {};
+constexpr none_t none{};
+template <typename T>
+struct optional {
+  bool has_value;
+  T value;
+  optional(none_t) : has_value(false), value() {}
+  optional(const T& value) : has_value(true), value(value) {}
+  explicit operator bool() const { return has_value; }
+  const T& operator*() const { return value; }
+};
+template <typename T>
+optional<T> some(const T& value) {
+  return optional<T>(value);
+}
+template <typename T>
+bool operator==(const optional<T>& value, none_t) {
+  return !value;
+}
+template <typename T>
+bool operator!=(const optional<T>& value, none_t) {
+  return bool(value);
+}
+template <typename T>
+bool operator==(none_t, const optional<T>& value) {
+  return !value;
+}
+template <typename T>
+bool operator!=(none_t, const optional<T>& value) {
+  return bool(value);
+}
+template <typename T>
+const T& value_of(const optional<T>& value) {
+  return *value;
+}
+template <typename T>
+const T& value_of(const T& value) {
+  return value;
+}
+template <typename T, typename U>
+T or_else(const optional<T>& value, const U& fallback) {
+  return value ? *value : fallback;
+}
+template <typename U>
+U or_else(none_t, const U& fallback) {
+  return fallback;
+}
+template <typename T, typename U>
+T or_else(const T& value, const U&) {
+  return value;
+}
+template <typename T, typename F>
+auto and_then(const optional<T>& value, F f) -> optional<decltype(f(*value))> {
+  if (value) {
+    return f(*value);
+  }
+  return none;
+}
+template <typename F>
+none_t and_then(none_t, F) {
+  return none;
+}
+template <typename T, typename F>
+auto and_then(const T& value, F f) {
+  return f(value);
+}
+}
+
+int main(int argc, char* argv[]) {
+  const auto examples_optionals_half = [&](
+    const auto examples_optionals_half_n
+  ) {
+    return (((examples_optionals_half_n%2)==0) ? tako::some((examples_optionals_half_n/2)) : tako::none);
+  };
+  const auto examples_optionals_double = [&](
+    const auto examples_optionals_double_it
+  ) {
+    return (examples_optionals_double_it*2);
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", tako::or_else(examples_optionals_half(4), 0)), " "), tako::or_else(examples_optionals_half(3), 0)), "\n"));
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", tako::or_else(tako::and_then(examples_optionals_half(8), [&](const auto value) { return examples_optionals_double(value); }), -(1))), " "), tako::or_else(tako::and_then(examples_optionals_half(7), [&](const auto value) { return examples_optionals_double(value); }), -(1))), "\n"));
+  const auto examples_optionals_whole = tako::and_then(examples_optionals_half(12), [&](const auto value) { return examples_optionals_double(value); });
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat("", ((examples_optionals_whole!=tako::none) ? [&](const auto examples_optionals_whole) { return (examples_optionals_whole+1); }(tako::value_of(examples_optionals_whole)) : 0)), " "), ((examples_optionals_whole==tako::none) ? 0 : [&](const auto examples_optionals_whole) { return (examples_optionals_whole-1); }(tako::value_of(examples_optionals_whole)))), "\n"));
+  return (examples_optionals_half(7)==tako::none);
+}
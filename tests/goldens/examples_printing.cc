@@ -1,19 +1,41 @@
 #include <iostream>
 #include <string>
 #include <sstream>
+#include <vector>
 namespace std{
 template <typename T>
-string to_string(const T& t){
+string to_string_of(const T& t, long){
   stringstream out;
   out << t;
   return out.str();
 }
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
 string to_string(const bool& t){
   return t ? "true" : "false";
 }
 }
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
 
 int main(int argc, char* argv[]) {
-  std::cout << ((std::to_string(((3*3)*2))+std::to_string("\n")));
+  std::cout << (tako::concat(((3*3)*2), "\n"));
   return 0;
 }
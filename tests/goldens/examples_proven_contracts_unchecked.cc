@@ -0,0 +1,14 @@
+
+int main(int argc, char* argv[]) {
+  const auto examples_proven_contracts_positive = [&](
+    const auto examples_proven_contracts_positive_n
+  ) {
+    return ((1<2) ? examples_proven_contracts_positive_n : throw 101);
+  };
+  const auto examples_proven_contracts_halve = [&](
+    const auto examples_proven_contracts_halve_n
+  ) {
+    return ((examples_proven_contracts_halve_n>2) ? ((0<examples_proven_contracts_halve_n) ? (examples_proven_contracts_halve_n/2) : throw 101) : throw 101);
+  };
+  return (examples_proven_contracts_positive(3)+examples_proven_contracts_halve(8));
+}
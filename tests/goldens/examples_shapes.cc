@@ -0,0 +1,22 @@
+
+int main(int argc, char* argv[]) {
+  struct examples_shapes_Shape { unsigned int tag : 2; int Circle_r; int Rect_w; int Rect_h; bool operator==(const examples_shapes_Shape& other) const { return tag == other.tag && (tag != 0 || (Circle_r == other.Circle_r)) && (tag != 1 || (Rect_w == other.Rect_w && Rect_h == other.Rect_h)); } bool operator!=(const examples_shapes_Shape& other) const { return !(*this == other); } };
+  const auto examples_shapes_Circle = [&](
+    const auto examples_shapes_Circle_r
+  ) {
+    return examples_shapes_Shape{0, examples_shapes_Circle_r, {}, {}};
+  };
+  const auto examples_shapes_Rect = [&](
+    const auto examples_shapes_Rect_w,
+    const auto examples_shapes_Rect_h
+  ) {
+    return examples_shapes_Shape{1, {}, examples_shapes_Rect_w, examples_shapes_Rect_h};
+  };
+  const auto examples_shapes_Empty = examples_shapes_Shape{2, {}, {}, {}};
+  const auto examples_shapes_area = [&](
+    const auto examples_shapes_area_s
+  ) {
+    return [&](const auto tako_match) { return (tako_match.tag == 0 ? [&](const auto examples_shapes_area___Circle_r) { return ((3*examples_shapes_area___Circle_r)*examples_shapes_area___Circle_r); }(tako_match.Circle_r) : (tako_match.tag == 1 ? [&](const auto examples_shapes_area___Rect_w, const auto examples_shapes_area___Rect_h) { return (examples_shapes_area___Rect_w*examples_shapes_area___Rect_h); }(tako_match.Rect_w, tako_match.Rect_h) : 0)); }(examples_shapes_area_s);
+  };
+  return ((examples_shapes_area(examples_shapes_Circle(2))+examples_shapes_area(examples_shapes_Rect(2, 5)))+examples_shapes_area(examples_shapes_Empty));
+}
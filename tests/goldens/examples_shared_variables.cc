@@ -0,0 +1,9 @@
+
+int main(int argc, char* argv[]) {
+  auto examples_shared_variables_count = 1;
+  const auto examples_shared_variables_current = [&]() {
+    return examples_shared_variables_count;
+  };
+  (examples_shared_variables_count+=5);
+  return examples_shared_variables_current();
+}
@@ -0,0 +1,51 @@
+#include <iostream>
+#include <string>
+#include <sstream>
+#include <vector>
+namespace std{
+template <typename T>
+string to_string_of(const T& t, long){
+  stringstream out;
+  out << t;
+  return out.str();
+}
+// Values of types that implement `Display` convert themselves.
+template <typename T>
+auto to_string_of(const T& t, int) -> decltype(t.to_string()){
+  return t.to_string();
+}
+template <typename T>
+string to_string(const T& t){
+  return to_string_of(t, 0);
+}
+string to_string(const bool& t){
+  return t ? "true" : "false";
+}
+}
+namespace tako {
+template <typename L, typename R>
+std::string concat(const L& left, const R& right) {
+  return std::to_string(left) + std::to_string(right);
+}
+// Lists are joined, rather than being shown as text.
+template <typename T>
+std::vector<T> concat(std::vector<T> left, const std::vector<T>& right) {
+  left.insert(left.end(), right.begin(), right.end());
+  return left;
+}
+}
+
+int main(int argc, char* argv[]) {
+  struct examples_wildcard_match_Suit { unsigned int tag : 2; bool operator==(const examples_wildcard_match_Suit& other) const { return tag == other.tag; } bool operator!=(const examples_wildcard_match_Suit& other) const { return !(*this == other); } };
+  const auto examples_wildcard_match_Hearts = examples_wildcard_match_Suit{0};
+  const auto examples_wildcard_match_Diamonds = examples_wildcard_match_Suit{1};
+  const auto examples_wildcard_match_Clubs = examples_wildcard_match_Suit{2};
+  const auto examples_wildcard_match_Spades = examples_wildcard_match_Suit{3};
+  const auto examples_wildcard_match_score = [&](
+    const auto examples_wildcard_match_score_s
+  ) {
+    return [&](const auto tako_match) { return (tako_match.tag == 0 ? 1 : (tako_match.tag == 3 ? 13 : 0)); }(examples_wildcard_match_score_s);
+  };
+  std::cout << (tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat(tako::concat("", examples_wildcard_match_score(examples_wildcard_match_Hearts)), " "), examples_wildcard_match_score(examples_wildcard_match_Diamonds)), " "), examples_wildcard_match_score(examples_wildcard_match_Clubs)), " "), examples_wildcard_match_score(examples_wildcard_match_Spades)), "\n"));
+  return 0;
+}